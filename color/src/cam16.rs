@@ -0,0 +1,587 @@
+// Copyright 2025 the Color Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The CAM16 color appearance model.
+//!
+//! Unlike the color spaces of this crate, a color appearance model predicts how a color looks
+//! in a particular viewing environment. The same stimulus appears different depending on the
+//! white point the observer is adapted to, the luminance of the surroundings and the lightness
+//! of the background. These are captured by [`ViewingConditions`].
+//!
+//! [`Cam16`] holds the appearance correlates of a color: lightness `J`, chroma `C`, hue angle
+//! `h`, colorfulness `M`, saturation `s` and brightness `Q`. It can be calculated from a color
+//! in given viewing conditions, and converted back to a color in the same or other viewing
+//! conditions to predict corresponding colors.
+//!
+//! The uniform color space derived from the model, CAM16-UCS, is available as the [`Cam16Ucs`]
+//! color space, using [the reference viewing conditions of sRGB](ViewingConditions::SRGB).
+//! Color differences in this space can be computed with [`delta_e::cam16_ucs`].
+//!
+//! The model is defined by [Li et al.][paper] in "Comprehensive color solutions: CAM16, CAT16,
+//! and CAM16-UCS".
+//!
+//! # Example
+//!
+//! ```rust
+//! use color::cam16::{Cam16, Surround, ViewingConditions};
+//! use color::{Chromaticity, OpaqueColor, Srgb};
+//!
+//! let orange = OpaqueColor::<Srgb>::new([1., 0.5, 0.]);
+//! let office = ViewingConditions::SRGB;
+//! let cinema = ViewingConditions::new(Chromaticity::D65, 10., 20., Surround::Dark);
+//!
+//! // The same color appears lighter in a dark surround.
+//! let in_office = Cam16::from_color(orange, &office);
+//! let in_cinema = Cam16::from_color(orange, &cinema);
+//! assert!(in_cinema.lightness > in_office.lightness);
+//!
+//! // The color to show in the cinema for it to appear as it does in the office.
+//! let corresponding = in_office.to_color::<Srgb>(&cinema);
+//! ```
+//!
+//! [`delta_e::cam16_ucs`]: crate::delta_e::cam16_ucs
+//! [paper]: https://doi.org/10.1002/col.22131
+
+use crate::{Chromaticity, ColorSpace, OpaqueColor, XyzD65, matvecmul};
+
+#[cfg(all(not(feature = "std"), not(test)))]
+use crate::floatfuncs::FloatFuncs;
+
+/// The CAT16 matrix, from XYZ to the sharpened cone responses of CAM16.
+const XYZ_TO_CAT16: [[f32; 3]; 3] = [
+    [0.401_288, 0.650_173, -0.051_461],
+    [-0.250_268, 1.204_414, 0.045_854],
+    [-0.002_079, 0.048_952, 0.953_127],
+];
+
+/// The inverse of [`XYZ_TO_CAT16`].
+const CAT16_TO_XYZ: [[f32; 3]; 3] = [
+    [1.862_067_9, -1.011_254_6, 0.149_186_78],
+    [0.387_526_54, 0.621_447_44, -0.008_973_985],
+    [-0.015_841_499, -0.034_122_94, 1.049_964_4],
+];
+
+/// The relative luminance of the surround of a viewing environment.
+///
+/// This compares the luminance of the area around the viewed field to that of the white.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Surround {
+    /// A surround at least 20% as luminous as the white, such as a surface color viewed in a
+    /// light booth or a display in a lit office.
+    #[default]
+    Average,
+    /// A surround less than 20% as luminous as the white, such as television viewed in a dim
+    /// room.
+    Dim,
+    /// A surround without light, such as a projector in a dark room.
+    Dark,
+}
+
+impl Surround {
+    /// The factor `F` determining the degree of adaptation, the impact of the surround `c`, and
+    /// the chromatic induction factor `Nc`.
+    const fn factors(self) -> (f32, f32, f32) {
+        match self {
+            Self::Average => (1.0, 0.69, 1.0),
+            Self::Dim => (0.9, 0.59, 0.9),
+            Self::Dark => (0.8, 0.525, 0.8),
+        }
+    }
+}
+
+/// The viewing conditions of the CAM16 color appearance model.
+///
+/// These are given by
+/// - the white point the observer is adapted to;
+/// - the adapting luminance `La`, the luminance of the adapting field in cd/m², commonly taken as
+///   20% of the luminance of the white;
+/// - the background luminance `Yb`, the luminance of the background relative to the white, in
+///   percent; and
+/// - the [`Surround`].
+///
+/// The conditions are precomputed on construction, so they are cheap to reuse.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ViewingConditions {
+    /// The degree of adaptation for each cone response, divided by the response to the white.
+    rgb_d: [f32; 3],
+    /// The luminance level adaptation factor `Fl`.
+    fl: f32,
+    /// The fourth root of `Fl`.
+    fl_root: f32,
+    /// The background induction factor `n`.
+    n: f32,
+    /// The base exponential nonlinearity `z`.
+    z: f32,
+    /// The background and chromatic brightness induction factors `Nbb` and `Ncb`, which are the
+    /// same.
+    nbb: f32,
+    /// The impact of the surround `c`.
+    c: f32,
+    /// The chromatic induction factor `Nc`.
+    nc: f32,
+    /// The achromatic response to the white `Aw`.
+    aw: f32,
+    /// The factor `(1.64 - 0.29^n)^0.73` relating chroma to the magnitude `t`.
+    chroma_factor: f32,
+}
+
+impl ViewingConditions {
+    /// The reference viewing conditions of sRGB.
+    ///
+    /// This is a D65 white point, an adapting luminance of 64 lux / π / 5 ≈ 4.07 cd/m², a
+    /// background luminance of 20% and an average surround, as specified in IEC 61966-2-1. These
+    /// are the conditions used by [`Cam16Ucs`].
+    pub const SRGB: Self = Self {
+        rgb_d: [1.020_856_2, 0.986_514_15, 0.934_856_9],
+        fl: 0.273_130_54,
+        fl_root: 0.722_923_9,
+        n: 0.2,
+        z: 1.927_213_6,
+        nbb: 1.000_304,
+        c: 0.69,
+        nc: 1.0,
+        aw: 25.518_496,
+        chroma_factor: 0.895_217_9,
+    };
+
+    /// The viewing conditions of [HCT](crate::hct).
+    ///
+    /// As in Material Design, this is a D65 white point, a background with an `L*` of 50, an
+    /// adapting luminance of 200 / π times the relative luminance of that background, and an
+    /// average surround.
+    pub const HCT: Self = Self {
+        rgb_d: [1.021_193_1, 0.986_296_3, 0.933_804_6],
+        fl: 0.388_481_45,
+        fl_root: 0.789_482_6,
+        n: 0.184_186_52,
+        z: 1.909_169_6,
+        nbb: 1.016_919_2,
+        c: 0.69,
+        nc: 1.0,
+        aw: 29.980_991,
+        chroma_factor: 0.883_452_6,
+    };
+
+    /// Create viewing conditions.
+    ///
+    /// The adapting luminance is in cd/m², and the background luminance is in percent of the
+    /// luminance of the white. The observer is assumed to be incompletely adapted to the white
+    /// point, as for self-luminous displays and most surface colors.
+    #[must_use]
+    pub fn new(
+        white_point: Chromaticity,
+        adapting_luminance: f32,
+        background_luminance: f32,
+        surround: Surround,
+    ) -> Self {
+        let (f, c, nc) = surround.factors();
+        let la = adapting_luminance;
+        let k = 1. / (5. * la + 1.);
+        let k4 = (k * k) * (k * k);
+        let fl = 0.2 * k4 * (5. * la) + 0.1 * (1. - k4) * (1. - k4) * (5. * la).cbrt();
+        let n = background_luminance * 0.01;
+        let z = 1.48 + n.sqrt();
+        let nbb = 0.725 * n.powf(-0.2);
+        let d = (f * (1. - (1. / 3.6) * ((-la - 42.) / 92.).exp())).clamp(0., 1.);
+
+        let rgb_w = matvecmul(&XYZ_TO_CAT16, white_point.to_xyz().map(|x| x * 100.));
+        let rgb_d = rgb_w.map(|x| d * 100. / x + 1. - d);
+        let [r, g, b] = [0, 1, 2].map(|i| adapt(rgb_d[i] * rgb_w[i], fl));
+        let aw = (2. * r + g + 0.05 * b) * nbb;
+
+        Self {
+            rgb_d,
+            fl,
+            fl_root: fl.sqrt().sqrt(),
+            n,
+            z,
+            nbb,
+            c,
+            nc,
+            aw,
+            chroma_factor: (1.64 - 0.29_f32.powf(n)).powf(0.73),
+        }
+    }
+}
+
+impl Default for ViewingConditions {
+    fn default() -> Self {
+        Self::SRGB
+    }
+}
+
+/// The nonlinear post-adaptation cone response compression.
+fn adapt(x: f32, fl: f32) -> f32 {
+    let f = (fl * x.abs() * 0.01).powf(0.42);
+    (400. * f / (f + 27.13)).copysign(x)
+}
+
+/// The inverse of [`adapt`].
+fn unadapt(x: f32, fl: f32) -> f32 {
+    let x_abs = x.abs();
+    (100. / fl * (27.13 * x_abs / (400. - x_abs)).powf(1. / 0.42)).copysign(x)
+}
+
+/// The appearance correlates of a color in the CAM16 color appearance model.
+///
+/// The correlates are relative to [`ViewingConditions`], which are not stored here. Converting
+/// back to a color requires the same conditions, or different conditions to find the color with
+/// the same appearance in those.
+///
+/// CAM16 is not defined for negative tristimulus values. Such colors result in a lightness of 0.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Cam16 {
+    /// The lightness `J`, where 0 is black and 100 is the white of the viewing conditions.
+    pub lightness: f32,
+    /// The chroma `C`, the colorfulness relative to the brightness of the white.
+    pub chroma: f32,
+    /// The hue angle `h` in degrees.
+    pub hue: f32,
+    /// The colorfulness `M`, the absolute perceived chromatic intensity.
+    pub colorfulness: f32,
+    /// The saturation `s`, the colorfulness relative to the color's own brightness.
+    pub saturation: f32,
+    /// The brightness `Q`, the absolute perceived intensity.
+    pub brightness: f32,
+}
+
+impl Cam16 {
+    /// Calculate the appearance of a color given as CIE XYZ tristimulus values.
+    ///
+    /// The values are scaled such that a `Y` of 1 is the luminance of the white of the viewing
+    /// conditions. They are not chromatically adapted: the model performs the adaptation to the
+    /// white point of the viewing conditions.
+    #[must_use]
+    pub fn from_xyz(xyz: [f32; 3], conditions: &ViewingConditions) -> Self {
+        let rgb = matvecmul(&XYZ_TO_CAT16, xyz.map(|x| x * 100.));
+        let [r, g, b] = [0, 1, 2].map(|i| adapt(conditions.rgb_d[i] * rgb[i], conditions.fl));
+
+        let a = r - 12. / 11. * g + 1. / 11. * b;
+        let b_ = (r + g - 2. * b) * (1. / 9.);
+        let mut hue = b_.atan2(a).to_degrees();
+        if hue < 0. {
+            hue += 360.;
+        }
+
+        let achromatic = ((2. * r + g + 0.05 * b) * conditions.nbb).max(0.);
+        let lightness = 100. * (achromatic / conditions.aw).powf(conditions.c * conditions.z);
+
+        let e_t = 0.25 * ((hue.to_radians() + 2.).sin_cos().1 + 3.8);
+        let t = 50_000. / 13. * conditions.nc * conditions.nbb * e_t * a.hypot(b_)
+            / (r + g + 1.05 * b + 0.305);
+        let chroma = t.powf(0.9) * (lightness * 0.01).sqrt() * conditions.chroma_factor;
+
+        Self::from_jch(lightness, chroma, hue, conditions)
+    }
+
+    /// Calculate the appearance of a color.
+    ///
+    /// The color is converted to XYZ without chromatic adaptation, see
+    /// [`ColorSpace::convert_absolute`]. Its luminance is relative to that of the white of the
+    /// viewing conditions.
+    #[must_use]
+    pub fn from_color<CS: ColorSpace>(
+        color: OpaqueColor<CS>,
+        conditions: &ViewingConditions,
+    ) -> Self {
+        Self::from_xyz(CS::convert_absolute::<XyzD65>(color.components), conditions)
+    }
+
+    /// Create appearance correlates from lightness `J`, chroma `C` and hue angle `h`.
+    ///
+    /// The other correlates are derived from these.
+    #[must_use]
+    pub fn from_jch(lightness: f32, chroma: f32, hue: f32, conditions: &ViewingConditions) -> Self {
+        let brightness = 4. / conditions.c
+            * (lightness * 0.01).sqrt()
+            * (conditions.aw + 4.)
+            * conditions.fl_root;
+        let colorfulness = chroma * conditions.fl_root;
+        let saturation = if brightness > 0. {
+            100. * (colorfulness / brightness).sqrt()
+        } else {
+            0.
+        };
+        Self {
+            lightness,
+            chroma,
+            hue,
+            colorfulness,
+            saturation,
+            brightness,
+        }
+    }
+
+    /// Create appearance correlates from lightness `J`, colorfulness `M` and hue angle `h`.
+    ///
+    /// The other correlates are derived from these.
+    #[must_use]
+    pub fn from_jmh(
+        lightness: f32,
+        colorfulness: f32,
+        hue: f32,
+        conditions: &ViewingConditions,
+    ) -> Self {
+        Self::from_jch(
+            lightness,
+            colorfulness / conditions.fl_root,
+            hue,
+            conditions,
+        )
+    }
+
+    /// Create appearance correlates from CAM16-UCS coordinates `[J', a', b']`.
+    ///
+    /// This is the inverse of [`Cam16::to_ucs`].
+    #[must_use]
+    pub fn from_ucs([j, a, b]: [f32; 3], conditions: &ViewingConditions) -> Self {
+        let lightness = j / (1.7 - 0.007 * j);
+        let colorfulness = ((0.0228 * a.hypot(b)).exp() - 1.) * (1. / 0.0228);
+        let mut hue = b.atan2(a).to_degrees();
+        if hue < 0. {
+            hue += 360.;
+        }
+        Self::from_jmh(lightness, colorfulness, hue, conditions)
+    }
+
+    /// Calculate the CIE XYZ tristimulus values of the color with this appearance.
+    ///
+    /// This uses the lightness, chroma and hue angle. The values are scaled as in
+    /// [`Cam16::from_xyz`].
+    #[must_use]
+    pub fn to_xyz(&self, conditions: &ViewingConditions) -> [f32; 3] {
+        if self.lightness <= 0. {
+            return [0.; 3];
+        }
+        let j_root = (self.lightness * 0.01).sqrt();
+        let t = (self.chroma / (j_root * conditions.chroma_factor)).powf(1. / 0.9);
+        let (sin, cos) = self.hue.to_radians().sin_cos();
+        let e_t = 0.25 * ((self.hue.to_radians() + 2.).sin_cos().1 + 3.8);
+        let achromatic =
+            conditions.aw * (self.lightness * 0.01).powf(1. / (conditions.c * conditions.z));
+
+        // Solve the achromatic response `p2` and the opponent color dimensions for the cone
+        // responses.
+        let p2 = achromatic / conditions.nbb;
+        let k = 50_000. / 13. * conditions.nc * conditions.nbb * e_t;
+        let gamma = 23. * t * (p2 + 0.305) / (23. * k + t * (11. * cos + 108. * sin));
+        let a = gamma * cos;
+        let b = gamma * sin;
+        let rgb = [
+            (460. * p2 + 451. * a + 288. * b) * (1. / 1403.),
+            (460. * p2 - 891. * a - 261. * b) * (1. / 1403.),
+            (460. * p2 - 220. * a - 6300. * b) * (1. / 1403.),
+        ];
+
+        let rgb = [0, 1, 2].map(|i| unadapt(rgb[i], conditions.fl) / conditions.rgb_d[i]);
+        matvecmul(&CAT16_TO_XYZ, rgb).map(|x| x * 0.01)
+    }
+
+    /// Calculate the color with this appearance.
+    ///
+    /// This is the inverse of [`Cam16::from_color`].
+    #[must_use]
+    pub fn to_color<CS: ColorSpace>(&self, conditions: &ViewingConditions) -> OpaqueColor<CS> {
+        OpaqueColor::new(XyzD65::convert_absolute::<CS>(self.to_xyz(conditions)))
+    }
+
+    /// Calculate the CAM16-UCS coordinates `[J', a', b']` of this appearance.
+    ///
+    /// See [`Cam16Ucs`] for the definition of the coordinates.
+    #[must_use]
+    pub fn to_ucs(&self) -> [f32; 3] {
+        let j = 1.7 * self.lightness / (1. + 0.007 * self.lightness);
+        let m = (1. + 0.0228 * self.colorfulness).ln() * (1. / 0.0228);
+        let (sin, cos) = self.hue.to_radians().sin_cos();
+        [j, m * cos, m * sin]
+    }
+}
+
+/// 🌌 The CAM16-UCS uniform color space.
+///
+/// Its components are `[J', a', b']` with
+/// - `J'` - the lightness, where 0 represents pure black and 100 the lightness of white;
+/// - `a'` - how green/red the color is; and
+/// - `b'` - how blue/yellow the color is.
+///
+/// `a'` and `b'` are unbounded, but are usually between -50 and 50.
+///
+/// This color space is derived from the [CAM16](self) color appearance model in the
+/// [reference viewing conditions of sRGB](ViewingConditions::SRGB), compressing lightness and
+/// colorfulness such that Euclidean distances match perceived color differences. As the observer
+/// is not completely adapted to the white point in these conditions, white has a slight
+/// chromatic component.
+///
+/// For other viewing conditions, use [`Cam16::to_ucs`] and [`Cam16::from_ucs`].
+#[derive(Clone, Copy, Debug)]
+pub struct Cam16Ucs;
+
+impl ColorSpace for Cam16Ucs {
+    const WHITE_COMPONENTS: [f32; 3] = [100., -1.898_250_8, -1.075_437];
+
+    fn to_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        let conditions = ViewingConditions::SRGB;
+        XyzD65::to_linear_srgb(Cam16::from_ucs(src, &conditions).to_xyz(&conditions))
+    }
+
+    fn from_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        Cam16::from_xyz(XyzD65::from_linear_srgb(src), &ViewingConditions::SRGB).to_ucs()
+    }
+
+    fn scale_chroma([j, a, b]: [f32; 3], scale: f32) -> [f32; 3] {
+        [j, a * scale, b * scale]
+    }
+
+    fn clip([j, a, b]: [f32; 3]) -> [f32; 3] {
+        [j.clamp(0., 100.), a, b]
+    }
+
+    fn is_in_gamut(_src: [f32; 3], _epsilon: f32) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Cam16, Cam16Ucs, Surround, ViewingConditions};
+    use crate::{Chromaticity, ColorSpace, OpaqueColor, Srgb};
+
+    #[test]
+    fn precomputed_conditions() {
+        // L* of 50 corresponds to a relative luminance of (66 / 116)^3.
+        let hct_background = 100. * (66_f32 / 116.).powi(3);
+        for (computed, expected) in [
+            (
+                ViewingConditions::new(
+                    Chromaticity::D65,
+                    64. / core::f32::consts::PI / 5.,
+                    20.,
+                    Surround::Average,
+                ),
+                ViewingConditions::SRGB,
+            ),
+            (
+                ViewingConditions::new(
+                    Chromaticity::D65,
+                    2. / core::f32::consts::PI * hct_background,
+                    hct_background,
+                    Surround::Average,
+                ),
+                ViewingConditions::HCT,
+            ),
+        ] {
+            check_conditions(computed, expected);
+        }
+    }
+
+    fn check_conditions(computed: ViewingConditions, expected: ViewingConditions) {
+        for (computed, expected) in [
+            (computed.rgb_d[0], expected.rgb_d[0]),
+            (computed.rgb_d[1], expected.rgb_d[1]),
+            (computed.rgb_d[2], expected.rgb_d[2]),
+            (computed.fl, expected.fl),
+            (computed.fl_root, expected.fl_root),
+            (computed.n, expected.n),
+            (computed.z, expected.z),
+            (computed.nbb, expected.nbb),
+            (computed.aw, expected.aw),
+            (computed.chroma_factor, expected.chroma_factor),
+        ] {
+            assert!(
+                (computed - expected).abs() < 1e-5 * expected.abs().max(1.),
+                "expected {expected}, got {computed}"
+            );
+        }
+    }
+
+    #[test]
+    fn reference_values() {
+        // Calculated with colour-science (https://github.com/colour-science/colour).
+        let conditions = ViewingConditions::new(
+            Chromaticity {
+                x: 95.05 / 303.93,
+                y: 100. / 303.93,
+            },
+            318.31,
+            20.,
+            Surround::Average,
+        );
+        let xyz = [0.1901, 0.2, 0.2178];
+        let cam = Cam16::from_xyz(xyz, &conditions);
+        let expected = [
+            41.731_21, 0.103_356, 217.067_96, 0.107_437, 2.345_015, 195.371_7,
+        ];
+        let actual = [
+            cam.lightness,
+            cam.chroma,
+            cam.hue,
+            cam.colorfulness,
+            cam.saturation,
+            cam.brightness,
+        ];
+        for (actual, expected) in actual.into_iter().zip(expected) {
+            assert!(
+                (actual - expected).abs() < 1e-3 * expected.max(1.),
+                "expected {expected}, got {actual}"
+            );
+        }
+
+        let roundtripped = cam.to_xyz(&conditions);
+        for (actual, expected) in roundtripped.into_iter().zip(xyz) {
+            assert!((actual - expected).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn roundtrip() {
+        let conditions = [
+            ViewingConditions::SRGB,
+            ViewingConditions::new(Chromaticity::D50, 200., 18., Surround::Dim),
+            ViewingConditions::new(Chromaticity::ACES, 10., 5., Surround::Dark),
+        ];
+        for conditions in conditions {
+            for rgb in [
+                [1., 0., 0.],
+                [0.2, 0.5, 0.1],
+                [0.9, 0.9, 0.95],
+                [0.01, 0., 0.02],
+            ] {
+                let color = OpaqueColor::<Srgb>::new(rgb);
+                let cam = Cam16::from_color(color, &conditions);
+                let roundtripped = cam.to_color::<Srgb>(&conditions);
+                assert!(color.difference(roundtripped) < 1e-4);
+
+                let from_jmh =
+                    Cam16::from_jmh(cam.lightness, cam.colorfulness, cam.hue, &conditions);
+                assert!((from_jmh.chroma - cam.chroma).abs() < 1e-4);
+                let from_ucs = Cam16::from_ucs(cam.to_ucs(), &conditions);
+                assert!((from_ucs.lightness - cam.lightness).abs() < 1e-3);
+                assert!((from_ucs.colorfulness - cam.colorfulness).abs() < 1e-3);
+            }
+        }
+    }
+
+    #[test]
+    fn ucs() {
+        // Reference values calculated in double precision from the definition.
+        for (rgb, ucs) in [
+            ([1., 0., 0.], [59.177_795, 40.819_794, 21.152_876]),
+            ([0., 0., 1.], [36.251_77, 8.572_26, -37.870_052]),
+            ([0.2, 0.5, 0.1], [71.326_82, -20.892_183, 18.754_93]),
+        ] {
+            let actual = Cam16Ucs::from_linear_srgb(rgb);
+            assert!(
+                OpaqueColor::<Cam16Ucs>::new(actual).difference(OpaqueColor::new(ucs)) < 1e-3,
+                "expected {ucs:?}, got {actual:?}"
+            );
+        }
+
+        let white = Srgb::convert::<Cam16Ucs>([1., 1., 1.]);
+        assert!(
+            OpaqueColor::<Cam16Ucs>::new(white)
+                .difference(OpaqueColor::new(Cam16Ucs::WHITE_COMPONENTS))
+                < 1e-3
+        );
+    }
+}
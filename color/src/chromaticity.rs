@@ -1,7 +1,11 @@
 // Copyright 2024 the Color Authors
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use crate::{matdiagmatmul, matmatmul, matvecmul};
+use crate::colorspace::XYZ_D65_TO_LINEAR_SRGB;
+use crate::{matdiagmatmul, matinv, matmatmul, matvecmul};
+
+#[cfg(all(not(feature = "std"), not(test)))]
+use crate::floatfuncs::FloatFuncs;
 
 /// CIE `xy` chromaticity, specifying a color in the XYZ color space, but not its luminosity.
 ///
@@ -54,12 +58,446 @@ impl Chromaticity {
         y: 0.33767,
     };
 
+    /// The CIE standard illuminant A, representing incandescent lighting.
+    ///
+    /// This is a Planckian radiator of about 2856 K. The chromaticities of this and the
+    /// following CIE illuminants are given to five digits under the standard 2° observer, as in
+    /// [CIE 15:2018][cie15].
+    ///
+    /// [cie15]: https://doi.org/10.25039/TR.015.2018
+    pub const A: Self = Self {
+        x: 0.44757,
+        y: 0.40745,
+    };
+
+    /// The CIE illuminant B, representing noon sunlight, at about 4874 K.
+    pub const B: Self = Self {
+        x: 0.34842,
+        y: 0.35161,
+    };
+
+    /// The CIE illuminant C, representing average daylight, at about 6774 K.
+    pub const C: Self = Self {
+        x: 0.31006,
+        y: 0.31616,
+    };
+
+    /// The CIE illuminant D55, representing mid-morning and mid-afternoon daylight.
+    pub const D55: Self = Self {
+        x: 0.33242,
+        y: 0.34743,
+    };
+
+    /// The CIE illuminant D75, representing north sky daylight.
+    pub const D75: Self = Self {
+        x: 0.29902,
+        y: 0.31485,
+    };
+
+    /// The CIE illuminant E, with equal energy at all wavelengths.
+    pub const E: Self = Self {
+        x: 1. / 3.,
+        y: 1. / 3.,
+    };
+
+    /// The CIE illuminant F1, a daylight fluorescent lamp, at about 6430 K.
+    pub const F1: Self = Self {
+        x: 0.31310,
+        y: 0.33727,
+    };
+
+    /// The CIE illuminant F2, a cool white fluorescent lamp, at about 4230 K.
+    pub const F2: Self = Self {
+        x: 0.37208,
+        y: 0.37529,
+    };
+
+    /// The CIE illuminant F3, a white fluorescent lamp, at about 3450 K.
+    pub const F3: Self = Self {
+        x: 0.40910,
+        y: 0.39430,
+    };
+
+    /// The CIE illuminant F4, a warm white fluorescent lamp, at about 2940 K.
+    pub const F4: Self = Self {
+        x: 0.44018,
+        y: 0.40329,
+    };
+
+    /// The CIE illuminant F5, a daylight fluorescent lamp, at about 6350 K.
+    pub const F5: Self = Self {
+        x: 0.31379,
+        y: 0.34531,
+    };
+
+    /// The CIE illuminant F6, a light white fluorescent lamp, at about 4150 K.
+    pub const F6: Self = Self {
+        x: 0.37790,
+        y: 0.38835,
+    };
+
+    /// The CIE illuminant F7, a broadband fluorescent lamp simulating D65.
+    pub const F7: Self = Self {
+        x: 0.31292,
+        y: 0.32933,
+    };
+
+    /// The CIE illuminant F8, a broadband fluorescent lamp simulating D50.
+    pub const F8: Self = Self {
+        x: 0.34588,
+        y: 0.35875,
+    };
+
+    /// The CIE illuminant F9, a broadband cool white deluxe fluorescent lamp, at about 4150 K.
+    pub const F9: Self = Self {
+        x: 0.37417,
+        y: 0.37281,
+    };
+
+    /// The CIE illuminant F10, a narrow tri-band fluorescent lamp, at about 5000 K.
+    pub const F10: Self = Self {
+        x: 0.34609,
+        y: 0.35986,
+    };
+
+    /// The CIE illuminant F11, a narrow tri-band fluorescent lamp, at about 4000 K.
+    pub const F11: Self = Self {
+        x: 0.38052,
+        y: 0.37713,
+    };
+
+    /// The CIE illuminant F12, a narrow tri-band fluorescent lamp, at about 3000 K.
+    pub const F12: Self = Self {
+        x: 0.43695,
+        y: 0.40441,
+    };
+
+    /// The CIE illuminant LED-B1, a phosphor-converted blue LED, at about 2733 K.
+    ///
+    /// The chromaticities of the LED illuminants are given to four digits, as in CIE 15:2018.
+    pub const LED_B1: Self = Self {
+        x: 0.4560,
+        y: 0.4078,
+    };
+
+    /// The CIE illuminant LED-B2, a phosphor-converted blue LED, at about 2998 K.
+    pub const LED_B2: Self = Self {
+        x: 0.4357,
+        y: 0.4012,
+    };
+
+    /// The CIE illuminant LED-B3, a phosphor-converted blue LED, at about 4103 K.
+    pub const LED_B3: Self = Self {
+        x: 0.3756,
+        y: 0.3723,
+    };
+
+    /// The CIE illuminant LED-B4, a phosphor-converted blue LED, at about 5109 K.
+    pub const LED_B4: Self = Self {
+        x: 0.3422,
+        y: 0.3502,
+    };
+
+    /// The CIE illuminant LED-B5, a phosphor-converted blue LED, at about 6598 K.
+    pub const LED_B5: Self = Self {
+        x: 0.3118,
+        y: 0.3236,
+    };
+
+    /// The CIE illuminant LED-BH1, a mix of phosphor-converted blue and red LEDs, at about 2851 K.
+    pub const LED_BH1: Self = Self {
+        x: 0.4474,
+        y: 0.4066,
+    };
+
+    /// The CIE illuminant LED-RGB1, a mix of red, green and blue LEDs, at about 2840 K.
+    pub const LED_RGB1: Self = Self {
+        x: 0.4557,
+        y: 0.4211,
+    };
+
+    /// The CIE illuminant LED-V1, a phosphor-converted violet LED, at about 2724 K.
+    pub const LED_V1: Self = Self {
+        x: 0.4548,
+        y: 0.4044,
+    };
+
+    /// The CIE illuminant LED-V2, a phosphor-converted violet LED, at about 4070 K.
+    pub const LED_V2: Self = Self {
+        x: 0.3781,
+        y: 0.3775,
+    };
+
+    /// The chromaticity on the Planckian locus at the given correlated color temperature, in
+    /// kelvin.
+    ///
+    /// This is the color of a black body radiator at that temperature, such as incandescent
+    /// light. It uses the approximation of the locus by [Krystek (1985)][krystek], which is
+    /// accurate from 1000 K to 15000 K.
+    ///
+    /// See [`Chromaticity::daylight`] for the daylight locus, on which the D series of illuminants
+    /// lie, and [`Chromaticity::from_cct_and_duv`] to add a tint.
+    ///
+    /// [krystek]: https://doi.org/10.1002/col.5080100109
+    ///
+    /// ```rust
+    /// use color::Chromaticity;
+    ///
+    /// let warm_white = Chromaticity::from_cct(2700.);
+    /// let (cct, duv) = warm_white.cct_and_duv().unwrap();
+    /// assert!((cct - 2700.).abs() < 1. && duv.abs() < 1e-6);
+    /// ```
+    #[must_use]
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "the locus is calculated in f64, then reduced to f32"
+    )]
+    pub fn from_cct(cct: f32) -> Self {
+        let [u, v] = planckian_uv(1e6 / f64::from(cct));
+        Self::from_uv([u as f32, v as f32])
+    }
+
+    /// The chromaticity at the given correlated color temperature, in kelvin, offset from the
+    /// Planckian locus by the distance `duv` in the CIE 1960 `uv` chromaticity diagram.
+    ///
+    /// A positive `duv` is above the locus, towards green, and a negative `duv` is below it,
+    /// towards magenta. This is the tint of white balance controls; typical light sources have a
+    /// `duv` within ±0.02.
+    ///
+    /// This is the inverse of [`Chromaticity::cct_and_duv`].
+    #[must_use]
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "the locus is calculated in f64, then reduced to f32"
+    )]
+    pub fn from_cct_and_duv(cct: f32, duv: f32) -> Self {
+        let mired = 1e6 / f64::from(cct);
+        let [u, v] = planckian_uv(mired);
+        let [nu, nv] = planckian_normal(mired);
+        let duv = f64::from(duv);
+        Self::from_uv([(u + duv * nu) as f32, (v + duv * nv) as f32])
+    }
+
+    /// The chromaticity on the CIE daylight locus at the given correlated color temperature, in
+    /// kelvin.
+    ///
+    /// The daylight locus is defined from 4000 K to 25000 K. The CIE D series of illuminants lie
+    /// on it, at temperatures slightly above their names, after a revision of the constants of
+    /// Planck's law: for example, D65 is at about 6504 K.
+    ///
+    /// ```rust
+    /// use color::Chromaticity;
+    ///
+    /// let daylight = Chromaticity::daylight(6504.);
+    /// assert!((daylight.x - Chromaticity::D65.x).abs() < 2e-4);
+    /// assert!((daylight.y - Chromaticity::D65.y).abs() < 2e-4);
+    /// ```
+    #[must_use]
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "the locus is calculated in f64, then reduced to f32"
+    )]
+    pub fn daylight(cct: f32) -> Self {
+        let t_recip = 1. / f64::from(cct);
+        let [a3, a2, a1, a0] = if cct <= 7000. {
+            [-4.6070e9, 2.9678e6, 0.09911e3, 0.244_063]
+        } else {
+            [-2.0064e9, 1.9018e6, 0.24748e3, 0.237_040]
+        };
+        let x = ((a3 * t_recip + a2) * t_recip + a1) * t_recip + a0;
+        let y = (-3. * x + 2.870) * x - 0.275;
+        Self {
+            x: x as f32,
+            y: y as f32,
+        }
+    }
+
+    /// The correlated color temperature, in kelvin, and the distance `duv` from the Planckian
+    /// locus in the CIE 1960 `uv` chromaticity diagram.
+    ///
+    /// The correlated color temperature is that of the closest point on the Planckian locus. The
+    /// distance `duv` is positive above the locus, towards green, and negative below it, towards
+    /// magenta. The correlated color temperature is only meaningful for chromaticities close to
+    /// the locus, with a `duv` within ±0.05.
+    ///
+    /// This is the inverse of [`Chromaticity::from_cct_and_duv`]. Rather than interpolating in a
+    /// table of the locus, as the methods of Robertson (1968) and Ohno (2013) do, it finds the
+    /// closest point on the same approximation of the locus as [`Chromaticity::from_cct`] by
+    /// Newton's method. This makes the two exact inverses of each other, to within 0.01% of the
+    /// temperature.
+    ///
+    /// The approximation is only accurate from 1000 K to 15000 K, so this returns `None` for
+    /// chromaticities whose closest point on the locus is outside that range.
+    ///
+    /// ```rust
+    /// use color::Chromaticity;
+    ///
+    /// let (cct, duv) = Chromaticity::D65.cct_and_duv().unwrap();
+    /// assert!((cct - 6504.).abs() < 5.);
+    /// assert!((duv - 0.0032).abs() < 1e-4);
+    /// ```
+    #[must_use]
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "the locus is calculated in f64, then reduced to f32"
+    )]
+    pub fn cct_and_duv(self) -> Option<(f32, f32)> {
+        let [u, v] = self.to_uv().map(f64::from);
+
+        // Start from the approximation of McCamy (1992), then find the closest point on the locus
+        // by Newton's method, parametrized by the reciprocal temperature.
+        let n = (f64::from(self.x) - 0.3320) / (f64::from(self.y) - 0.1858);
+        let cct = ((-449. * n + 3525.) * n - 6823.3) * n + 5520.33;
+        // The range of the approximation of the locus, from 15000 K to 1000 K. The search covers
+        // a wider range, to tell chromaticities beyond the ends from those at the ends.
+        const MIRED_RANGE: (f64, f64) = (1e6 / 15000., 1e6 / 1000.);
+        const SEARCH_RANGE: (f64, f64) = (1., 2000.);
+        let mut mired = if cct > 0. {
+            (1e6 / cct).clamp(MIRED_RANGE.0, MIRED_RANGE.1)
+        } else {
+            MIRED_RANGE.1
+        };
+        const H: f64 = 1e-3;
+        for _ in 0..8 {
+            let [u0, v0] = planckian_uv(mired - H);
+            let [u1, v1] = planckian_uv(mired);
+            let [u2, v2] = planckian_uv(mired + H);
+            let (du, dv) = ((u2 - u0) / (2. * H), (v2 - v0) / (2. * H));
+            let (ddu, ddv) = ((u2 - 2. * u1 + u0) / (H * H), (v2 - 2. * v1 + v0) / (H * H));
+            let (eu, ev) = (u1 - u, v1 - v);
+            let step = (eu * du + ev * dv) / (du * du + dv * dv + eu * ddu + ev * ddv);
+            mired = (mired - step).clamp(SEARCH_RANGE.0, SEARCH_RANGE.1);
+        }
+        // Allow for rounding errors at the ends of the range.
+        if mired < MIRED_RANGE.0 * (1. - 1e-5) || mired > MIRED_RANGE.1 * (1. + 1e-5) {
+            return None;
+        }
+
+        let [lu, lv] = planckian_uv(mired);
+        let [nu, nv] = planckian_normal(mired);
+        let duv = (u - lu) * nu + (v - lv) * nv;
+        Some(((1e6 / mired) as f32, duv as f32))
+    }
+
+    /// Convert to CIE 1960 `uv` chromaticity coordinates, as used for correlated color
+    /// temperature.
+    #[must_use]
+    pub const fn to_uv(self) -> [f32; 2] {
+        let d_recip = 1. / (-2. * self.x + 12. * self.y + 3.);
+        [4. * self.x * d_recip, 6. * self.y * d_recip]
+    }
+
+    /// Convert from CIE 1960 `uv` chromaticity coordinates.
+    #[must_use]
+    pub const fn from_uv([u, v]: [f32; 2]) -> Self {
+        let d_recip = 1. / (2. * u - 8. * v + 4.);
+        Self {
+            x: 3. * u * d_recip,
+            y: 2. * v * d_recip,
+        }
+    }
+
+    /// Convert to CIE 1976 `u′v′` chromaticity coordinates, as used by [CIELUV](crate::Luv).
+    #[must_use]
+    pub const fn to_uv_prime(self) -> [f32; 2] {
+        let d_recip = 1. / (-2. * self.x + 12. * self.y + 3.);
+        [4. * self.x * d_recip, 9. * self.y * d_recip]
+    }
+
+    /// Convert from CIE 1976 `u′v′` chromaticity coordinates.
+    #[must_use]
+    pub const fn from_uv_prime([u, v]: [f32; 2]) -> Self {
+        let d_recip = 1. / (6. * u - 16. * v + 12.);
+        Self {
+            x: 9. * u * d_recip,
+            y: 4. * v * d_recip,
+        }
+    }
+
     /// Convert the `xy` chromaticities to XYZ, assuming `xyY` with `Y=1`.
     pub(crate) const fn to_xyz(self) -> [f32; 3] {
         let y_recip = 1. / self.y;
         [self.x * y_recip, 1., (1. - self.x - self.y) * y_recip]
     }
 
+    /// The chromaticity of an XYZ color.
+    #[cfg_attr(
+        not(any(feature = "icc", test)),
+        expect(dead_code, reason = "only used for ICC profiles")
+    )]
+    pub(crate) const fn from_xyz([x, y, z]: [f32; 3]) -> Self {
+        let sum_recip = 1. / (x + y + z);
+        Self {
+            x: x * sum_recip,
+            y: y * sum_recip,
+        }
+    }
+
+    /// Calculate the 3x3 chromatic adaptation matrix in XYZ space, using the given chromatic
+    /// adaptation transform.
+    ///
+    /// This calculates the matrix going from a reference white of `self` to a reference white of
+    /// `to`. The `degree` of adaptation is 1 for full adaptation to the white point `to`, and 0
+    /// for no adaptation. Values in between model the incomplete adaptation of an observer, as the
+    /// degree of adaptation `D` of CIECAM02 and CAM16.
+    ///
+    /// ```rust
+    /// use color::{AdaptationMethod, Chromaticity};
+    ///
+    /// let d65_to_d50 = Chromaticity::D65.chromatic_adaptation_matrix(
+    ///     Chromaticity::D50,
+    ///     AdaptationMethod::Cat16,
+    ///     1.,
+    /// );
+    /// // The luminance of white is preserved.
+    /// let white_d50 = d65_to_d50[1][0] * 0.3127 / 0.3290 + d65_to_d50[1][1]
+    ///     + d65_to_d50[1][2] * (1. - 0.3127 - 0.3290) / 0.3290;
+    /// assert!((white_d50 - 1.).abs() < 1e-5);
+    /// ```
+    #[must_use]
+    pub const fn chromatic_adaptation_matrix(
+        self,
+        to: Self,
+        method: AdaptationMethod,
+        degree: f32,
+    ) -> [[f32; 3]; 3] {
+        let xyz_to_cone = method.xyz_to_cone();
+        let source = matvecmul(&xyz_to_cone, self.to_xyz());
+        let dest = matvecmul(&xyz_to_cone, to.to_xyz());
+
+        matmatmul(
+            &matdiagmatmul(
+                &matinv(&xyz_to_cone),
+                [
+                    degree * dest[0] / source[0] + (1. - degree),
+                    degree * dest[1] / source[1] + (1. - degree),
+                    degree * dest[2] / source[2] + (1. - degree),
+                ],
+            ),
+            &xyz_to_cone,
+        )
+    }
+
+    /// Calculate the 3x3 chromatic adaptation matrix from linear sRGB space, using the given
+    /// chromatic adaptation transform.
+    ///
+    /// See [`Chromaticity::chromatic_adaptation_matrix`].
+    pub(crate) const fn linear_srgb_adaptation_matrix(
+        self,
+        to: Self,
+        method: AdaptationMethod,
+        degree: f32,
+    ) -> [[f32; 3]; 3] {
+        const LINEAR_SRGB_TO_XYZ_D65: [[f32; 3]; 3] = matinv(&XYZ_D65_TO_LINEAR_SRGB);
+        matmatmul(
+            &XYZ_D65_TO_LINEAR_SRGB,
+            &matmatmul(
+                &self.chromatic_adaptation_matrix(to, method, degree),
+                &LINEAR_SRGB_TO_XYZ_D65,
+            ),
+        )
+    }
+
     /// Calculate the 3x3 linear Bradford chromatic adaptation matrix from linear sRGB space.
     ///
     /// This calculates the matrix going from a reference white of `self` to a reference white of
@@ -125,3 +563,246 @@ impl Chromaticity {
         [0.0389, -0.0685, 1.0296],
     ];
 }
+
+/// A chromatic adaptation transform.
+///
+/// Chromatic adaptation transforms predict the color that looks the same under a different
+/// white point. They scale the responses of a cone space, with a matrix from XYZ that varies
+/// between the transforms, by the ratio of the responses to the white points.
+///
+/// See [`Chromaticity::chromatic_adaptation_matrix`] and
+/// [`ColorSpace::chromatically_adapt_with`](crate::ColorSpace::chromatically_adapt_with).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AdaptationMethod {
+    /// The linear Bradford transform.
+    ///
+    /// This is the transform used by default in this crate, as in CSS Color 4 and ICC profiles.
+    #[default]
+    Bradford,
+    /// The transform of the CIECAM02 color appearance model.
+    Cat02,
+    /// The transform of the [CAM16](crate::cam16) color appearance model.
+    Cat16,
+    /// The von Kries transform, using the Hunt-Pointer-Estévez cone space normalized to D65.
+    VonKries,
+    /// Scaling of the XYZ components, without a cone space.
+    XyzScaling,
+}
+
+impl AdaptationMethod {
+    /// The matrix converting XYZ to the cone space of the transform.
+    const fn xyz_to_cone(self) -> [[f32; 3]; 3] {
+        match self {
+            Self::Bradford => Chromaticity::XYZ_TO_BRADFORD,
+            Self::Cat02 => [
+                [0.7328, 0.4296, -0.1624],
+                [-0.7036, 1.6975, 0.0061],
+                [0.0030, 0.0136, 0.9834],
+            ],
+            Self::Cat16 => [
+                [0.401_288, 0.650_173, -0.051_461],
+                [-0.250_268, 1.204_414, 0.045_854],
+                [-0.002_079, 0.048_952, 0.953_127],
+            ],
+            Self::VonKries => [
+                [0.400_24, 0.707_6, -0.080_81],
+                [-0.2263, 1.165_32, 0.045_7],
+                [0., 0., 0.918_22],
+            ],
+            Self::XyzScaling => [[1., 0., 0.], [0., 1., 0.], [0., 0., 1.]],
+        }
+    }
+}
+
+/// The CIE 1960 `uv` chromaticity on the Planckian locus at a reciprocal temperature, in mireds.
+///
+/// This uses the rational approximation of Krystek (1985).
+fn planckian_uv(mired: f64) -> [f64; 2] {
+    let t = 1e6 / mired;
+    let u = (0.860_117_757 + 1.541_182_54e-4 * t + 1.286_412_12e-7 * t * t)
+        / (1. + 8.424_202_35e-4 * t + 7.081_451_63e-7 * t * t);
+    let v = (0.317_398_726 + 4.228_062_45e-5 * t + 4.204_816_91e-8 * t * t)
+        / (1. - 2.897_418_16e-5 * t + 1.614_560_53e-7 * t * t);
+    [u, v]
+}
+
+/// The unit normal of the Planckian locus in the CIE 1960 `uv` chromaticity diagram, pointing
+/// above the locus.
+fn planckian_normal(mired: f64) -> [f64; 2] {
+    const H: f64 = 1e-3;
+    let [u0, v0] = planckian_uv(mired - H);
+    let [u1, v1] = planckian_uv(mired + H);
+    // The temperature increases towards lower `u`, and reciprocal temperature the other way.
+    let (du, dv) = (u1 - u0, v1 - v0);
+    let len = du.hypot(dv);
+    [-dv / len, du / len]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AdaptationMethod, Chromaticity};
+    use crate::{AlphaColor, ColorSpace, ColorSpaceTag, DynamicColor, Srgb, XyzD65, matvecmul};
+
+    fn assert_close(actual: Chromaticity, expected: Chromaticity, epsilon: f32) {
+        assert!(
+            (actual.x - expected.x).abs() < epsilon && (actual.y - expected.y).abs() < epsilon,
+            "expected {expected:?}, got {actual:?}"
+        );
+    }
+
+    #[test]
+    fn loci() {
+        assert_close(Chromaticity::from_cct(2856.), Chromaticity::A, 5e-4);
+        // The illuminants are computed from tabulated spectra, which differ slightly from the
+        // locus.
+        assert_close(Chromaticity::daylight(5003.), Chromaticity::D50, 2e-4);
+        assert_close(Chromaticity::daylight(5503.), Chromaticity::D55, 2e-4);
+        assert_close(Chromaticity::daylight(6504.), Chromaticity::D65, 2e-4);
+        assert_close(Chromaticity::daylight(7504.), Chromaticity::D75, 2e-4);
+    }
+
+    #[test]
+    fn cct_and_duv() {
+        // The correlated color temperatures given by CIE 15:2018.
+        for (chromaticity, expected) in [
+            (Chromaticity::A, 2856.),
+            (Chromaticity::D65, 6504.),
+            (Chromaticity::F2, 4230.),
+            (Chromaticity::F11, 4000.),
+            (Chromaticity::LED_B3, 4103.),
+            (Chromaticity::LED_V1, 2724.),
+        ] {
+            let (cct, duv) = chromaticity.cct_and_duv().unwrap();
+            assert!(
+                (cct - expected).abs() < 10. && duv.abs() < 0.005,
+                "expected {expected} K for {chromaticity:?}, got {cct} K"
+            );
+        }
+
+        for (cct, duv) in [
+            (1000., 0.),
+            (1500., -0.02),
+            (3000., 0.01),
+            (6500., -0.005),
+            (15000., 0.03),
+        ] {
+            let chromaticity = Chromaticity::from_cct_and_duv(cct, duv);
+            let (actual_cct, actual_duv) = chromaticity.cct_and_duv().unwrap();
+            assert!(
+                (actual_cct - cct).abs() < cct * 1e-4 && (actual_duv - duv).abs() < 1e-6,
+                "expected ({cct}, {duv}), got ({actual_cct}, {actual_duv})"
+            );
+        }
+
+        // Temperatures beyond the range of the approximation are not found.
+        for chromaticity in [
+            Chromaticity::from_cct(25000.),
+            Chromaticity::from_cct_and_duv(15100., 0.01),
+            Chromaticity::daylight(25000.),
+            Chromaticity { x: 0.24, y: 0.23 },
+            Chromaticity::from_cct(900.),
+        ] {
+            assert_eq!(
+                chromaticity.cct_and_duv(),
+                None,
+                "unexpected temperature for {chromaticity:?}"
+            );
+        }
+        // Above the locus is towards green.
+        let above = Chromaticity::from_cct_and_duv(5000., 0.01);
+        let below = Chromaticity::from_cct_and_duv(5000., -0.01);
+        assert!(above.y > below.y);
+    }
+
+    #[test]
+    fn uv() {
+        for chromaticity in [Chromaticity::D65, Chromaticity::A, Chromaticity::LED_RGB1] {
+            assert_close(
+                Chromaticity::from_uv(chromaticity.to_uv()),
+                chromaticity,
+                1e-6,
+            );
+            assert_close(
+                Chromaticity::from_uv_prime(chromaticity.to_uv_prime()),
+                chromaticity,
+                1e-6,
+            );
+            let [u, v] = chromaticity.to_uv();
+            let [u_prime, v_prime] = chromaticity.to_uv_prime();
+            assert!((u - u_prime).abs() < 1e-7 && (v * 1.5 - v_prime).abs() < 1e-7);
+        }
+    }
+
+    #[test]
+    fn adaptation_methods() {
+        for method in [
+            AdaptationMethod::Bradford,
+            AdaptationMethod::Cat02,
+            AdaptationMethod::Cat16,
+            AdaptationMethod::VonKries,
+            AdaptationMethod::XyzScaling,
+        ] {
+            let matrix = Chromaticity::D65.chromatic_adaptation_matrix(Chromaticity::A, method, 1.);
+            let white = matvecmul(&matrix, Chromaticity::D65.to_xyz());
+            assert_close(Chromaticity::from_xyz(white), Chromaticity::A, 1e-6);
+
+            let identity =
+                Chromaticity::D65.chromatic_adaptation_matrix(Chromaticity::A, method, 0.);
+            for (i, row) in identity.iter().enumerate() {
+                for (j, x) in row.iter().enumerate() {
+                    let expected = if i == j { 1. } else { 0. };
+                    assert!((x - expected).abs() < 1e-6, "{method:?}: {identity:?}");
+                }
+            }
+
+            // White under D65 is white under A after adaptation.
+            let adapted = Srgb::chromatically_adapt_with(
+                [1., 1., 1.],
+                Chromaticity::D65,
+                Chromaticity::A,
+                method,
+                1.,
+            );
+            let xyz = Srgb::convert_absolute::<XyzD65>(adapted);
+            assert_close(Chromaticity::from_xyz(xyz), Chromaticity::A, 1e-5);
+
+            let color = AlphaColor::<Srgb>::new([0.8, 0.4, 0.2, 0.5]);
+            let expected = Srgb::chromatically_adapt_with(
+                [0.8, 0.4, 0.2],
+                Chromaticity::D65,
+                Chromaticity::D50,
+                method,
+                0.8,
+            );
+            let actual = DynamicColor::from_alpha_color(color)
+                .convert(ColorSpaceTag::Oklab)
+                .chromatically_adapt_with(Chromaticity::D65, Chromaticity::D50, method, 0.8)
+                .to_alpha_color::<Srgb>();
+            assert!(
+                actual.difference(AlphaColor::new([
+                    expected[0],
+                    expected[1],
+                    expected[2],
+                    0.5
+                ])) < 1e-5
+            );
+        }
+
+        // Bradford is the default transform.
+        let src = [0.8, 0.4, 0.2];
+        let default = Srgb::chromatically_adapt(src, Chromaticity::D65, Chromaticity::D50);
+        let generic = Srgb::chromatically_adapt_with(
+            src,
+            Chromaticity::D65,
+            Chromaticity::D50,
+            AdaptationMethod::Bradford,
+            0.999_999,
+        );
+        assert!(
+            AlphaColor::<Srgb>::new([default[0], default[1], default[2], 1.])
+                .difference(AlphaColor::new([generic[0], generic[1], generic[2], 1.]))
+                < 1e-5
+        );
+    }
+}
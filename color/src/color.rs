@@ -7,8 +7,10 @@ use core::any::TypeId;
 use core::marker::PhantomData;
 
 use crate::{
-    ColorSpace, ColorSpaceLayout, ColorSpaceTag, Oklab, Oklch, PremulRgba8, Rgba8, Srgb,
+    ColorSpace, ColorSpaceLayout, ColorSpaceTag, Lab, Oklab, Oklch, PremulRgba8, Rgba8, Srgb,
     cache_key::{BitEq, BitHash},
+    delta_e::Formula,
+    gamut,
 };
 
 #[cfg(all(not(feature = "std"), not(test)))]
@@ -230,12 +232,69 @@ impl<CS: ColorSpace> OpaqueColor<CS> {
     }
 
     /// Difference between two colors by Euclidean metric.
+    ///
+    /// For a perceptual difference, convert to [`Oklab`](crate::Oklab) first, or use
+    /// [`OpaqueColor::delta_e`].
     #[must_use]
     pub fn difference(self, other: Self) -> f32 {
         let d = (self - other).components;
         (d[0] * d[0] + d[1] * d[1] + d[2] * d[2]).sqrt()
     }
 
+    /// Perceptual difference between two colors by one of the CIE Lab color difference formulas.
+    ///
+    /// Both colors are converted to [`Lab`] first. For asymmetric formulas, `self` is the
+    /// reference color. See the [`delta_e`](crate::delta_e) module for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color::{OpaqueColor, Srgb, delta_e::Formula};
+    ///
+    /// let reference = OpaqueColor::<Srgb>::new([0.8, 0.2, 0.1]);
+    /// let sample = OpaqueColor::<Srgb>::new([0.81, 0.2, 0.1]);
+    /// assert!(reference.delta_e(sample, Formula::Ciede2000) < 1.);
+    /// ```
+    #[must_use]
+    pub fn delta_e(self, other: Self, formula: Formula) -> f32 {
+        formula.compute(self.convert::<Lab>(), other.convert::<Lab>())
+    }
+
+    /// Whether the color is within the natural gamut of the color space `TargetCS`.
+    ///
+    /// Components may exceed the gamut by at most `epsilon`, in the units of `TargetCS`'s
+    /// components. See [`ColorSpace::is_in_gamut`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color::{DisplayP3, OpaqueColor, Srgb};
+    ///
+    /// let p3_red = OpaqueColor::<DisplayP3>::new([1., 0., 0.]);
+    /// assert!(p3_red.is_in_gamut::<DisplayP3>(0.));
+    /// assert!(!p3_red.is_in_gamut::<Srgb>(1e-4));
+    /// ```
+    #[must_use]
+    pub fn is_in_gamut<TargetCS: ColorSpace>(self, epsilon: f32) -> bool {
+        TargetCS::is_in_gamut(CS::convert::<TargetCS>(self.components), epsilon)
+    }
+
+    /// Convert a color into a different color space, mapping it into the gamut of that color
+    /// space.
+    ///
+    /// This reduces chroma in [Oklch] while preserving lightness and hue, following the
+    /// algorithm of [CSS Color Module Level 4 § 13.2][css-sec]. See
+    /// [`DynamicColor::map_to_gamut`] for more details.
+    ///
+    /// [css-sec]: https://www.w3.org/TR/css-color-4/#css-gamut-mapping
+    /// [`DynamicColor::map_to_gamut`]: crate::DynamicColor::map_to_gamut
+    #[must_use]
+    pub fn map_to_gamut<TargetCS: ColorSpace>(self) -> OpaqueColor<TargetCS> {
+        let dest = CS::convert::<TargetCS>(self.components);
+        let origin = CS::convert::<Oklch>(self.components);
+        OpaqueColor::new(gamut::map_to_gamut::<TargetCS>(dest, origin))
+    }
+
     /// Linearly interpolate colors, without hue fixup.
     ///
     /// This method produces meaningful results in rectangular color spaces,
@@ -453,6 +512,33 @@ impl<CS: ColorSpace> AlphaColor<CS> {
         AlphaColor::new(add_alpha(components, alpha))
     }
 
+    /// Whether the color is within the natural gamut of the color space `TargetCS`.
+    ///
+    /// The alpha channel is not taken into account. See [`OpaqueColor::is_in_gamut`] for more
+    /// details.
+    #[must_use]
+    pub fn is_in_gamut<TargetCS: ColorSpace>(self, epsilon: f32) -> bool {
+        self.discard_alpha().is_in_gamut::<TargetCS>(epsilon)
+    }
+
+    /// Convert a color into a different color space, mapping it into the gamut of that color
+    /// space.
+    ///
+    /// The alpha channel is preserved. See [`OpaqueColor::map_to_gamut`] for more details.
+    #[must_use]
+    pub fn map_to_gamut<TargetCS: ColorSpace>(self) -> AlphaColor<TargetCS> {
+        let (opaque, alpha) = self.split();
+        opaque.map_to_gamut::<TargetCS>().with_alpha(alpha)
+    }
+
+    /// Perceptual difference between two colors by one of the CIE Lab color difference formulas.
+    ///
+    /// The alpha channel is ignored. See [`OpaqueColor::delta_e`] for more details.
+    #[must_use]
+    pub fn delta_e(self, other: Self, formula: Formula) -> f32 {
+        self.discard_alpha().delta_e(other.discard_alpha(), formula)
+    }
+
     /// Convert a color to the corresponding premultiplied form.
     #[must_use]
     pub const fn premultiply(self) -> PremulColor<CS> {
@@ -6,7 +6,12 @@ extern crate std;
 
 use core::any::TypeId;
 
-use crate::{Chromaticity, matvecmul, tag::ColorSpaceTag};
+use crate::{
+    AdaptationMethod, Chromaticity,
+    gamut::{find_cusp, find_gamut_intersection},
+    matmatmul, matvecmul,
+    tag::ColorSpaceTag,
+};
 
 #[cfg(all(not(feature = "std"), not(test)))]
 use crate::floatfuncs::FloatFuncs;
@@ -216,7 +221,8 @@ pub trait ColorSpace: Clone + Copy + 'static {
     ///
     /// The color is assumed to be under a reference white point of `from` and is chromatically
     /// adapted to the given white point `to`. The linear Bradford transform is used to perform the
-    /// chromatic adaptation.
+    /// chromatic adaptation. See [`ColorSpace::chromatically_adapt_with`] to choose another
+    /// transform.
     fn chromatically_adapt(src: [f32; 3], from: Chromaticity, to: Chromaticity) -> [f32; 3] {
         if from == to {
             return src;
@@ -237,6 +243,35 @@ pub trait ColorSpace: Clone + Copy + 'static {
         Self::from_linear_srgb_absolute(lin_srgb_adapted)
     }
 
+    /// Chromatically adapt the color between the given white point chromaticities, using the given
+    /// chromatic adaptation transform and degree of adaptation.
+    ///
+    /// The `degree` of adaptation is 1 for full adaptation to the white point `to`, and 0 for no
+    /// adaptation. See [`Chromaticity::chromatic_adaptation_matrix`].
+    ///
+    /// With [`AdaptationMethod::Bradford`] and a degree of 1, this is
+    /// [`ColorSpace::chromatically_adapt`].
+    fn chromatically_adapt_with(
+        src: [f32; 3],
+        from: Chromaticity,
+        to: Chromaticity,
+        method: AdaptationMethod,
+        degree: f32,
+    ) -> [f32; 3] {
+        if method == AdaptationMethod::Bradford && degree == 1. {
+            return Self::chromatically_adapt(src, from, to);
+        }
+        if from == to {
+            return src;
+        }
+
+        let lin_srgb_adapted = matvecmul(
+            &from.linear_srgb_adaptation_matrix(to, method, degree),
+            Self::to_linear_srgb_absolute(src),
+        );
+        Self::from_linear_srgb_absolute(lin_srgb_adapted)
+    }
+
     /// Scale the chroma by the given amount.
     ///
     /// In color spaces with a natural representation of chroma, scale
@@ -267,6 +302,35 @@ pub trait ColorSpace: Clone + Copy + 'static {
     /// assert_eq!(XyzD65::clip([0.4, -0.2, 1.2]), [0.4, -0.2, 1.2]);
     /// ```
     fn clip(src: [f32; 3]) -> [f32; 3];
+
+    /// Whether the color is within the natural gamut of the color space.
+    ///
+    /// Components may exceed the gamut by at most `epsilon`, which is in the units of the color
+    /// space's components. Color spaces without gamut limits, such as [`Lab`] and [`XyzD65`],
+    /// consider every color to be in gamut.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color::{ColorSpace, Oklab, Srgb};
+    ///
+    /// assert!(Srgb::is_in_gamut([0.4, 0.2, 1.], 0.));
+    /// assert!(!Srgb::is_in_gamut([0.4, -0.2, 1.], 0.));
+    /// assert!(Srgb::is_in_gamut([0.4, 0.2, 1.00001], 1e-4));
+    /// assert!(Oklab::is_in_gamut([0.4, -0.5, 0.5], 0.));
+    /// ```
+    ///
+    /// # Note to implementers
+    ///
+    /// The default implementation checks whether [`ColorSpace::clip`] moves any component by more
+    /// than `epsilon`. Color spaces whose natural gamut is not described by their clipping
+    /// behavior should override this.
+    fn is_in_gamut(src: [f32; 3], epsilon: f32) -> bool {
+        let clipped = Self::clip(src);
+        (src[0] - clipped[0]).abs() <= epsilon
+            && (src[1] - clipped[1]).abs() <= epsilon
+            && (src[2] - clipped[2]).abs() <= epsilon
+    }
 }
 
 /// The layout of a color space, particularly the hue component.
@@ -407,6 +471,8 @@ impl ColorSpace for Srgb {
             rgb_to_hsl(src, true)
         } else if TypeId::of::<TargetCS>() == TypeId::of::<Hwb>() {
             rgb_to_hwb(src)
+        } else if TypeId::of::<TargetCS>() == TypeId::of::<Hsv>() {
+            hwb_to_hsv(rgb_to_hwb(src))
         } else {
             let lin_rgb = Self::to_linear_srgb(src);
             TargetCS::from_linear_srgb(lin_rgb)
@@ -703,11 +769,77 @@ impl From<ProphotoRgb> for ColorSpaceTag {
 #[derive(Clone, Copy, Debug)]
 pub struct Rec2020;
 
+// XYZ_to_lin_sRGB * lin_Rec2020_to_XYZ
+#[expect(
+    clippy::cast_possible_truncation,
+    reason = "exact rational, truncate at compile-time"
+)]
+const LINEAR_REC2020_TO_SRGB: [[f32; 3]; 3] = [
+    [
+        (2_785_571_537. / 1_677_558_947.) as f32,
+        (-985_802_650. / 1_677_558_947.) as f32,
+        (-122_209_940. / 1_677_558_947.) as f32,
+    ],
+    [
+        (-4_638_020_506. / 37_238_079_773.) as f32,
+        (42_187_016_744. / 37_238_079_773.) as f32,
+        (-310_916_465. / 37_238_079_773.) as f32,
+    ],
+    [
+        (-97_469_024. / 5_369_968_309.) as f32,
+        (-3_780_738_464. / 37_589_778_163.) as f32,
+        (42_052_799_795. / 37_589_778_163.) as f32,
+    ],
+];
+
+// XYZ_to_lin_Rec2020 * lin_sRGB_to_XYZ
+#[expect(
+    clippy::cast_possible_truncation,
+    reason = "exact rational, truncate at compile-time"
+)]
+const LINEAR_SRGB_TO_REC2020: [[f32; 3]; 3] = [
+    [
+        (2_939_026_994. / 4_684_425_795.) as f32,
+        (9_255_011_753. / 28_106_554_770.) as f32,
+        (173_911_579. / 4_015_222_110.) as f32,
+    ],
+    [
+        (76_515_593. / 1_107_360_270.) as f32,
+        (6_109_575_001. / 6_644_161_620.) as f32,
+        (75_493_061. / 6_644_161_620.) as f32,
+    ],
+    [
+        (12_225_392. / 745_840_075.) as f32,
+        (1_772_384_008. / 20_137_682_025.) as f32,
+        (18_035_212_433. / 20_137_682_025.) as f32,
+    ],
+];
+
 impl Rec2020 {
     // These are the parameters of the transfer function defined in the Rec. 2020 specification.
     // They are truncated here to f32 precision.
-    const A: f32 = 1.099_296_8;
-    const B: f32 = 0.018_053_97;
+    pub(crate) const A: f32 = 1.099_296_8;
+    pub(crate) const B: f32 = 0.018_053_97;
+}
+
+/// The inverse of the transfer function of [`Rec2020`], extended to negative values.
+pub(crate) fn rec2020_to_lin(x: f32) -> f32 {
+    if x.abs() < Rec2020::B * 4.5 {
+        x * (1. / 4.5)
+    } else {
+        ((x.abs() + (Rec2020::A - 1.)) / Rec2020::A)
+            .powf(1. / 0.45)
+            .copysign(x)
+    }
+}
+
+/// The transfer function of [`Rec2020`], extended to negative values.
+pub(crate) fn lin_to_rec2020(x: f32) -> f32 {
+    if x.abs() < Rec2020::B {
+        x * 4.5
+    } else {
+        (Rec2020::A * x.abs().powf(0.45) - (Rec2020::A - 1.)).copysign(x)
+    }
 }
 
 impl ColorSpace for Rec2020 {
@@ -716,74 +848,11 @@ impl ColorSpace for Rec2020 {
     const WHITE_COMPONENTS: [f32; 3] = [1., 1., 1.];
 
     fn to_linear_srgb([r, g, b]: [f32; 3]) -> [f32; 3] {
-        // XYZ_to_lin_sRGB * lin_Rec2020_to_XYZ
-        #[expect(
-            clippy::cast_possible_truncation,
-            reason = "exact rational, truncate at compile-time"
-        )]
-        const LINEAR_REC2020_TO_SRGB: [[f32; 3]; 3] = [
-            [
-                (2_785_571_537. / 1_677_558_947.) as f32,
-                (-985_802_650. / 1_677_558_947.) as f32,
-                (-122_209_940. / 1_677_558_947.) as f32,
-            ],
-            [
-                (-4_638_020_506. / 37_238_079_773.) as f32,
-                (42_187_016_744. / 37_238_079_773.) as f32,
-                (-310_916_465. / 37_238_079_773.) as f32,
-            ],
-            [
-                (-97_469_024. / 5_369_968_309.) as f32,
-                (-3_780_738_464. / 37_589_778_163.) as f32,
-                (42_052_799_795. / 37_589_778_163.) as f32,
-            ],
-        ];
-
-        fn transfer(x: f32) -> f32 {
-            if x.abs() < Rec2020::B * 4.5 {
-                x * (1. / 4.5)
-            } else {
-                ((x.abs() + (Rec2020::A - 1.)) / Rec2020::A)
-                    .powf(1. / 0.45)
-                    .copysign(x)
-            }
-        }
-
-        matvecmul(&LINEAR_REC2020_TO_SRGB, [r, g, b].map(transfer))
+        matvecmul(&LINEAR_REC2020_TO_SRGB, [r, g, b].map(rec2020_to_lin))
     }
 
     fn from_linear_srgb([r, g, b]: [f32; 3]) -> [f32; 3] {
-        // XYZ_to_lin_Rec2020 * lin_sRGB_to_XYZ
-        #[expect(
-            clippy::cast_possible_truncation,
-            reason = "exact rational, truncate at compile-time"
-        )]
-        const LINEAR_SRGB_TO_REC2020: [[f32; 3]; 3] = [
-            [
-                (2_939_026_994. / 4_684_425_795.) as f32,
-                (9_255_011_753. / 28_106_554_770.) as f32,
-                (173_911_579. / 4_015_222_110.) as f32,
-            ],
-            [
-                (76_515_593. / 1_107_360_270.) as f32,
-                (6_109_575_001. / 6_644_161_620.) as f32,
-                (75_493_061. / 6_644_161_620.) as f32,
-            ],
-            [
-                (12_225_392. / 745_840_075.) as f32,
-                (1_772_384_008. / 20_137_682_025.) as f32,
-                (18_035_212_433. / 20_137_682_025.) as f32,
-            ],
-        ];
-
-        fn transfer(x: f32) -> f32 {
-            if x.abs() < Rec2020::B {
-                x * 4.5
-            } else {
-                (Rec2020::A * x.abs().powf(0.45) - (Rec2020::A - 1.)).copysign(x)
-            }
-        }
-        matvecmul(&LINEAR_SRGB_TO_REC2020, [r, g, b]).map(transfer)
+        matvecmul(&LINEAR_SRGB_TO_REC2020, [r, g, b]).map(lin_to_rec2020)
     }
 
     fn clip([r, g, b]: [f32; 3]) -> [f32; 3] {
@@ -797,6 +866,312 @@ impl From<Rec2020> for ColorSpaceTag {
     }
 }
 
+/// The luminance in cd/m² of the reference white of the Rec. 2100 color spaces.
+///
+/// This is mapped to the white of [linear sRGB](`LinearSrgb`), following ITU-R BT.2408 and CSS
+/// Color HDR Module Level 1.
+const REC2100_REFERENCE_WHITE: f32 = 203.;
+
+/// Convert linear Rec. 2100 (or Rec. 2020) RGB, relative to the reference white, to PQ.
+pub(crate) fn lin_to_pq(x: f32) -> f32 {
+    Rec2100Pq::inverse_eotf(x.abs() * REC2100_REFERENCE_WHITE).copysign(x)
+}
+
+/// Convert PQ to linear Rec. 2100 (or Rec. 2020) RGB, relative to the reference white.
+pub(crate) fn pq_to_lin(x: f32) -> f32 {
+    (Rec2100Pq::eotf(x.abs()) * (1. / REC2100_REFERENCE_WHITE)).copysign(x)
+}
+
+/// 🌌 The Rec. 2100 color space with the perceptual quantizer (PQ) transfer function.
+///
+/// Rec. 2100 PQ is a high dynamic range color space. It has the same primaries as
+/// [Rec. 2020](`Rec2020`), and encodes absolute luminances up to 10,000 cd/m² with the
+/// perceptual quantizer transfer function of SMPTE ST 2084.
+///
+/// Its components are `[r, g, b]` (red, green, and blue channels respectively), with `[0, 0, 0]`
+/// pure black and `[1, 1, 1]` a white of 10,000 cd/m². The natural bounds of the channels are
+/// `[0, 1]`.
+///
+/// Following [ITU-R BT.2408][bt2408], the reference white is at 203 cd/m², which is a value of
+/// about 0.58 in all channels. This is the white of [linear sRGB](`LinearSrgb`), so brighter
+/// colors convert to linear sRGB values greater than 1.
+///
+/// This corresponds to the color space in [CSS Color HDR Module Level 1][css-sec]. The color
+/// space is defined by the International Telecommunication Union [here][itu].
+///
+/// [css-sec]: https://drafts.csswg.org/css-color-hdr/#valdef-color-rec2100-pq
+/// [bt2408]: https://www.itu.int/pub/R-REP-BT.2408
+/// [itu]: https://www.itu.int/rec/R-REC-BT.2100/en
+#[derive(Clone, Copy, Debug)]
+pub struct Rec2100Pq;
+
+impl Rec2100Pq {
+    // These are the parameters of the transfer function defined in SMPTE ST 2084.
+    const M1: f64 = 2610. / 16384.;
+    const M2: f64 = 2523. / 4096. * 128.;
+    const C1: f64 = 3424. / 4096.;
+    const C2: f64 = 2413. / 4096. * 32.;
+    const C3: f64 = 2392. / 4096. * 32.;
+
+    /// The PQ electro-optical transfer function.
+    ///
+    /// This converts a non-linear signal value in `[0, 1]` to the absolute luminance it encodes,
+    /// in cd/m², in `[0, 10000]`.
+    #[must_use]
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "the result is in the range of f32"
+    )]
+    pub fn eotf(signal: f32) -> f32 {
+        // The curve is steep, so this is calculated in `f64` to keep roundtrips precise.
+        let e = f64::from(signal.max(0.)).powf(1. / Self::M2);
+        let y = ((e - Self::C1).max(0.) / (Self::C2 - Self::C3 * e)).powf(1. / Self::M1);
+        (10000. * y) as f32
+    }
+
+    /// The inverse of the PQ electro-optical transfer function.
+    ///
+    /// This converts an absolute luminance in cd/m², in `[0, 10000]`, to a non-linear signal
+    /// value in `[0, 1]`.
+    #[must_use]
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "the result is in the range of f32"
+    )]
+    pub fn inverse_eotf(luminance: f32) -> f32 {
+        let y = (f64::from(luminance.max(0.)) * (1. / 10000.)).powf(Self::M1);
+        ((Self::C1 + Self::C2 * y) / (1. + Self::C3 * y)).powf(Self::M2) as f32
+    }
+}
+
+impl ColorSpace for Rec2100Pq {
+    const TAG: Option<ColorSpaceTag> = Some(ColorSpaceTag::Rec2100Pq);
+
+    const WHITE_COMPONENTS: [f32; 3] = [0.580_688_9; 3];
+
+    fn to_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        matvecmul(&LINEAR_REC2020_TO_SRGB, src.map(pq_to_lin))
+    }
+
+    fn from_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        matvecmul(&LINEAR_SRGB_TO_REC2020, src).map(lin_to_pq)
+    }
+
+    fn clip([r, g, b]: [f32; 3]) -> [f32; 3] {
+        [r.clamp(0., 1.), g.clamp(0., 1.), b.clamp(0., 1.)]
+    }
+}
+
+impl From<Rec2100Pq> for ColorSpaceTag {
+    fn from(_: Rec2100Pq) -> Self {
+        Self::Rec2100Pq
+    }
+}
+
+/// 🌌 The Rec. 2100 color space with the hybrid log-gamma (HLG) transfer function.
+///
+/// Rec. 2100 HLG is a high dynamic range color space. It has the same primaries as
+/// [Rec. 2020](`Rec2020`), and encodes relative scene light with the hybrid log-gamma transfer
+/// function, which is backwards compatible with standard dynamic range displays.
+///
+/// Its components are `[r, g, b]` (red, green, and blue channels respectively), with `[0, 0, 0]`
+/// pure black and `[1, 1, 1]` peak white. The natural bounds of the channels are `[0, 1]`.
+///
+/// Following [ITU-R BT.2408][bt2408], the reference white is at a signal value of 0.75 in all
+/// channels. This is the white of [linear sRGB](`LinearSrgb`), so brighter colors convert to
+/// linear sRGB values greater than 1. The HLG opto-optical transfer function, which depends on
+/// the peak luminance of the display, is not applied.
+///
+/// This corresponds to the color space in [CSS Color HDR Module Level 1][css-sec]. The color
+/// space is defined by the International Telecommunication Union [here][itu].
+///
+/// [css-sec]: https://drafts.csswg.org/css-color-hdr/#valdef-color-rec2100-hlg
+/// [bt2408]: https://www.itu.int/pub/R-REP-BT.2408
+/// [itu]: https://www.itu.int/rec/R-REC-BT.2100/en
+#[derive(Clone, Copy, Debug)]
+pub struct Rec2100Hlg;
+
+impl Rec2100Hlg {
+    // These are the parameters of the transfer function defined in the Rec. 2100 specification.
+    const A: f32 = 0.178_832_77;
+    const B: f32 = 1. - 4. * Self::A;
+    const C: f32 = 0.559_910_7;
+
+    /// The scale from scene light to linear sRGB, mapping the reference white to 1.
+    ///
+    /// This is the reciprocal of the inverse OETF of 0.75.
+    pub(crate) const SCALE: f32 = 3.774_118;
+
+    /// The HLG opto-electronic transfer function.
+    ///
+    /// This converts normalized linear scene light in `[0, 1]` to a non-linear signal value in
+    /// `[0, 1]`.
+    #[must_use]
+    pub fn oetf(light: f32) -> f32 {
+        let light = light.max(0.);
+        if light <= 1. / 12. {
+            (3. * light).sqrt()
+        } else {
+            Self::A * (12. * light - Self::B).ln() + Self::C
+        }
+    }
+
+    /// The inverse of the HLG opto-electronic transfer function.
+    ///
+    /// This converts a non-linear signal value in `[0, 1]` to normalized linear scene light in
+    /// `[0, 1]`.
+    #[must_use]
+    pub fn inverse_oetf(signal: f32) -> f32 {
+        let signal = signal.max(0.);
+        if signal <= 0.5 {
+            signal * signal * (1. / 3.)
+        } else {
+            (((signal - Self::C) / Self::A).exp() + Self::B) * (1. / 12.)
+        }
+    }
+}
+
+impl ColorSpace for Rec2100Hlg {
+    const TAG: Option<ColorSpaceTag> = Some(ColorSpaceTag::Rec2100Hlg);
+
+    const WHITE_COMPONENTS: [f32; 3] = [0.75; 3];
+
+    fn to_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        let lin = src.map(|x| (Self::inverse_oetf(x.abs()) * Self::SCALE).copysign(x));
+        matvecmul(&LINEAR_REC2020_TO_SRGB, lin)
+    }
+
+    fn from_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        matvecmul(&LINEAR_SRGB_TO_REC2020, src)
+            .map(|x| Self::oetf(x.abs() * (1. / Self::SCALE)).copysign(x))
+    }
+
+    fn clip([r, g, b]: [f32; 3]) -> [f32; 3] {
+        [r.clamp(0., 1.), g.clamp(0., 1.), b.clamp(0., 1.)]
+    }
+}
+
+impl From<Rec2100Hlg> for ColorSpaceTag {
+    fn from(_: Rec2100Hlg) -> Self {
+        Self::Rec2100Hlg
+    }
+}
+
+/// 🌌 The `ICtCp` color space.
+///
+/// `ICtCp` is a perceptually uniform color space for high dynamic range and wide color gamut
+/// colors, developed by Dolby and standardized in Rec. 2100. It is derived from
+/// [Rec. 2100 PQ](`Rec2100Pq`).
+///
+/// Its components are `[I, Ct, Cp]` with
+/// - `I` - the intensity, with 0 black and 1 a white of 10,000 cd/m²,
+/// - `Ct` - the blue-yellow chroma component ("tritan"), and
+/// - `Cp` - the red-green chroma component ("protan").
+///
+/// The natural bounds of `I` are `[0, 1]`. `Ct` and `Cp` are unbounded, but are typically in
+/// `[-0.5, 0.5]`. As in [`Rec2100Pq`], the reference white of 203 cd/m² is the white of
+/// [linear sRGB](`LinearSrgb`), with an intensity of about 0.58.
+///
+/// This corresponds to the color space in [CSS Color HDR Module Level 1][css-sec]. The color
+/// space is defined by the International Telecommunication Union [here][itu].
+///
+/// [css-sec]: https://drafts.csswg.org/css-color-hdr/#ICtCp
+/// [itu]: https://www.itu.int/rec/R-REC-BT.2100/en
+#[derive(Clone, Copy, Debug)]
+pub struct ICtCp;
+
+// lin_Rec2020_to_LMS
+const ICTCP_REC2020_TO_LMS: [[f32; 3]; 3] = [
+    [1688. / 4096., 2146. / 4096., 262. / 4096.],
+    [683. / 4096., 2951. / 4096., 462. / 4096.],
+    [99. / 4096., 309. / 4096., 3688. / 4096.],
+];
+
+// LMS_to_lin_Rec2020
+#[expect(
+    clippy::cast_possible_truncation,
+    reason = "exact rational, truncate at compile-time"
+)]
+const ICTCP_LMS_TO_REC2020: [[f32; 3]; 3] = [
+    [
+        (1_074_053. / 312_533.) as f32,
+        (-783_349. / 312_533.) as f32,
+        (21_829. / 312_533.) as f32,
+    ],
+    [
+        (-1_236_583. / 1_562_665.) as f32,
+        (3_099_703. / 1_562_665.) as f32,
+        (-60_091. / 312_533.) as f32,
+    ],
+    [
+        (-40_551. / 1_562_665.) as f32,
+        (-154_569. / 1_562_665.) as f32,
+        (351_557. / 312_533.) as f32,
+    ],
+];
+
+const ICTCP_LMS_TO_ITP: [[f32; 3]; 3] = [
+    [0.5, 0.5, 0.],
+    [6610. / 4096., -13613. / 4096., 7003. / 4096.],
+    [17933. / 4096., -17390. / 4096., -543. / 4096.],
+];
+
+#[expect(
+    clippy::cast_possible_truncation,
+    reason = "exact rational, truncate at compile-time"
+)]
+const ICTCP_ITP_TO_LMS: [[f32; 3]; 3] = [
+    [
+        1.,
+        (1_112_064. / 129_174_029.) as f32,
+        (14_342_144. / 129_174_029.) as f32,
+    ],
+    [
+        1.,
+        (-1_112_064. / 129_174_029.) as f32,
+        (-14_342_144. / 129_174_029.) as f32,
+    ],
+    [
+        1.,
+        (72_341_504. / 129_174_029.) as f32,
+        (-41_416_704. / 129_174_029.) as f32,
+    ],
+];
+
+impl ColorSpace for ICtCp {
+    const TAG: Option<ColorSpaceTag> = Some(ColorSpaceTag::ICtCp);
+
+    const WHITE_COMPONENTS: [f32; 3] = [0.580_688_9, 0., 0.];
+
+    fn to_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        const ICTCP_LMS_TO_SRGB: [[f32; 3]; 3] =
+            matmatmul(&LINEAR_REC2020_TO_SRGB, &ICTCP_LMS_TO_REC2020);
+        let lms = matvecmul(&ICTCP_ITP_TO_LMS, src).map(pq_to_lin);
+        matvecmul(&ICTCP_LMS_TO_SRGB, lms)
+    }
+
+    fn from_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        const ICTCP_SRGB_TO_LMS: [[f32; 3]; 3] =
+            matmatmul(&ICTCP_REC2020_TO_LMS, &LINEAR_SRGB_TO_REC2020);
+        let lms = matvecmul(&ICTCP_SRGB_TO_LMS, src).map(lin_to_pq);
+        matvecmul(&ICTCP_LMS_TO_ITP, lms)
+    }
+
+    fn clip([i, ct, cp]: [f32; 3]) -> [f32; 3] {
+        [i.clamp(0., 1.), ct, cp]
+    }
+
+    fn is_in_gamut(_src: [f32; 3], _epsilon: f32) -> bool {
+        true
+    }
+}
+
+impl From<ICtCp> for ColorSpaceTag {
+    fn from(_: ICtCp) -> Self {
+        Self::ICtCp
+    }
+}
+
 /// 🌌 The ACES2065-1 color space.
 ///
 /// This is a linear color space with a very wide gamut. It is is often used for archival and
@@ -1125,6 +1500,16 @@ impl From<XyzD50> for ColorSpaceTag {
 #[derive(Clone, Copy, Debug)]
 pub struct XyzD65;
 
+pub(crate) const XYZ_D65_TO_LINEAR_SRGB: [[f32; 3]; 3] = [
+    [12_831. / 3_959., -329. / 214., -1_974. / 3_959.],
+    [
+        -851_781. / 878_810.,
+        1_648_619. / 878_810.,
+        36_519. / 878_810.,
+    ],
+    [705. / 12_673., -2_585. / 12_673., 705. / 667.],
+];
+
 impl ColorSpace for XyzD65 {
     const IS_LINEAR: bool = true;
 
@@ -1133,16 +1518,7 @@ impl ColorSpace for XyzD65 {
     const WHITE_COMPONENTS: [f32; 3] = [3127. / 3290., 1., 3583. / 3290.];
 
     fn to_linear_srgb(src: [f32; 3]) -> [f32; 3] {
-        const XYZ_TO_LINEAR_SRGB: [[f32; 3]; 3] = [
-            [12_831. / 3_959., -329. / 214., -1_974. / 3_959.],
-            [
-                -851_781. / 878_810.,
-                1_648_619. / 878_810.,
-                36_519. / 878_810.,
-            ],
-            [705. / 12_673., -2_585. / 12_673., 705. / 667.],
-        ];
-        matvecmul(&XYZ_TO_LINEAR_SRGB, src)
+        matvecmul(&XYZ_D65_TO_LINEAR_SRGB, src)
     }
 
     fn from_linear_srgb(src: [f32; 3]) -> [f32; 3] {
@@ -1194,13 +1570,13 @@ pub struct Oklab;
 // Matrices taken from [Oklab] blog post, precision reduced to f32
 //
 // [Oklab]: https://bottosson.github.io/posts/oklab/
-const OKLAB_LAB_TO_LMS: [[f32; 3]; 3] = [
+pub(crate) const OKLAB_LAB_TO_LMS: [[f32; 3]; 3] = [
     [1.0, 0.396_337_78, 0.215_803_76],
     [1.0, -0.105_561_346, -0.063_854_17],
     [1.0, -0.089_484_18, -1.291_485_5],
 ];
 
-const OKLAB_LMS_TO_SRGB: [[f32; 3]; 3] = [
+pub(crate) const OKLAB_LMS_TO_SRGB: [[f32; 3]; 3] = [
     [4.076_741_7, -3.307_711_6, 0.230_969_94],
     [-1.268_438, 2.609_757_4, -0.341_319_38],
     [-0.004_196_086_3, -0.703_418_6, 1.707_614_7],
@@ -1251,6 +1627,10 @@ impl ColorSpace for Oklab {
     fn clip([l, a, b]: [f32; 3]) -> [f32; 3] {
         [l.clamp(0., 1.), a, b]
     }
+
+    fn is_in_gamut(_src: [f32; 3], _epsilon: f32) -> bool {
+        true
+    }
 }
 
 impl From<Oklab> for ColorSpaceTag {
@@ -1320,6 +1700,10 @@ impl ColorSpace for Oklch {
     fn clip([l, c, h]: [f32; 3]) -> [f32; 3] {
         [l.clamp(0., 1.), c.max(0.), h]
     }
+
+    fn is_in_gamut(_src: [f32; 3], _epsilon: f32) -> bool {
+        true
+    }
 }
 
 impl From<Oklch> for ColorSpaceTag {
@@ -1328,60 +1712,583 @@ impl From<Oklch> for ColorSpaceTag {
     }
 }
 
-/// 🌌 The CIELAB color space
-///
-/// The CIE L\*a\*b\* color space was created in 1976 to be more perceptually
-/// uniform than RGB color spaces, and is both widely used and the basis of
-/// other efforts to express colors, including [FreieFarbe].
-///
-/// Its components are `[L, a, b]` with
-/// - `L` - the lightness with a natural bound between 0 and 100, where 0 represents pure black and 100
-///   represents the lightness of white;
-/// - `a` - how green/red the color is; and
-/// - `b` - how blue/yellow the color is.
-///
-/// `a` and `b` are unbounded, but are usually between -160 and 160.
+// The following is adapted from Björn Ottosson's post on Okhsv and Okhsl, with the hue in degrees
+// instead of turns.
+//
+// See https://bottosson.github.io/posts/colorpicker/.
+
+/// The toe function, an estimate of the lightness `L*` of [`Lab`] from the lightness of
+/// [`Oklab`], both scaled to the range 0 to 1.
+fn toe(x: f32) -> f32 {
+    const K1: f32 = 0.206;
+    const K2: f32 = 0.03;
+    const K3: f32 = (1. + K1) / (1. + K2);
+    0.5 * (K3 * x - K1 + ((K3 * x - K1) * (K3 * x - K1) + 4. * K2 * K3 * x).sqrt())
+}
+
+/// The inverse of [`toe`].
+fn toe_inv(x: f32) -> f32 {
+    const K1: f32 = 0.206;
+    const K2: f32 = 0.03;
+    const K3: f32 = (1. + K1) / (1. + K2);
+    (x * x + K1 * x) / (K3 * (x + K2))
+}
+
+/// The chroma below which a color in [`Oklab`] is treated as gray by [`Okhsl`] and [`Okhsv`].
 ///
-/// The color space has poor hue linearity and hue uniformity compared with
-/// [Oklab], though superior lightness uniformity. Note that the lightness
-/// range differs from Oklab as well; in Oklab white has a lightness of 1.
+/// Close to black and white, the sRGB gamut is very narrow, so rounding errors in the chroma of
+/// gray colors would otherwise result in large saturations.
+const OKHSX_ACHROMATIC_CHROMA: f32 = 1e-6;
+
+/// The normalized hue `(a, b)` and the chroma of a color in [`Oklab`].
+fn oklab_hue_chroma(a: f32, b: f32) -> (f32, f32, f32) {
+    let c = a.hypot(b);
+    if c < OKHSX_ACHROMATIC_CHROMA {
+        (1., 0., c)
+    } else {
+        (a / c, b / c, c)
+    }
+}
+
+/// The hue angle in degrees of a color in [`Oklab`].
+fn oklab_hue(a: f32, b: f32) -> f32 {
+    lab_to_lch([0., a, b])[2]
+}
+
+/// Convert a cusp `(L, C)` to the slopes `(S, T)` of the triangle approximating the sRGB gamut.
+fn cusp_to_st((l, c): (f32, f32)) -> (f32, f32) {
+    (c / l, c / (1. - l))
+}
+
+/// A smooth approximation of the location of the sRGB gamut cusp, as slopes `(S, T)`.
+fn st_mid(a: f32, b: f32) -> (f32, f32) {
+    let s = 0.115_169_93
+        + 1. / (7.447_789_7
+            + 4.159_012_4 * b
+            + a * (-2.195_573_4
+                + 1.751_984 * b
+                + a * (-2.137_049_4 - 10.023_01 * b
+                    + a * (-4.248_945_7 + 5.387_708 * b + 4.698_91 * a))));
+    let t = 0.112_396_42
+        + 1. / (1.613_203_2 - 0.681_243_8 * b
+            + a * (0.403_706_12
+                + 0.901_481_2 * b
+                + a * (-0.270_879_43
+                    + 0.612_239_9 * b
+                    + a * (0.002_992_15 - 0.453_995_68 * b - 0.146_618_72 * a))));
+    (s, t)
+}
+
+/// The chroma of [`Okhsl`] saturations 0.4 (`C_0`), 0.8 (`C_mid`) and 1 (`C_max`).
+fn okhsl_chromas(l: f32, a: f32, b: f32) -> (f32, f32, f32) {
+    let cusp = find_cusp(a, b);
+    let c_max = find_gamut_intersection(a, b, l, 1., l, cusp);
+    let (s_max, t_max) = cusp_to_st(cusp);
+
+    // Scale factor to compensate for the curved part of the gamut shape.
+    let k = c_max / (l * s_max).min((1. - l) * t_max);
+
+    // Use soft minimum functions, instead of the sharp triangle shape, to get smooth values for
+    // chroma.
+    let c_mid = {
+        let (s_mid, t_mid) = st_mid(a, b);
+        let c_a = l * s_mid;
+        let c_b = (1. - l) * t_mid;
+        0.9 * k
+            * (1. / (1. / (c_a * c_a * c_a * c_a) + 1. / (c_b * c_b * c_b * c_b)))
+                .sqrt()
+                .sqrt()
+    };
+    let c_0 = {
+        // The shape is independent of hue here, so the slopes are constants, chosen to be about
+        // their average values.
+        let c_a = l * 0.4;
+        let c_b = (1. - l) * 0.8;
+        (1. / (1. / (c_a * c_a) + 1. / (c_b * c_b))).sqrt()
+    };
+    (c_0, c_mid, c_max)
+}
+
+/// 🌌 The Okhsl color space
 ///
-/// The CIE L\*a\*b\* color space is defined in terms of a D50 white point. For
-/// conversion between color spaces with other illuminants (especially D65
-/// as in sRGB), the standard Bradform linear chromatic adaptation transform
-/// is used.
+/// Okhsl is an alternative to [HSL](Hsl) built on [`Oklab`], with a more uniform hue and
+/// lightness. Its saturation is fitted to the sRGB gamut, such that every color with components
+/// within their natural bounds is in the sRGB gamut.
 ///
-/// This corresponds to the color space in [CSS Color Module Level 4 § 9.1 ][css-sec].
+/// Its components are `[h, s, l]` with
+/// - `h` - the hue angle in degrees, as in [`Oklch`];
+/// - `s` - the saturation, where 0 is gray and 1 is on the boundary of the sRGB gamut; and
+/// - `l` - the lightness, where 0 is black and 1 is white, estimating the lightness `L*` of
+///   [`Lab`] divided by 100.
 ///
-/// Lab has a cylindrical counterpart: [Lch].
+/// Unlike in [`Hsl`], the saturation and lightness are in the range 0 to 1, as in Okhsl's
+/// [definition][bjorn]. It is commonly used with the `--okhsl` identifier in the CSS `color()`
+/// function, as in [Color.js].
 ///
-/// [FreieFarbe]: https://freiefarbe.de/en/
-/// [css-sec]: https://www.w3.org/TR/css-color-4/#cie-lab
+/// [bjorn]: https://bottosson.github.io/posts/colorpicker/#okhsl
+/// [Color.js]: https://colorjs.io/docs/spaces#okhsl
 #[derive(Clone, Copy, Debug)]
-pub struct Lab;
+pub struct Okhsl;
 
-// Matrices computed from CSS Color 4 spec, then used `cargo clippy --fix`
-// to reduce precision to f32 and add underscores.
+impl ColorSpace for Okhsl {
+    const TAG: Option<ColorSpaceTag> = Some(ColorSpaceTag::Okhsl);
 
-// This is D65_to_D50 * lin_sRGB_to_XYZ, then rows scaled by 1 / D50[i].
-const LAB_SRGB_TO_XYZ: [[f32; 3]; 3] = [
-    [0.452_211_65, 0.399_412_24, 0.148_376_09],
-    [0.222_493_17, 0.716_887, 0.060_619_81],
-    [0.016_875_342, 0.117_659_41, 0.865_465_2],
-];
+    const LAYOUT: ColorSpaceLayout = ColorSpaceLayout::HueFirst;
 
-// This is XYZ_to_lin_sRGB * D50_to_D65, then columns scaled by D50[i].
-const LAB_XYZ_TO_SRGB: [[f32; 3]; 3] = [
-    [3.022_233_7, -1.617_386, -0.404_847_65],
-    [-0.943_848_25, 1.916_254_4, 0.027_593_868],
-    [0.069_386_27, -0.228_976_76, 1.159_590_5],
-];
+    const WHITE_COMPONENTS: [f32; 3] = [0., 0., 1.];
 
-const EPSILON: f32 = 216. / 24389.;
-const KAPPA: f32 = 24389. / 27.;
+    fn to_linear_srgb([h, s, l]: [f32; 3]) -> [f32; 3] {
+        let lightness = toe_inv(l);
+        if s == 0. || l <= 0. || l >= 1. {
+            return Oklab::to_linear_srgb([lightness, 0., 0.]);
+        }
+        let (b, a) = h.to_radians().sin_cos();
+        let (c_0, c_mid, c_max) = okhsl_chromas(lightness, a, b);
+
+        // Interpolate the chroma, through `C_0` and `C_mid` at saturation 0.8 to `C_max`.
+        const MID: f32 = 0.8;
+        const MID_INV: f32 = 1.25;
+        let c = if s < MID {
+            let t = MID_INV * s;
+            let k_1 = MID * c_0;
+            let k_2 = 1. - k_1 / c_mid;
+            t * k_1 / (1. - k_2 * t)
+        } else {
+            let t = (s - MID) / (1. - MID);
+            let k_0 = c_mid;
+            let k_1 = (1. - MID) * c_mid * c_mid * MID_INV * MID_INV / c_0;
+            let k_2 = 1. - k_1 / (c_max - c_mid);
+            k_0 + t * k_1 / (1. - k_2 * t)
+        };
+        Oklab::to_linear_srgb([lightness, c * a, c * b])
+    }
 
-impl ColorSpace for Lab {
-    const TAG: Option<ColorSpaceTag> = Some(ColorSpaceTag::Lab);
+    fn from_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        let [lightness, a, b] = Oklab::from_linear_srgb(src);
+        let h = oklab_hue(a, b);
+        let l = toe(lightness);
+        let (a, b, c) = oklab_hue_chroma(a, b);
+        if c < OKHSX_ACHROMATIC_CHROMA || lightness <= 0. || lightness >= 1. {
+            return [h, 0., l];
+        }
+        let (c_0, c_mid, c_max) = okhsl_chromas(lightness, a, b);
+
+        // Invert the interpolation of `to_linear_srgb`.
+        const MID: f32 = 0.8;
+        const MID_INV: f32 = 1.25;
+        let s = if c < c_mid {
+            let k_1 = MID * c_0;
+            let k_2 = 1. - k_1 / c_mid;
+            let t = c / (k_1 + k_2 * c);
+            t * MID
+        } else {
+            let k_0 = c_mid;
+            let k_1 = (1. - MID) * c_mid * c_mid * MID_INV * MID_INV / c_0;
+            let k_2 = 1. - k_1 / (c_max - c_mid);
+            let t = (c - k_0) / (k_1 + k_2 * (c - k_0));
+            MID + (1. - MID) * t
+        };
+        [h, s, l]
+    }
+
+    fn scale_chroma([h, s, l]: [f32; 3], scale: f32) -> [f32; 3] {
+        [h, s * scale, l]
+    }
+
+    fn clip([h, s, l]: [f32; 3]) -> [f32; 3] {
+        [h, s.clamp(0., 1.), l.clamp(0., 1.)]
+    }
+
+    fn is_in_gamut(src: [f32; 3], epsilon: f32) -> bool {
+        Srgb::is_in_gamut(Self::convert::<Srgb>(src), epsilon)
+    }
+}
+
+impl From<Okhsl> for ColorSpaceTag {
+    fn from(_: Okhsl) -> Self {
+        Self::Okhsl
+    }
+}
+
+/// 🌌 The Okhsv color space
+///
+/// Okhsv is an alternative to [HSV](Hsv) built on [`Oklab`], with a more uniform hue. Its
+/// saturation and value are fitted to the sRGB gamut, such that every color with components
+/// within their natural bounds is in the sRGB gamut.
+///
+/// Its components are `[h, s, v]` with
+/// - `h` - the hue angle in degrees, as in [`Oklch`];
+/// - `s` - the saturation, where 0 is gray and 1 is on the boundary of the sRGB gamut; and
+/// - `v` - the value, where 0 is black and 1 is the brightest color of the hue and saturation.
+///
+/// Unlike in [`Hsv`], the saturation and value are in the range 0 to 1, as in Okhsv's
+/// [definition][bjorn]. It is commonly used with the `--okhsv` identifier in the CSS `color()`
+/// function, as in [Color.js].
+///
+/// [bjorn]: https://bottosson.github.io/posts/colorpicker/#okhsv
+/// [Color.js]: https://colorjs.io/docs/spaces#okhsv
+#[derive(Clone, Copy, Debug)]
+pub struct Okhsv;
+
+/// The saturation of the triangle approximating the sRGB gamut in [`Okhsv`], where the
+/// saturation is 0.5 in Okhsv.
+const OKHSV_S_0: f32 = 0.5;
+
+/// The scale of the lightness and chroma compensating for the curved top of the sRGB gamut in
+/// [`Okhsv`], given the lightness and chroma on the triangle approximating the gamut.
+fn okhsv_scale(l_v: f32, c_v: f32, a: f32, b: f32) -> f32 {
+    let l_vt = toe_inv(l_v);
+    let c_vt = c_v * l_vt / l_v;
+    let [r, g, b] = Oklab::to_linear_srgb([l_vt, a * c_vt, b * c_vt]);
+    (1. / r.max(g).max(b).max(0.)).cbrt()
+}
+
+impl ColorSpace for Okhsv {
+    const TAG: Option<ColorSpaceTag> = Some(ColorSpaceTag::Okhsv);
+
+    const LAYOUT: ColorSpaceLayout = ColorSpaceLayout::HueFirst;
+
+    const WHITE_COMPONENTS: [f32; 3] = [0., 0., 1.];
+
+    fn to_linear_srgb([h, s, v]: [f32; 3]) -> [f32; 3] {
+        if v <= 0. {
+            return [0.; 3];
+        }
+        let (b, a) = h.to_radians().sin_cos();
+        let (s_max, t_max) = cusp_to_st(find_cusp(a, b));
+        let k = 1. - OKHSV_S_0 / s_max;
+
+        // First compute the lightness and chroma as if the gamut were a perfect triangle, ...
+        let denom = OKHSV_S_0 + t_max - t_max * k * s;
+        let l_v = 1. - s * OKHSV_S_0 / denom;
+        let c_v = s * t_max * OKHSV_S_0 / denom;
+        let mut l = v * l_v;
+        let mut c = v * c_v;
+
+        // ... then compensate for both the toe and the curved top part of the triangle.
+        let scale_l = okhsv_scale(l_v, c_v, a, b);
+        let l_new = toe_inv(l);
+        c *= l_new / l;
+        l = l_new;
+        l *= scale_l;
+        c *= scale_l;
+        Oklab::to_linear_srgb([l, c * a, c * b])
+    }
+
+    fn from_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        let [mut l, a, b] = Oklab::from_linear_srgb(src);
+        let h = oklab_hue(a, b);
+        if l <= 0. {
+            return [h, 0., 0.];
+        }
+        let (a, b, mut c) = oklab_hue_chroma(a, b);
+        let (s_max, t_max) = cusp_to_st(find_cusp(a, b));
+        let k = 1. - OKHSV_S_0 / s_max;
+
+        // Find the lightness and chroma on the triangle approximating the gamut, ...
+        let t = t_max / (c + l * t_max);
+        let l_v = t * l;
+        let c_v = t * c;
+
+        // ... then invert the compensation for the toe and the curved top of the triangle.
+        let scale_l = okhsv_scale(l_v, c_v, a, b);
+        l /= scale_l;
+        c /= scale_l;
+        let l_toe = toe(l);
+        c *= l_toe / l;
+        l = l_toe;
+
+        let v = l / l_v;
+        let s = if c < OKHSX_ACHROMATIC_CHROMA {
+            0.
+        } else {
+            (OKHSV_S_0 + t_max) * c_v / (t_max * OKHSV_S_0 + t_max * k * c_v)
+        };
+        [h, s, v]
+    }
+
+    fn scale_chroma([h, s, v]: [f32; 3], scale: f32) -> [f32; 3] {
+        [h, s * scale, v]
+    }
+
+    fn clip([h, s, v]: [f32; 3]) -> [f32; 3] {
+        [h, s.clamp(0., 1.), v.clamp(0., 1.)]
+    }
+
+    fn is_in_gamut(src: [f32; 3], epsilon: f32) -> bool {
+        Srgb::is_in_gamut(Self::convert::<Srgb>(src), epsilon)
+    }
+}
+
+impl From<Okhsv> for ColorSpaceTag {
+    fn from(_: Okhsv) -> Self {
+        Self::Okhsv
+    }
+}
+
+/// 🌌 The Jzazbz color space, a perceptually uniform color space for high dynamic range.
+///
+/// Its components are `[Jz, az, bz]` with
+/// - `Jz` - the lightness, where 0 represents pure black and about 0.222 represents the lightness
+///   of white;
+/// - `az` - how green/red the color is; and
+/// - `bz` - how blue/yellow the color is.
+///
+/// `Jz` has a natural lower bound of 0 and an upper bound of 1, which corresponds to 10,000 cd/m².
+/// `az` and `bz` are unbounded, but are usually between -0.25 and 0.25.
+///
+/// As in [`Rec2100Pq`], the white of [linear sRGB](`LinearSrgb`) is taken to have a luminance of
+/// 203 cd/m², so brighter colors convert to linear sRGB values greater than 1. Jzazbz does not
+/// map the D65 white point exactly to the neutral axis, so achromatic colors have small non-zero
+/// `az` and `bz` components.
+///
+/// This color space was proposed by [Safdar et al.][paper] in 2017. It is commonly used with the
+/// `--jzazbz` identifier in the CSS `color()` function, as in [Color.js].
+///
+/// Jzazbz has a cylindrical counterpart: [`JzCzhz`].
+///
+/// [paper]: https://doi.org/10.1364/OE.25.015131
+/// [Color.js]: https://colorjs.io/docs/spaces#jzazbz
+#[derive(Clone, Copy, Debug)]
+pub struct Jzazbz;
+
+// This is the XYZ to LMS matrix of Jzazbz, premultiplied by the `b` and `g` parameters of the
+// color space, times lin_sRGB_to_XYZ, scaled by 203 cd/m² and divided by the 10,000 cd/m² peak
+// luminance of the perceptual quantizer. Computed in f64, then reduced to f32.
+const JZAZBZ_SRGB_TO_LMS: [[f32; 3]; 3] = [
+    [0.007_277_867, 0.010_336_397, 0.002_113_219_5],
+    [0.004_475_095, 0.012_023_139, 0.003_238_737],
+    [0.001_611_583_3, 0.004_675_742_5, 0.013_462_939],
+];
+
+// The inverse of the above.
+const JZAZBZ_LMS_TO_SRGB: [[f32; 3]; 3] = [
+    [292.098_1, -257.366_67, 16.064_508],
+    [-109.551_125, 188.282_47, -28.098_752],
+    [3.081_973_6, -34.583_275, 82.113_81],
+];
+
+const JZAZBZ_LMS_TO_IAB: [[f32; 3]; 3] = [
+    [0.5, 0.5, 0.],
+    [3.524, -4.066_708, 0.542_708],
+    [0.199_076, 1.096_799, -1.295_875],
+];
+
+const JZAZBZ_IAB_TO_LMS: [[f32; 3]; 3] = [
+    [1., 0.138_605_04, 0.058_047_316],
+    [1., -0.138_605_04, -0.058_047_316],
+    [1., -0.096_019_24, -0.811_891_9],
+];
+
+impl Jzazbz {
+    // The perceptual quantizer of Jzazbz is that of Rec. 2100 PQ, but with a larger exponent.
+    const P: f64 = 1.7 * 2523. / 32.;
+    const D: f32 = -0.56;
+    const D0: f32 = 1.629_55e-11;
+
+    /// The perceptual quantizer, from luminance relative to 10,000 cd/m² to a non-linear value.
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "the result is in the range of f32"
+    )]
+    fn pq(x: f32) -> f32 {
+        let y = f64::from(x.abs()).powf(Rec2100Pq::M1);
+        let v = ((Rec2100Pq::C1 + Rec2100Pq::C2 * y) / (1. + Rec2100Pq::C3 * y)).powf(Self::P);
+        (v as f32).copysign(x)
+    }
+
+    /// The inverse of [`Self::pq`].
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "the result is in the range of f32"
+    )]
+    fn inverse_pq(x: f32) -> f32 {
+        let e = f64::from(x.abs()).powf(1. / Self::P);
+        let v = ((e - Rec2100Pq::C1).max(0.) / (Rec2100Pq::C2 - Rec2100Pq::C3 * e))
+            .powf(1. / Rec2100Pq::M1);
+        (v as f32).copysign(x)
+    }
+}
+
+impl ColorSpace for Jzazbz {
+    const TAG: Option<ColorSpaceTag> = Some(ColorSpaceTag::Jzazbz);
+
+    const WHITE_COMPONENTS: [f32; 3] = [0.222_065_25, -0.000_160_624_93, -0.000_117_034];
+
+    fn to_linear_srgb([jz, az, bz]: [f32; 3]) -> [f32; 3] {
+        let jz = jz + Self::D0;
+        let iz = jz / (1. + Self::D - Self::D * jz);
+        let lms = matvecmul(&JZAZBZ_IAB_TO_LMS, [iz, az, bz]).map(Self::inverse_pq);
+        matvecmul(&JZAZBZ_LMS_TO_SRGB, lms)
+    }
+
+    fn from_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        let lms = matvecmul(&JZAZBZ_SRGB_TO_LMS, src).map(Self::pq);
+        let [iz, az, bz] = matvecmul(&JZAZBZ_LMS_TO_IAB, lms);
+        let jz = (1. + Self::D) * iz / (1. + Self::D * iz) - Self::D0;
+        [jz, az, bz]
+    }
+
+    fn scale_chroma([jz, az, bz]: [f32; 3], scale: f32) -> [f32; 3] {
+        [jz, az * scale, bz * scale]
+    }
+
+    fn convert<TargetCS: ColorSpace>(src: [f32; 3]) -> [f32; 3] {
+        if TypeId::of::<Self>() == TypeId::of::<TargetCS>() {
+            src
+        } else if TypeId::of::<TargetCS>() == TypeId::of::<JzCzhz>() {
+            lab_to_lch(src)
+        } else {
+            let lin_rgb = Self::to_linear_srgb(src);
+            TargetCS::from_linear_srgb(lin_rgb)
+        }
+    }
+
+    fn clip([jz, az, bz]: [f32; 3]) -> [f32; 3] {
+        [jz.clamp(0., 1.), az, bz]
+    }
+
+    fn is_in_gamut(_src: [f32; 3], _epsilon: f32) -> bool {
+        true
+    }
+}
+
+impl From<Jzazbz> for ColorSpaceTag {
+    fn from(_: Jzazbz) -> Self {
+        Self::Jzazbz
+    }
+}
+
+/// 🌌 The cylindrical version of the [Jzazbz] color space.
+///
+/// Its components are `[Jz, Cz, hz]` with
+/// - `Jz` - the lightness as in [`Jzazbz`];
+/// - `Cz` - the chromatic intensity, the natural lower bound of 0 being achromatic, usually not
+///   exceeding 0.25; and
+/// - `hz` - the hue angle in degrees.
+///
+/// It is commonly used with the `--jzczhz` identifier in the CSS `color()` function.
+#[derive(Clone, Copy, Debug)]
+pub struct JzCzhz;
+
+impl ColorSpace for JzCzhz {
+    const TAG: Option<ColorSpaceTag> = Some(ColorSpaceTag::JzCzhz);
+
+    const LAYOUT: ColorSpaceLayout = ColorSpaceLayout::HueThird;
+
+    const WHITE_COMPONENTS: [f32; 3] = [0.222_065_25, 0.000_198_739_34, 216.077_7];
+
+    fn from_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        lab_to_lch(Jzazbz::from_linear_srgb(src))
+    }
+
+    fn to_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        Jzazbz::to_linear_srgb(lch_to_lab(src))
+    }
+
+    fn scale_chroma([jz, cz, hz]: [f32; 3], scale: f32) -> [f32; 3] {
+        [jz, cz * scale, hz]
+    }
+
+    fn convert<TargetCS: ColorSpace>(src: [f32; 3]) -> [f32; 3] {
+        if TypeId::of::<Self>() == TypeId::of::<TargetCS>() {
+            src
+        } else if TypeId::of::<TargetCS>() == TypeId::of::<Jzazbz>() {
+            lch_to_lab(src)
+        } else {
+            let lin_rgb = Self::to_linear_srgb(src);
+            TargetCS::from_linear_srgb(lin_rgb)
+        }
+    }
+
+    fn clip([jz, cz, hz]: [f32; 3]) -> [f32; 3] {
+        [jz.clamp(0., 1.), cz.max(0.), hz]
+    }
+
+    fn is_in_gamut(_src: [f32; 3], _epsilon: f32) -> bool {
+        true
+    }
+}
+
+impl From<JzCzhz> for ColorSpaceTag {
+    fn from(_: JzCzhz) -> Self {
+        Self::JzCzhz
+    }
+}
+
+/// 🌌 The CIELAB color space
+///
+/// The CIE L\*a\*b\* color space was created in 1976 to be more perceptually
+/// uniform than RGB color spaces, and is both widely used and the basis of
+/// other efforts to express colors, including [FreieFarbe].
+///
+/// Its components are `[L, a, b]` with
+/// - `L` - the lightness with a natural bound between 0 and 100, where 0 represents pure black and 100
+///   represents the lightness of white;
+/// - `a` - how green/red the color is; and
+/// - `b` - how blue/yellow the color is.
+///
+/// `a` and `b` are unbounded, but are usually between -160 and 160.
+///
+/// The color space has poor hue linearity and hue uniformity compared with
+/// [Oklab], though superior lightness uniformity. Note that the lightness
+/// range differs from Oklab as well; in Oklab white has a lightness of 1.
+///
+/// The CIE L\*a\*b\* color space is defined in terms of a D50 white point. For
+/// conversion between color spaces with other illuminants (especially D65
+/// as in sRGB), the standard Bradform linear chromatic adaptation transform
+/// is used.
+///
+/// This corresponds to the color space in [CSS Color Module Level 4 § 9.1 ][css-sec].
+///
+/// Lab has a cylindrical counterpart: [Lch].
+///
+/// [FreieFarbe]: https://freiefarbe.de/en/
+/// [css-sec]: https://www.w3.org/TR/css-color-4/#cie-lab
+#[derive(Clone, Copy, Debug)]
+pub struct Lab;
+
+// Matrices computed from CSS Color 4 spec, then used `cargo clippy --fix`
+// to reduce precision to f32 and add underscores.
+
+// This is D65_to_D50 * lin_sRGB_to_XYZ, then rows scaled by 1 / D50[i].
+const LAB_SRGB_TO_XYZ: [[f32; 3]; 3] = [
+    [0.452_211_65, 0.399_412_24, 0.148_376_09],
+    [0.222_493_17, 0.716_887, 0.060_619_81],
+    [0.016_875_342, 0.117_659_41, 0.865_465_2],
+];
+
+// This is XYZ_to_lin_sRGB * D50_to_D65, then columns scaled by D50[i].
+const LAB_XYZ_TO_SRGB: [[f32; 3]; 3] = [
+    [3.022_233_7, -1.617_386, -0.404_847_65],
+    [-0.943_848_25, 1.916_254_4, 0.027_593_868],
+    [0.069_386_27, -0.228_976_76, 1.159_590_5],
+];
+
+const EPSILON: f32 = 216. / 24389.;
+const KAPPA: f32 = 24389. / 27.;
+
+/// Convert a relative luminance `Y` to the lightness `L*` of [`Lab`].
+pub(crate) fn lstar_from_y(y: f32) -> f32 {
+    if y > EPSILON {
+        116. * y.cbrt() - 16.
+    } else {
+        KAPPA * y
+    }
+}
+
+/// Convert the lightness `L*` of [`Lab`] to a relative luminance `Y`.
+pub(crate) fn y_from_lstar(lstar: f32) -> f32 {
+    if lstar > KAPPA * EPSILON {
+        let f = (lstar + 16.) * (1. / 116.);
+        f * f * f
+    } else {
+        lstar * (1. / KAPPA)
+    }
+}
+
+impl ColorSpace for Lab {
+    const TAG: Option<ColorSpaceTag> = Some(ColorSpaceTag::Lab);
 
     const WHITE_COMPONENTS: [f32; 3] = [100., 0., 0.];
 
@@ -1434,6 +2341,10 @@ impl ColorSpace for Lab {
     fn clip([l, a, b]: [f32; 3]) -> [f32; 3] {
         [l.clamp(0., 100.), a, b]
     }
+
+    fn is_in_gamut(_src: [f32; 3], _epsilon: f32) -> bool {
+        true
+    }
 }
 
 impl From<Lab> for ColorSpaceTag {
@@ -1487,6 +2398,10 @@ impl ColorSpace for Lch {
     fn clip([l, c, h]: [f32; 3]) -> [f32; 3] {
         [l.clamp(0., 100.), c.max(0.), h]
     }
+
+    fn is_in_gamut(_src: [f32; 3], _epsilon: f32) -> bool {
+        true
+    }
 }
 
 impl From<Lch> for ColorSpaceTag {
@@ -1495,6 +2410,329 @@ impl From<Lch> for ColorSpaceTag {
     }
 }
 
+/// 🌌 The CIELUV color space
+///
+/// The CIE L\*u\*v\* color space was created in 1976, alongside [CIELAB](Lab), to be more
+/// perceptually uniform than XYZ. Unlike CIELAB, additive mixtures of lights lie on straight lines
+/// in the chromaticity plane, which makes the boundaries of RGB gamuts easy to compute.
+///
+/// Its components are `[L, u, v]` with
+/// - `L` - the lightness as in [`Lab`];
+/// - `u` - how green/red the color is; and
+/// - `v` - how blue/yellow the color is.
+///
+/// `u` and `v` are unbounded, but are usually between -100 and 200.
+///
+/// This color space uses a D65 white point, as in [`XyzD65`]. It is commonly used with the
+/// `--luv` identifier in the CSS `color()` function, as in [Color.js].
+///
+/// Luv has a cylindrical counterpart: [`LchUv`].
+///
+/// [Color.js]: https://colorjs.io/docs/spaces#cie-luv
+#[derive(Clone, Copy, Debug)]
+pub struct Luv;
+
+/// The chromaticity `u′` of the D65 white point.
+const LUV_WHITE_U: f32 = 0.197_83;
+
+/// The chromaticity `v′` of the D65 white point.
+const LUV_WHITE_V: f32 = 0.468_32;
+
+impl ColorSpace for Luv {
+    const TAG: Option<ColorSpaceTag> = Some(ColorSpaceTag::Luv);
+
+    const WHITE_COMPONENTS: [f32; 3] = [100., 0., 0.];
+
+    fn to_linear_srgb([l, u, v]: [f32; 3]) -> [f32; 3] {
+        if l == 0. {
+            return [0.; 3];
+        }
+        let u_prime = u / (13. * l) + LUV_WHITE_U;
+        let v_prime = v / (13. * l) + LUV_WHITE_V;
+        let y = y_from_lstar(l);
+        let x = y * 9. * u_prime / (4. * v_prime);
+        let z = y * (12. - 3. * u_prime - 20. * v_prime) / (4. * v_prime);
+        XyzD65::to_linear_srgb([x, y, z])
+    }
+
+    fn from_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        let [x, y, z] = XyzD65::from_linear_srgb(src);
+        let l = lstar_from_y(y);
+        let denominator = x + 15. * y + 3. * z;
+        if denominator == 0. {
+            return [l, 0., 0.];
+        }
+        let u = 13. * l * (4. * x / denominator - LUV_WHITE_U);
+        let v = 13. * l * (9. * y / denominator - LUV_WHITE_V);
+        [l, u, v]
+    }
+
+    fn scale_chroma([l, u, v]: [f32; 3], scale: f32) -> [f32; 3] {
+        [l, u * scale, v * scale]
+    }
+
+    fn convert<TargetCS: ColorSpace>(src: [f32; 3]) -> [f32; 3] {
+        if TypeId::of::<Self>() == TypeId::of::<TargetCS>() {
+            src
+        } else if TypeId::of::<TargetCS>() == TypeId::of::<LchUv>() {
+            lab_to_lch(src)
+        } else {
+            let lin_rgb = Self::to_linear_srgb(src);
+            TargetCS::from_linear_srgb(lin_rgb)
+        }
+    }
+
+    fn clip([l, u, v]: [f32; 3]) -> [f32; 3] {
+        [l.clamp(0., 100.), u, v]
+    }
+
+    fn is_in_gamut(_src: [f32; 3], _epsilon: f32) -> bool {
+        true
+    }
+}
+
+impl From<Luv> for ColorSpaceTag {
+    fn from(_: Luv) -> Self {
+        Self::Luv
+    }
+}
+
+/// 🌌 The cylindrical version of the [Luv] color space.
+///
+/// Its components are `[L, C, h]` with
+/// - `L` - the lightness as in [`Luv`];
+/// - `C` - the chromatic intensity, the natural lower bound of 0 being achromatic, usually not
+///   exceeding 180; and
+/// - `h` - the hue angle in degrees.
+///
+/// It is commonly used with the `--lchuv` identifier in the CSS `color()` function.
+#[derive(Clone, Copy, Debug)]
+pub struct LchUv;
+
+impl ColorSpace for LchUv {
+    const TAG: Option<ColorSpaceTag> = Some(ColorSpaceTag::LchUv);
+
+    const LAYOUT: ColorSpaceLayout = ColorSpaceLayout::HueThird;
+
+    const WHITE_COMPONENTS: [f32; 3] = [100., 0., 0.];
+
+    fn from_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        lab_to_lch(Luv::from_linear_srgb(src))
+    }
+
+    fn to_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        Luv::to_linear_srgb(lch_to_lab(src))
+    }
+
+    fn scale_chroma([l, c, h]: [f32; 3], scale: f32) -> [f32; 3] {
+        [l, c * scale, h]
+    }
+
+    fn convert<TargetCS: ColorSpace>(src: [f32; 3]) -> [f32; 3] {
+        if TypeId::of::<Self>() == TypeId::of::<TargetCS>() {
+            src
+        } else if TypeId::of::<TargetCS>() == TypeId::of::<Luv>() {
+            lch_to_lab(src)
+        } else {
+            let lin_rgb = Self::to_linear_srgb(src);
+            TargetCS::from_linear_srgb(lin_rgb)
+        }
+    }
+
+    fn clip([l, c, h]: [f32; 3]) -> [f32; 3] {
+        [l.clamp(0., 100.), c.max(0.), h]
+    }
+
+    fn is_in_gamut(_src: [f32; 3], _epsilon: f32) -> bool {
+        true
+    }
+}
+
+impl From<LchUv> for ColorSpaceTag {
+    fn from(_: LchUv) -> Self {
+        Self::LchUv
+    }
+}
+
+/// The lines bounding the sRGB gamut in the `u`-`v` plane of [`Luv`] at lightness `l`.
+///
+/// Each line `[a, b, c]` is the set of points with `a * u + b * v + c = 0`, on which one of the
+/// linear sRGB channels is 0 or 1.
+fn luv_srgb_bounds(l: f32) -> [[f32; 3]; 6] {
+    let y = y_from_lstar(l);
+    let mut bounds = [[0.; 3]; 6];
+    for (channel, [m0, m1, m2]) in XYZ_D65_TO_LINEAR_SRGB.into_iter().enumerate() {
+        for (bound, t) in [0., 1.].into_iter().enumerate() {
+            // Substituting the inverse of Luv in `m0 * x + m1 * y + m2 * z = t` and multiplying
+            // by the denominator gives a linear equation in `u` and `v`.
+            let a = y * (9. * m0 - 3. * m2);
+            let b = y * (4. * m1 - 20. * m2) - 4. * t;
+            let c = 13. * l * (a * LUV_WHITE_U + b * LUV_WHITE_V + 12. * m2 * y);
+            bounds[2 * channel + bound] = [a, b, c];
+        }
+    }
+    bounds
+}
+
+/// The maximum chroma of [`LchUv`] in the sRGB gamut at the given lightness and hue.
+fn lchuv_max_chroma(l: f32, h: f32) -> f32 {
+    let (sin, cos) = h.to_radians().sin_cos();
+    luv_srgb_bounds(l)
+        .into_iter()
+        .map(|[a, b, c]| -c / (a * cos + b * sin))
+        .filter(|length| *length >= 0.)
+        .fold(f32::INFINITY, f32::min)
+}
+
+/// The maximum chroma of [`LchUv`] in the sRGB gamut at the given lightness, for all hues.
+fn lchuv_max_safe_chroma(l: f32) -> f32 {
+    luv_srgb_bounds(l)
+        .into_iter()
+        .map(|[a, b, c]| c.abs() / a.hypot(b))
+        .fold(f32::INFINITY, f32::min)
+}
+
+/// Whether a lightness of [`Luv`] is black or white, where the sRGB gamut vanishes.
+///
+/// The tolerance accounts for the precision of converting white to `f32`.
+fn luv_is_extreme(l: f32) -> bool {
+    !(1e-4..=100. - 1e-4).contains(&l)
+}
+
+/// 🌌 The `HSLuv` color space
+///
+/// `HSLuv` is a human-friendly alternative to [HSL](Hsl), built on [`LchUv`] instead of sRGB.
+/// The lightness is perceptually uniform, and the saturation is the chroma of `LCHuv` relative to
+/// the highest chroma in the sRGB gamut at the same lightness and hue.
+///
+/// Its components are `[H, S, L]` with
+/// - `H` - the hue angle in degrees, as in [`LchUv`];
+/// - `S` - the saturation, where 0 is gray and 100 is on the boundary of the sRGB gamut; and
+/// - `L` - the lightness, as in [`Luv`], where 0 is black and 100 is white.
+///
+/// Every color with components within these bounds is in the sRGB gamut. Colors of the same
+/// saturation and lightness can have very different chroma, see [`Hpluv`] for an alternative.
+///
+/// This color space is defined on [hsluv.org]. It is commonly used with the `--hsluv` identifier
+/// in the CSS `color()` function.
+///
+/// [hsluv.org]: https://www.hsluv.org/
+#[derive(Clone, Copy, Debug)]
+pub struct Hsluv;
+
+impl ColorSpace for Hsluv {
+    const TAG: Option<ColorSpaceTag> = Some(ColorSpaceTag::Hsluv);
+
+    const LAYOUT: ColorSpaceLayout = ColorSpaceLayout::HueFirst;
+
+    const WHITE_COMPONENTS: [f32; 3] = [0., 0., 100.];
+
+    fn from_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        let [l, c, h] = LchUv::from_linear_srgb(src);
+        let s = if luv_is_extreme(l) {
+            0.
+        } else {
+            100. * c / lchuv_max_chroma(l, h)
+        };
+        [h, s, l]
+    }
+
+    fn to_linear_srgb([h, s, l]: [f32; 3]) -> [f32; 3] {
+        let c = if luv_is_extreme(l) {
+            0.
+        } else {
+            0.01 * s * lchuv_max_chroma(l, h)
+        };
+        LchUv::to_linear_srgb([l, c, h])
+    }
+
+    fn scale_chroma([h, s, l]: [f32; 3], scale: f32) -> [f32; 3] {
+        [h, s * scale, l]
+    }
+
+    fn clip([h, s, l]: [f32; 3]) -> [f32; 3] {
+        [h, s.clamp(0., 100.), l.clamp(0., 100.)]
+    }
+
+    fn is_in_gamut(src: [f32; 3], epsilon: f32) -> bool {
+        Srgb::is_in_gamut(Self::convert::<Srgb>(src), epsilon)
+    }
+}
+
+impl From<Hsluv> for ColorSpaceTag {
+    fn from(_: Hsluv) -> Self {
+        Self::Hsluv
+    }
+}
+
+/// 🌌 The `HPLuv` color space
+///
+/// `HPLuv` is a variant of [`Hsluv`] where the saturation is the chroma of [`LchUv`]
+/// relative to the highest chroma in the sRGB gamut at the same lightness, for all hues. Colors of
+/// the same saturation and lightness have the same chroma, which makes `HPLuv` suited to pastel
+/// palettes.
+///
+/// Its components are `[H, S, L]` with
+/// - `H` - the hue angle in degrees, as in [`LchUv`];
+/// - `S` - the saturation, where 0 is gray and 100 is the highest chroma in the sRGB gamut for
+///   all hues; and
+/// - `L` - the lightness, as in [`Luv`], where 0 is black and 100 is white.
+///
+/// Every color with components within these bounds is in the sRGB gamut, but colors of some hues
+/// with saturation greater than 100 are in the sRGB gamut as well.
+///
+/// This color space is defined on [hsluv.org]. It is commonly used with the `--hpluv` identifier
+/// in the CSS `color()` function.
+///
+/// [hsluv.org]: https://www.hsluv.org/
+#[derive(Clone, Copy, Debug)]
+pub struct Hpluv;
+
+impl ColorSpace for Hpluv {
+    const TAG: Option<ColorSpaceTag> = Some(ColorSpaceTag::Hpluv);
+
+    const LAYOUT: ColorSpaceLayout = ColorSpaceLayout::HueFirst;
+
+    const WHITE_COMPONENTS: [f32; 3] = [0., 0., 100.];
+
+    fn from_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        let [l, c, h] = LchUv::from_linear_srgb(src);
+        let s = if luv_is_extreme(l) {
+            0.
+        } else {
+            100. * c / lchuv_max_safe_chroma(l)
+        };
+        [h, s, l]
+    }
+
+    fn to_linear_srgb([h, s, l]: [f32; 3]) -> [f32; 3] {
+        let c = if luv_is_extreme(l) {
+            0.
+        } else {
+            0.01 * s * lchuv_max_safe_chroma(l)
+        };
+        LchUv::to_linear_srgb([l, c, h])
+    }
+
+    fn scale_chroma([h, s, l]: [f32; 3], scale: f32) -> [f32; 3] {
+        [h, s * scale, l]
+    }
+
+    fn clip([h, s, l]: [f32; 3]) -> [f32; 3] {
+        [h, s.max(0.), l.clamp(0., 100.)]
+    }
+
+    fn is_in_gamut(src: [f32; 3], epsilon: f32) -> bool {
+        Srgb::is_in_gamut(Self::convert::<Srgb>(src), epsilon)
+    }
+}
+
+impl From<Hpluv> for ColorSpaceTag {
+    fn from(_: Hpluv) -> Self {
+        Self::Hpluv
+    }
+}
+
 /// 🌌 The HSL color space
 ///
 /// The HSL color space is fairly widely used and convenient, but it is
@@ -1594,6 +2832,8 @@ impl ColorSpace for Hsl {
             hsl_to_rgb(src)
         } else if TypeId::of::<TargetCS>() == TypeId::of::<Hwb>() {
             rgb_to_hwb(hsl_to_rgb(src))
+        } else if TypeId::of::<TargetCS>() == TypeId::of::<Hsv>() {
+            hsl_to_hsv(src)
         } else {
             let lin_rgb = Self::to_linear_srgb(src);
             TargetCS::from_linear_srgb(lin_rgb)
@@ -1603,6 +2843,10 @@ impl ColorSpace for Hsl {
     fn clip([h, s, l]: [f32; 3]) -> [f32; 3] {
         [h, s.max(0.), l.clamp(0., 100.)]
     }
+
+    fn is_in_gamut(src: [f32; 3], epsilon: f32) -> bool {
+        Srgb::is_in_gamut(Self::convert::<Srgb>(src), epsilon)
+    }
 }
 
 impl From<Hsl> for ColorSpaceTag {
@@ -1683,6 +2927,8 @@ impl ColorSpace for Hwb {
             hwb_to_rgb(src)
         } else if TypeId::of::<TargetCS>() == TypeId::of::<Hsl>() {
             rgb_to_hsl(hwb_to_rgb(src), true)
+        } else if TypeId::of::<TargetCS>() == TypeId::of::<Hsv>() {
+            hwb_to_hsv(src)
         } else {
             let lin_rgb = Self::to_linear_srgb(src);
             TargetCS::from_linear_srgb(lin_rgb)
@@ -1692,6 +2938,10 @@ impl ColorSpace for Hwb {
     fn clip([h, w, b]: [f32; 3]) -> [f32; 3] {
         [h, w.clamp(0., 100.), b.clamp(0., 100.)]
     }
+
+    fn is_in_gamut(src: [f32; 3], epsilon: f32) -> bool {
+        Srgb::is_in_gamut(Self::convert::<Srgb>(src), epsilon)
+    }
 }
 
 impl From<Hwb> for ColorSpaceTag {
@@ -1700,13 +2950,126 @@ impl From<Hwb> for ColorSpaceTag {
     }
 }
 
+/// 🌌 The HSV color space
+///
+/// The HSV color space, also known as HSB, is the model of many color pickers. Like [Hsl] and
+/// [Hwb], it is a cylindrical representation of [sRGB](Srgb) and shares their hue angle, and
+/// thus their flaw of poor hue uniformity.
+///
+/// Its components are `[H, S, V]` with
+/// - `H` - the hue angle in degrees, with red at 0, green at 120, and blue at 240.
+/// - `S` - the saturation, where 0 is gray and 100 is maximally saturated.
+/// - `V` - the value, where 0 is black and 100 is the brightest color of the hue.
+///
+/// HSV is not a CSS color space. It is used with the `--hsv` identifier in the CSS `color()`
+/// function, as in [Color.js].
+///
+/// [Color.js]: https://colorjs.io/docs/spaces#hsv
+#[derive(Clone, Copy, Debug)]
+pub struct Hsv;
+
+/// Convert HSV to HWB.
+fn hsv_to_hwb([h, s, v]: [f32; 3]) -> [f32; 3] {
+    [h, (1. - s * 0.01) * v, 100. - v]
+}
+
+/// Convert HWB to HSV.
+///
+/// Whiteness and blackness adding up to more than 100 are normalized, as in [`hwb_to_rgb`].
+fn hwb_to_hsv([h, w, b]: [f32; 3]) -> [f32; 3] {
+    if w + b >= 100. {
+        return [h, 0., 100. * w / (w + b)];
+    }
+    let v = 100. - b;
+    [h, 100. * (1. - w / v), v]
+}
+
+/// Convert HSV to HSL.
+fn hsv_to_hsl([h, s, v]: [f32; 3]) -> [f32; 3] {
+    let sat = s * 0.01;
+    let value = v * 0.01;
+    let light = value * (1. - 0.5 * sat);
+    let denom = light.min(1. - light);
+    let sat = if denom > 0. {
+        (value - light) / denom
+    } else {
+        0.
+    };
+    [h, sat * 100., light * 100.]
+}
+
+/// Convert HSL to HSV.
+fn hsl_to_hsv([h, s, l]: [f32; 3]) -> [f32; 3] {
+    let sat = s * 0.01;
+    let light = l * 0.01;
+    let value = light + sat * light.min(1. - light);
+    let sat = if value > 0. {
+        2. * (1. - light / value)
+    } else {
+        0.
+    };
+    [h, sat * 100., value * 100.]
+}
+
+impl ColorSpace for Hsv {
+    const TAG: Option<ColorSpaceTag> = Some(ColorSpaceTag::Hsv);
+
+    const LAYOUT: ColorSpaceLayout = ColorSpaceLayout::HueFirst;
+
+    const WHITE_COMPONENTS: [f32; 3] = [0., 0., 100.];
+
+    fn from_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        let rgb = Srgb::from_linear_srgb(src);
+        hwb_to_hsv(rgb_to_hwb(rgb))
+    }
+
+    fn to_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        let rgb = hwb_to_rgb(hsv_to_hwb(src));
+        Srgb::to_linear_srgb(rgb)
+    }
+
+    fn scale_chroma([h, s, v]: [f32; 3], scale: f32) -> [f32; 3] {
+        [h, s * scale, v]
+    }
+
+    fn convert<TargetCS: ColorSpace>(src: [f32; 3]) -> [f32; 3] {
+        if TypeId::of::<Self>() == TypeId::of::<TargetCS>() {
+            src
+        } else if TypeId::of::<TargetCS>() == TypeId::of::<Srgb>() {
+            hwb_to_rgb(hsv_to_hwb(src))
+        } else if TypeId::of::<TargetCS>() == TypeId::of::<Hsl>() {
+            hsv_to_hsl(src)
+        } else if TypeId::of::<TargetCS>() == TypeId::of::<Hwb>() {
+            hsv_to_hwb(src)
+        } else {
+            let lin_rgb = Self::to_linear_srgb(src);
+            TargetCS::from_linear_srgb(lin_rgb)
+        }
+    }
+
+    fn clip([h, s, v]: [f32; 3]) -> [f32; 3] {
+        [h, s.clamp(0., 100.), v.clamp(0., 100.)]
+    }
+
+    fn is_in_gamut(src: [f32; 3], epsilon: f32) -> bool {
+        Srgb::is_in_gamut(Self::convert::<Srgb>(src), epsilon)
+    }
+}
+
+impl From<Hsv> for ColorSpaceTag {
+    fn from(_: Hsv) -> Self {
+        Self::Hsv
+    }
+}
+
 #[cfg(test)]
 mod tests {
     extern crate alloc;
 
     use crate::{
-        A98Rgb, Aces2065_1, AcesCg, Chromaticity, ColorSpace, DisplayP3, Hsl, Hwb, Lab, Lch,
-        LinearSrgb, Oklab, Oklch, OpaqueColor, ProphotoRgb, Rec2020, Srgb, XyzD50, XyzD65,
+        A98Rgb, Aces2065_1, AcesCg, Chromaticity, ColorSpace, DisplayP3, Hpluv, Hsl, Hsluv, Hsv,
+        Hwb, ICtCp, JzCzhz, Jzazbz, Lab, Lch, LchUv, LinearSrgb, Luv, Okhsl, Okhsv, Oklab, Oklch,
+        OpaqueColor, ProphotoRgb, Rec2020, Rec2100Hlg, Rec2100Pq, Srgb, XyzD50, XyzD65,
     };
     use alloc::vec::Vec;
 
@@ -1791,6 +3154,69 @@ mod tests {
             [0.2, 0.2, -0.1],
             [2.0, 0., -0.4],
         ]);
+
+        // The HDR transfer functions are steep near the top of their range and exponential
+        // above it, so these are tested with values of moderate magnitude.
+        let hdr_values = {
+            let components = [0., 1., -1., 0.5, 0.75, 0.000_001, -0.000_001];
+            let mut values = Vec::new();
+            for c0 in components {
+                for c1 in components {
+                    for c2 in components {
+                        values.push([c0, c1, c2]);
+                    }
+                }
+            }
+            values
+        };
+        test_roundtrips::<Rec2100Pq, Srgb>(&hdr_values);
+        test_roundtrips::<Rec2100Hlg, Srgb>(&hdr_values);
+        test_roundtrips::<Jzazbz, Srgb>(&[
+            [0., 0., 0.],
+            [0.222, 0., 0.],
+            [0.1, 0.05, -0.05],
+            [0.3, -0.1, 0.1],
+        ]);
+        test_roundtrips::<JzCzhz, Jzazbz>(&[[0.1, 0.05, 30.], [0.3, 0.1, 270.]]);
+        test_roundtrips::<Hsv, Srgb>(&[
+            [0., 0., 0.],
+            [0., 100., 100.],
+            [200., 50., 30.],
+            [300., 20., 90.],
+        ]);
+        test_roundtrips::<Hsv, Hsl>(&[[200., 50., 30.], [300., 20., 90.]]);
+        test_roundtrips::<Hsv, Hwb>(&[[200., 50., 30.], [300., 20., 90.]]);
+        let okhsx_values = [
+            [0., 0., 0.],
+            [29., 1., 0.6],
+            [140., 0.5, 0.3],
+            [250., 0.9, 0.8],
+            [330., 0.2, 0.95],
+        ];
+        test_roundtrips::<Okhsl, Srgb>(&okhsx_values);
+        test_roundtrips::<Okhsv, Srgb>(&okhsx_values);
+
+        test_roundtrips::<Luv, Srgb>(&[
+            [0., 0., 0.],
+            [100., 0., 0.],
+            [50., 80., -40.],
+            [120., -50., 100.],
+        ]);
+        test_roundtrips::<LchUv, Luv>(&[[50., 80., 30.], [70., 120., 270.]]);
+        let hsluv_values = [
+            [0., 0., 0.],
+            [12., 100., 53.],
+            [140., 50., 30.],
+            [300., 80., 90.],
+        ];
+        test_roundtrips::<Hsluv, Srgb>(&hsluv_values);
+        test_roundtrips::<Hpluv, Srgb>(&hsluv_values);
+        test_roundtrips::<ICtCp, Srgb>(&[
+            [0., 0., 0.],
+            [0.580_688_9, 0., 0.],
+            [0.5, 0.05, -0.05],
+            [0.7, -0.1, 0.2],
+        ]);
     }
 
     #[test]
@@ -1807,7 +3233,7 @@ mod tests {
             );
             // For cylindrical color spaces, hue is usually unstable when the color is gray in
             // general, or white specifically as here, so zero out hue for the purposes of this
-            // test. Also see `DynamicColor::powerless_to_missing`.
+            // test. Also see `DynamicColor::normalize_powerless`.
             let mut expected = CS::WHITE_COMPONENTS;
             let mut actual = Srgb::convert::<CS>(Srgb::WHITE_COMPONENTS);
             if let Some(hue) = CS::LAYOUT.hue_channel() {
@@ -1825,13 +3251,25 @@ mod tests {
         check_white::<DisplayP3>();
         check_white::<Hsl>();
         check_white::<Hwb>();
+        check_white::<Hsv>();
+        check_white::<Okhsl>();
+        check_white::<Okhsv>();
         check_white::<Lab>();
         check_white::<Lch>();
         check_white::<LinearSrgb>();
         check_white::<Oklab>();
         check_white::<Oklch>();
+        check_white::<Jzazbz>();
+        check_white::<JzCzhz>();
+        check_white::<Luv>();
+        check_white::<LchUv>();
+        check_white::<Hsluv>();
+        check_white::<Hpluv>();
         check_white::<ProphotoRgb>();
         check_white::<Rec2020>();
+        check_white::<Rec2100Pq>();
+        check_white::<Rec2100Hlg>();
+        check_white::<ICtCp>();
         check_white::<Aces2065_1>();
         check_white::<AcesCg>();
         check_white::<XyzD50>();
@@ -1896,6 +3334,157 @@ mod tests {
         }
     }
 
+    #[test]
+    fn rec2100_transfer_functions() {
+        // Reference values from ITU-R BT.2100 and BT.2408.
+        assert!((Rec2100Pq::eotf(0.) - 0.).abs() < 1e-6);
+        assert!((Rec2100Pq::eotf(1.) - 10_000.).abs() < 1e-1);
+        assert!((Rec2100Pq::inverse_eotf(203.) - 0.580_688_9).abs() < 1e-5);
+        assert!((Rec2100Pq::inverse_eotf(100.) - 0.508_078).abs() < 1e-5);
+        assert!((Rec2100Hlg::oetf(1. / 12.) - 0.5).abs() < 1e-6);
+        assert!((Rec2100Hlg::oetf(1.) - 1.).abs() < 1e-6);
+        assert!((Rec2100Hlg::inverse_oetf(0.5) - 1. / 12.).abs() < 1e-6);
+
+        // The reference white is diffuse white in both HDR encodings.
+        assert!(almost_equal::<Srgb>(
+            Rec2100Pq::convert::<Srgb>([0.580_688_9; 3]),
+            [1.; 3],
+            1e-4
+        ));
+        assert!(almost_equal::<Srgb>(
+            Rec2100Hlg::convert::<Srgb>([0.75; 3]),
+            [1.; 3],
+            1e-4
+        ));
+    }
+
+    #[test]
+    fn ictcp_rec2100pq() {
+        // ICtCp is defined on PQ-encoded LMS, so achromatic colors have an intensity equal to
+        // their PQ signal and no chroma.
+        for v in [0.1, 0.3, 0.580_688_9, 0.7] {
+            let ictcp = Rec2100Pq::convert::<ICtCp>([v; 3]);
+            assert!(almost_equal::<ICtCp>(ictcp, [v, 0., 0.], 1e-4));
+        }
+        let red = Rec2100Pq::convert::<ICtCp>([0.580_688_9, 0., 0.]);
+        assert!(red[1] < 0. && red[2] > 0.);
+    }
+
+    #[test]
+    fn jzazbz_srgb() {
+        // Reference values calculated in double precision from the definition, placing the
+        // white of sRGB at 203 cd/m² as Color.js does.
+        for (srgb, jzazbz) in [
+            ([1., 0., 0.], [0.134_385, 0.117_885, 0.111_878]),
+            ([0., 0., 1.], [0.095_774, -0.040_845, -0.185_854]),
+            ([0.2, 0.4, 0.6], [0.093_986, -0.025_507, -0.066_552]),
+        ] {
+            assert!(almost_equal::<Jzazbz>(
+                jzazbz,
+                Srgb::convert::<Jzazbz>(srgb),
+                1e-5
+            ));
+            assert!(almost_equal::<Srgb>(
+                srgb,
+                Jzazbz::convert::<Srgb>(jzazbz),
+                1e-4
+            ));
+        }
+    }
+
+    #[test]
+    fn hsv_shortcuts() {
+        assert_eq!(Srgb::convert::<Hsv>([1., 0., 0.]), [0., 100., 100.]);
+        assert_eq!(Srgb::convert::<Hsv>([0.5, 0.25, 0.25]), [0., 50., 50.]);
+        assert_eq!(Hsv::convert::<Srgb>([120., 100., 50.]), [0., 0.5, 0.]);
+
+        // The shortcuts between the hue-based sRGB spaces agree with converting through sRGB.
+        for rgb in [
+            [0.5, 0.2, 0.4],
+            [0.9, 0.8, 0.1],
+            [0.1, 0.3, 0.3],
+            [0.7, 0.7, 0.7],
+        ] {
+            let hsv = Srgb::convert::<Hsv>(rgb);
+            let hsl = Srgb::convert::<Hsl>(rgb);
+            let hwb = Srgb::convert::<Hwb>(rgb);
+            assert!(almost_equal::<Hsv>(Hsl::convert::<Hsv>(hsl), hsv, 1e-4));
+            assert!(almost_equal::<Hsv>(Hwb::convert::<Hsv>(hwb), hsv, 1e-4));
+            assert!(almost_equal::<Hsl>(Hsv::convert::<Hsl>(hsv), hsl, 1e-4));
+            assert!(almost_equal::<Hwb>(Hsv::convert::<Hwb>(hsv), hwb, 1e-4));
+        }
+    }
+
+    #[test]
+    fn hsluv_srgb() {
+        // Reference values from the HSLuv snapshot test.
+        let red = [1., 0., 0.];
+        assert!(almost_equal::<Luv>(
+            Srgb::convert::<Luv>(red),
+            [53.237_1, 175.009_8, 37.765_1],
+            1e-2,
+        ));
+        assert!(almost_equal::<Hsluv>(
+            Srgb::convert::<Hsluv>(red),
+            [12.177_05, 100., 53.237_1],
+            1e-2,
+        ));
+        assert!(almost_equal::<Hpluv>(
+            Srgb::convert::<Hpluv>(red),
+            [12.177_05, 426.747_2, 53.237_1],
+            1e-1,
+        ));
+
+        // The primary and secondary colors are on the boundary of the sRGB gamut.
+        for rgb in [
+            [0., 1., 0.],
+            [0., 0., 1.],
+            [1., 1., 0.],
+            [0., 1., 1.],
+            [1., 0., 1.],
+        ] {
+            let [_, s, _] = Srgb::convert::<Hsluv>(rgb);
+            assert!((s - 100.).abs() < 1e-2, "{rgb:?} has saturation {s}");
+        }
+    }
+
+    #[test]
+    fn okhsx_srgb() {
+        // Reference values from Color.js.
+        let red = [1., 0., 0.];
+        assert!(almost_equal::<Okhsl>(
+            Srgb::convert::<Okhsl>(red),
+            [29.233_9, 1., 0.568_08],
+            1e-4,
+        ));
+        assert!(almost_equal::<Okhsv>(
+            Srgb::convert::<Okhsv>(red),
+            [29.233_9, 1., 1.],
+            1e-4,
+        ));
+
+        // The primary and secondary colors are on the boundary of the sRGB gamut, and are the
+        // brightest colors of their hue.
+        for rgb in [
+            [0., 1., 0.],
+            [0., 0., 1.],
+            [1., 1., 0.],
+            [0., 1., 1.],
+            [1., 0., 1.],
+        ] {
+            let [_, s, _] = Srgb::convert::<Okhsl>(rgb);
+            assert!((s - 1.).abs() < 1e-3, "{rgb:?} has saturation {s}");
+            let [_, s, v] = Srgb::convert::<Okhsv>(rgb);
+            assert!((s - 1.).abs() < 1e-3, "{rgb:?} has saturation {s}");
+            assert!((v - 1.).abs() < 1e-3, "{rgb:?} has value {v}");
+        }
+
+        // The lightness of Okhsl estimates that of CIELAB.
+        let [_, _, l] = Srgb::convert::<Okhsl>([0.5, 0.5, 0.5]);
+        let [lstar, _, _] = Srgb::convert::<Lab>([0.5, 0.5, 0.5]);
+        assert!((l - lstar * 0.01).abs() < 1e-2);
+    }
+
     #[test]
     fn aces2065_1_srgb() {
         for (srgb, aces2065_1) in [
@@ -1941,6 +3530,34 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn gamut_membership() {
+        assert!(Srgb::is_in_gamut([0., 0.5, 1.], 0.));
+        assert!(!Srgb::is_in_gamut([0., 0.5, 1.01], 1e-3));
+        assert!(Srgb::is_in_gamut([0., 0.5, 1.0001], 1e-3));
+        assert!(!Srgb::is_in_gamut([f32::NAN, 0.5, 0.5], 1e-3));
+
+        // The gamut of the hue-based sRGB spaces is that of sRGB, which is not captured by
+        // clipping their components.
+        let out_of_gamut = Srgb::convert::<Hsl>([1.2, 0., 0.]);
+        assert_eq!(Hsl::clip(out_of_gamut)[1], out_of_gamut[1]);
+        assert!(!Hsl::is_in_gamut(out_of_gamut, 1e-3));
+        assert!(!Hwb::is_in_gamut(Srgb::convert::<Hwb>([1.2, 0., 0.]), 1e-3));
+        assert!(Hwb::is_in_gamut(Srgb::convert::<Hwb>([1., 0., 0.]), 1e-3));
+        assert!(!Hsv::is_in_gamut(Srgb::convert::<Hsv>([1.2, 0., 0.]), 1e-3));
+        assert!(Hsv::is_in_gamut(Srgb::convert::<Hsv>([1., 0., 0.]), 1e-3));
+        assert!(!Okhsv::is_in_gamut(
+            Srgb::convert::<Okhsv>([1.2, 0., 0.]),
+            1e-3
+        ));
+        assert!(Okhsl::is_in_gamut([200., 1., 0.7], 1e-3));
+
+        // Spaces without gamut limits.
+        assert!(Lab::is_in_gamut([120., 300., -300.], 0.));
+        assert!(Oklch::is_in_gamut([0.5, 2., 100.], 0.));
+        assert!(XyzD65::is_in_gamut([2., -1., 3.], 0.));
+    }
+
     /// Test whether `ColorSpace::convert` with implicit chromatic adaptation results in the same
     /// color as `ColorSpace::convert_absolute` in combination with explicit chromatic adaptation
     /// through `Colorspace::chromatically_adapt`.
@@ -1977,12 +3594,24 @@ mod tests {
         test::<Srgb, Lch>([0.5, 0.2, 0.4]);
         test::<Srgb, Hsl>([0.5, 0.2, 0.4]);
         test::<Srgb, Hwb>([0.5, 0.2, 0.4]);
+        test::<Srgb, Hsv>([0.5, 0.2, 0.4]);
         test::<Srgb, Oklab>([0.5, 0.2, 0.4]);
         test::<Srgb, Oklch>([0.5, 0.2, 0.4]);
+        test::<Srgb, Jzazbz>([0.5, 0.2, 0.4]);
+        test::<Srgb, JzCzhz>([0.5, 0.2, 0.4]);
+        test::<Srgb, Luv>([0.5, 0.2, 0.4]);
+        test::<Srgb, LchUv>([0.5, 0.2, 0.4]);
+        test::<Srgb, Hsluv>([0.5, 0.2, 0.4]);
+        test::<Srgb, Hpluv>([0.5, 0.2, 0.4]);
+        test::<Srgb, Okhsl>([0.5, 0.2, 0.4]);
+        test::<Srgb, Okhsv>([0.5, 0.2, 0.4]);
         test::<Srgb, DisplayP3>([0.5, 0.2, 0.4]);
         test::<Srgb, A98Rgb>([0.5, 0.2, 0.4]);
         test::<Srgb, ProphotoRgb>([0.5, 0.2, 0.4]);
         test::<Srgb, Rec2020>([0.5, 0.2, 0.4]);
+        test::<Srgb, Rec2100Pq>([0.5, 0.2, 0.4]);
+        test::<Srgb, Rec2100Hlg>([0.5, 0.2, 0.4]);
+        test::<Srgb, ICtCp>([0.5, 0.2, 0.4]);
         test::<Srgb, Aces2065_1>([0.5, 0.2, 0.4]);
         test::<Srgb, AcesCg>([0.5, 0.2, 0.4]);
         test::<Srgb, XyzD50>([0.5, 0.2, 0.4]);
@@ -0,0 +1,503 @@
+// Copyright 2025 the Color Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Contrast metrics for accessibility.
+//!
+//! This module provides two ways of measuring the contrast between a foreground (typically text)
+//! and a background color:
+//!
+//! - [`wcag_ratio`], the contrast ratio of [WCAG 2.1], ranging from 1 to 21. The success
+//!   criteria require at least 4.5 (or 3 for large text) for level AA and 7 (or 4.5) for level
+//!   AAA.
+//! - [`apca`], the lightness contrast `Lc` of the [Accessible Perceptual Contrast Algorithm]
+//!   (APCA), ranging from about -108 to 106. It is polarity-aware: dark text on a light background
+//!   gives a positive value, light text on a dark background a negative one. An absolute value of
+//!   75 is the suggested minimum for body text, 60 for other content text and 45 for large text.
+//!
+//! Both metrics are defined on sRGB. Colors are converted to [`Srgb`] and, as browsers render
+//! them, clipped to its gamut. A translucent foreground is composited over the background first;
+//! the background is always treated as opaque.
+//!
+//! On top of these metrics, [`pick_contrasting`] chooses a foreground color from a set of
+//! candidates, and [`adjust_for_contrast`] changes the lightness of a foreground color until it
+//! meets a [`ContrastTarget`].
+//!
+//! The same metrics are available on [`DynamicColor`] through
+//! [`DynamicColor::contrast_ratio`] and [`DynamicColor::apca_contrast`].
+//!
+//! [WCAG 2.1]: https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio
+//! [Accessible Perceptual Contrast Algorithm]: https://github.com/Myndex/apca-w3
+//! [`DynamicColor`]: crate::DynamicColor
+//! [`DynamicColor::contrast_ratio`]: crate::DynamicColor::contrast_ratio
+//! [`DynamicColor::apca_contrast`]: crate::DynamicColor::apca_contrast
+
+use crate::{AlphaColor, ColorSpace, Oklch, OpaqueColor, Srgb};
+
+#[cfg(all(not(feature = "std"), not(test)))]
+use crate::floatfuncs::FloatFuncs;
+
+/// Convert a color to sRGB, clipping it to the sRGB gamut, and split off its alpha.
+fn clipped_srgb<CS: ColorSpace>(color: AlphaColor<CS>) -> (OpaqueColor<Srgb>, f32) {
+    let (opaque, alpha) = color.convert::<Srgb>().split();
+    (
+        opaque.map(|r, g, b| [r.clamp(0., 1.), g.clamp(0., 1.), b.clamp(0., 1.)]),
+        alpha.clamp(0., 1.),
+    )
+}
+
+/// Composite `foreground` over the opaque `background` in sRGB, clipping both to the sRGB gamut.
+///
+/// This returns the composited foreground and the background.
+fn composite<F: ColorSpace, B: ColorSpace>(
+    foreground: AlphaColor<F>,
+    background: AlphaColor<B>,
+) -> (OpaqueColor<Srgb>, OpaqueColor<Srgb>) {
+    let (fg, alpha) = clipped_srgb(foreground);
+    let (bg, _) = clipped_srgb(background);
+    (bg + (fg - bg) * alpha, bg)
+}
+
+/// The [WCAG 2.1 contrast ratio][wcag] between a foreground and a background color.
+///
+/// The result ranges from 1 (no contrast) to 21 (black on white). The ratio is symmetric for
+/// opaque colors. A translucent foreground is composited over the background, whose alpha is
+/// ignored.
+///
+/// # Examples
+///
+/// ```rust
+/// use color::{AlphaColor, Srgb, contrast::wcag_ratio};
+///
+/// let black = AlphaColor::<Srgb>::BLACK;
+/// let white = AlphaColor::<Srgb>::WHITE;
+/// assert!((wcag_ratio(black, white) - 21.).abs() < 1e-4);
+///
+/// // Half-transparent black over white is a mid gray.
+/// let translucent = black.with_alpha(0.5);
+/// assert!(wcag_ratio(translucent, white) < 5.);
+/// ```
+///
+/// [wcag]: https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio
+#[must_use]
+pub fn wcag_ratio<F: ColorSpace, B: ColorSpace>(
+    foreground: AlphaColor<F>,
+    background: AlphaColor<B>,
+) -> f32 {
+    let (fg, bg) = composite(foreground, background);
+    let (fg, bg) = (fg.relative_luminance(), bg.relative_luminance());
+    let (lighter, darker) = if fg > bg { (fg, bg) } else { (bg, fg) };
+    (lighter + 0.05) / (darker + 0.05)
+}
+
+/// The screen luminance estimate `Ys` of APCA, with the soft clamp for near-black applied.
+fn apca_luminance(color: OpaqueColor<Srgb>) -> f32 {
+    const BLACK_THRESHOLD: f32 = 0.022;
+    const BLACK_CLAMP: f32 = 1.414;
+
+    let [r, g, b] = color.components;
+    let y = 0.212_672_9 * r.powf(2.4) + 0.715_152_2 * g.powf(2.4) + 0.072_175 * b.powf(2.4);
+    if y > BLACK_THRESHOLD {
+        y
+    } else {
+        y + (BLACK_THRESHOLD - y).powf(BLACK_CLAMP)
+    }
+}
+
+/// The [APCA] lightness contrast `Lc` of text on a background.
+///
+/// This implements version 0.0.98G-4g of the algorithm. Unlike [`wcag_ratio`], the result is not
+/// symmetric: dark text on a light background gives positive values up to about 106, light text
+/// on a dark background negative values down to about -108. Contrasts with an absolute value
+/// below 7.5 are clamped to 0.
+///
+/// A translucent text color is composited over the background, whose alpha is ignored.
+///
+/// # Examples
+///
+/// ```rust
+/// use color::{AlphaColor, Srgb, contrast::apca};
+///
+/// let gray = AlphaColor::<Srgb>::from_rgb8(0x88, 0x88, 0x88);
+/// let white = AlphaColor::<Srgb>::WHITE;
+/// assert!((apca(gray, white) - 63.06).abs() < 0.01);
+/// assert!((apca(white, gray) + 68.54).abs() < 0.01);
+/// ```
+///
+/// [APCA]: https://github.com/Myndex/apca-w3
+#[must_use]
+pub fn apca<T: ColorSpace, B: ColorSpace>(text: AlphaColor<T>, background: AlphaColor<B>) -> f32 {
+    const NORM_BG: f32 = 0.56;
+    const NORM_TEXT: f32 = 0.57;
+    const REV_TEXT: f32 = 0.62;
+    const REV_BG: f32 = 0.65;
+    const SCALE: f32 = 1.14;
+    const OFFSET: f32 = 0.027;
+    const DELTA_Y_MIN: f32 = 0.0005;
+    const LOW_CLIP: f32 = 0.1;
+
+    let (text, background) = composite(text, background);
+    let (y_text, y_bg) = (apca_luminance(text), apca_luminance(background));
+    if (y_bg - y_text).abs() < DELTA_Y_MIN {
+        return 0.;
+    }
+
+    let lc = if y_bg > y_text {
+        // Dark text on a light background.
+        let sapc = (y_bg.powf(NORM_BG) - y_text.powf(NORM_TEXT)) * SCALE;
+        if sapc < LOW_CLIP { 0. } else { sapc - OFFSET }
+    } else {
+        // Light text on a dark background.
+        let sapc = (y_bg.powf(REV_BG) - y_text.powf(REV_TEXT)) * SCALE;
+        if sapc > -LOW_CLIP { 0. } else { sapc + OFFSET }
+    };
+    lc * 100.
+}
+
+/// A minimum contrast to reach between a foreground and a background color.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ContrastTarget {
+    /// A minimum [WCAG 2.1 contrast ratio](wcag_ratio), such as 4.5 for level AA.
+    Wcag(f32),
+    /// A minimum absolute [APCA lightness contrast](apca), such as 75 for body text.
+    ///
+    /// As the target is compared to the absolute value of `Lc`, it is met by both dark text on a
+    /// light background and light text on a dark background.
+    Apca(f32),
+}
+
+impl ContrastTarget {
+    /// The contrast between a foreground and a background color by the metric of this target.
+    ///
+    /// For [`ContrastTarget::Apca`], this is the absolute value of `Lc`.
+    #[must_use]
+    pub fn contrast<F: ColorSpace, B: ColorSpace>(
+        self,
+        foreground: AlphaColor<F>,
+        background: AlphaColor<B>,
+    ) -> f32 {
+        match self {
+            Self::Wcag(_) => wcag_ratio(foreground, background),
+            Self::Apca(_) => apca(foreground, background).abs(),
+        }
+    }
+
+    /// Whether the contrast between a foreground and a background color meets this target.
+    #[must_use]
+    pub fn is_met_by<F: ColorSpace, B: ColorSpace>(
+        self,
+        foreground: AlphaColor<F>,
+        background: AlphaColor<B>,
+    ) -> bool {
+        let target = match self {
+            Self::Wcag(target) | Self::Apca(target) => target,
+        };
+        self.contrast(foreground, background) >= target
+    }
+}
+
+/// Pick a foreground color with enough contrast against a background.
+///
+/// The candidates are in order of preference: the first one meeting the `target` is returned.
+/// If none of them do, the one with the highest contrast is returned instead; use
+/// [`ContrastTarget::is_met_by`] to tell these cases apart. `None` is only returned if there are
+/// no candidates.
+///
+/// This generalizes the `contrast-color()` function of [CSS Color 5].
+///
+/// # Examples
+///
+/// ```rust
+/// use color::{AlphaColor, Srgb, contrast::{ContrastTarget, pick_contrasting}};
+///
+/// let background = AlphaColor::<Srgb>::from_rgb8(0x20, 0x40, 0x80);
+/// let candidates = [
+///     AlphaColor::<Srgb>::from_rgb8(0x30, 0x30, 0x30),
+///     AlphaColor::<Srgb>::from_rgb8(0xf0, 0xf0, 0xf0),
+///     AlphaColor::<Srgb>::WHITE,
+/// ];
+/// let picked = pick_contrasting(background, candidates, ContrastTarget::Wcag(4.5));
+/// assert_eq!(picked, Some(candidates[1]));
+/// ```
+///
+/// [CSS Color 5]: https://www.w3.org/TR/css-color-5/#contrast-color
+pub fn pick_contrasting<B: ColorSpace, C: ColorSpace>(
+    background: AlphaColor<B>,
+    candidates: impl IntoIterator<Item = AlphaColor<C>>,
+    target: ContrastTarget,
+) -> Option<AlphaColor<C>> {
+    let mut best: Option<(AlphaColor<C>, f32)> = None;
+    for candidate in candidates {
+        if target.is_met_by(candidate, background) {
+            return Some(candidate);
+        }
+        let contrast = target.contrast(candidate, background);
+        if best.is_none_or(|(_, best_contrast)| contrast > best_contrast) {
+            best = Some((candidate, contrast));
+        }
+    }
+    best.map(|(candidate, _)| candidate)
+}
+
+/// Adjust the lightness of a foreground color until it has enough contrast against a background.
+///
+/// The lightness is changed in [`Oklch`], preserving the hue. The chroma is preserved where
+/// possible; the adjusted color is [mapped into the sRGB gamut](AlphaColor::map_to_gamut),
+/// reducing chroma as needed. Alpha is preserved.
+///
+/// Both making the color lighter and darker are considered, and the smallest change in
+/// lightness that meets the `target` is used. If the foreground already meets the target, it is
+/// returned unchanged. If the target cannot be met, the lightness extreme with the highest
+/// contrast is returned; use [`ContrastTarget::is_met_by`] to check for this.
+///
+/// # Examples
+///
+/// ```rust
+/// use color::{AlphaColor, Srgb, contrast::{ContrastTarget, adjust_for_contrast, wcag_ratio}};
+///
+/// let foreground = AlphaColor::<Srgb>::from_rgb8(0xff, 0x80, 0x80);
+/// let background = AlphaColor::<Srgb>::WHITE;
+/// let target = ContrastTarget::Wcag(4.5);
+/// assert!(!target.is_met_by(foreground, background));
+///
+/// let adjusted = adjust_for_contrast(foreground, background, target);
+/// assert!(target.is_met_by(adjusted, background));
+/// // The adjustment is minimal.
+/// assert!(wcag_ratio(adjusted, background) < 4.6);
+/// ```
+#[must_use]
+pub fn adjust_for_contrast<F: ColorSpace, B: ColorSpace>(
+    foreground: AlphaColor<F>,
+    background: AlphaColor<B>,
+    target: ContrastTarget,
+) -> AlphaColor<F> {
+    if target.is_met_by(foreground, background) {
+        return foreground;
+    }
+
+    let oklch = foreground.convert::<Oklch>();
+    let with_lightness = |l: f32| {
+        oklch
+            .map_lightness(|_| l)
+            .map_to_gamut::<Srgb>()
+            .convert::<F>()
+    };
+    let start = oklch.components[0].clamp(0., 1.);
+
+    // For each direction, find the lightness closest to the start meeting the target.
+    let mut best: Option<(f32, f32)> = None;
+    let mut fallback = (foreground, target.contrast(foreground, background));
+    for end in [0., 1.] {
+        let extreme = with_lightness(end);
+        if !target.is_met_by(extreme, background) {
+            let contrast = target.contrast(extreme, background);
+            if contrast > fallback.1 {
+                fallback = (extreme, contrast);
+            }
+            continue;
+        }
+        // Contrast increases monotonically from `start` towards `end`.
+        let (mut unmet, mut met) = (start, end);
+        for _ in 0..24 {
+            let mid = 0.5 * (unmet + met);
+            if target.is_met_by(with_lightness(mid), background) {
+                met = mid;
+            } else {
+                unmet = mid;
+            }
+        }
+        let distance = (met - start).abs();
+        if best.is_none_or(|(_, best_distance)| distance < best_distance) {
+            best = Some((met, distance));
+        }
+    }
+
+    match best {
+        Some((l, _)) => with_lightness(l),
+        None => fallback.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ContrastTarget, adjust_for_contrast, apca, pick_contrasting, wcag_ratio};
+    use crate::{AlphaColor, DynamicColor, Oklch, Srgb, parse_color};
+
+    fn rgb8(r: u8, g: u8, b: u8) -> AlphaColor<Srgb> {
+        AlphaColor::from_rgb8(r, g, b)
+    }
+
+    #[test]
+    fn wcag() {
+        let black = AlphaColor::<Srgb>::BLACK;
+        let white = AlphaColor::<Srgb>::WHITE;
+        for (fg, bg, expected) in [
+            (black, white, 21.),
+            (white, black, 21.),
+            (white, white, 1.),
+            (rgb8(0x76, 0x76, 0x76), white, 4.54),
+            (rgb8(0x59, 0x59, 0x59), white, 7.),
+            (rgb8(0xff, 0, 0), white, 4.),
+            (rgb8(0, 0, 0xff), rgb8(0xff, 0xff, 0), 8.),
+        ] {
+            let actual = wcag_ratio(fg, bg);
+            assert!(
+                (actual - expected).abs() < 0.01,
+                "{fg:?} on {bg:?}: expected {expected}, got {actual}"
+            );
+        }
+
+        // Out of gamut colors are clipped, other color spaces are converted.
+        let super_white = AlphaColor::<Srgb>::new([1.2, 1.1, 1.5, 1.]);
+        assert!(
+            (wcag_ratio(super_white, black) - 21.).abs() < 1e-4,
+            "out of gamut colors are clipped"
+        );
+        let oklch_white = AlphaColor::<Oklch>::new([1., 0., 0., 1.]);
+        assert!(
+            (wcag_ratio(black, oklch_white) - 21.).abs() < 1e-3,
+            "colors are converted to sRGB"
+        );
+    }
+
+    #[test]
+    fn wcag_translucent() {
+        let white = AlphaColor::<Srgb>::WHITE;
+        let fg = AlphaColor::<Srgb>::BLACK.with_alpha(0.5);
+        let composited = AlphaColor::<Srgb>::new([0.5, 0.5, 0.5, 1.]);
+        assert!(
+            (wcag_ratio(fg, white) - wcag_ratio(composited, white)).abs() < 1e-4,
+            "translucent foreground is composited over the background"
+        );
+        assert!(
+            (wcag_ratio(AlphaColor::<Srgb>::BLACK, white.with_alpha(0.)) - 21.).abs() < 1e-4,
+            "background alpha is ignored"
+        );
+    }
+
+    #[test]
+    fn apca_reference_values() {
+        let black = AlphaColor::<Srgb>::BLACK;
+        let white = AlphaColor::<Srgb>::WHITE;
+        for (text, bg, expected) in [
+            (rgb8(0x88, 0x88, 0x88), white, 63.06),
+            (white, rgb8(0x88, 0x88, 0x88), -68.54),
+            (black, rgb8(0xaa, 0xaa, 0xaa), 58.15),
+            (rgb8(0xaa, 0xaa, 0xaa), black, -56.24),
+            (black, white, 106.04),
+            (white, black, -107.88),
+            (rgb8(0x11, 0x22, 0x33), rgb8(0x11, 0x22, 0x33), 0.),
+        ] {
+            let actual = apca(text, bg);
+            assert!(
+                (actual - expected).abs() < 0.01,
+                "{text:?} on {bg:?}: expected {expected}, got {actual}"
+            );
+        }
+    }
+
+    #[test]
+    fn dynamic() {
+        let fg = parse_color("#888").unwrap();
+        let bg = parse_color("white").unwrap();
+        assert!(
+            (fg.apca_contrast(bg) - 63.06).abs() < 0.01,
+            "APCA of DynamicColor"
+        );
+        let fg = DynamicColor::from_alpha_color(AlphaColor::<Oklch>::new([0., 0., 0., 1.]));
+        assert!(
+            (fg.contrast_ratio(bg) - 21.).abs() < 1e-3,
+            "WCAG ratio of DynamicColor"
+        );
+    }
+
+    #[test]
+    fn pick() {
+        let white = AlphaColor::<Srgb>::WHITE;
+        let black = AlphaColor::<Srgb>::BLACK;
+        let gray = rgb8(0x76, 0x76, 0x76);
+        assert_eq!(
+            pick_contrasting(gray, [white, black], ContrastTarget::Wcag(4.5)),
+            Some(white),
+            "the first candidate meeting the target is picked"
+        );
+        assert_eq!(
+            pick_contrasting(gray, [black, white], ContrastTarget::Wcag(4.5)),
+            Some(black),
+            "the first candidate meeting the target is picked"
+        );
+        assert_eq!(
+            pick_contrasting(gray, [white, black], ContrastTarget::Wcag(7.)),
+            Some(black),
+            "the best candidate is picked if none meet the target"
+        );
+        assert_eq!(
+            pick_contrasting(white, [black, gray], ContrastTarget::Apca(75.)),
+            Some(black),
+            "APCA targets compare absolute values"
+        );
+        assert_eq!(
+            pick_contrasting(
+                white,
+                [] as [AlphaColor<Srgb>; 0],
+                ContrastTarget::Wcag(4.5)
+            ),
+            None,
+            "no candidates"
+        );
+    }
+
+    #[test]
+    fn adjust() {
+        let white = AlphaColor::<Srgb>::WHITE;
+        let black = AlphaColor::<Srgb>::BLACK;
+        for (fg, bg, target) in [
+            (rgb8(0xff, 0x80, 0x80), white, ContrastTarget::Wcag(4.5)),
+            (rgb8(0x20, 0x40, 0xa0), black, ContrastTarget::Wcag(7.)),
+            (
+                rgb8(0x80, 0x80, 0x80),
+                rgb8(0x70, 0x70, 0x70),
+                ContrastTarget::Wcag(4.5),
+            ),
+            (rgb8(0x40, 0xa0, 0x40), white, ContrastTarget::Apca(75.)),
+            (rgb8(0x40, 0xa0, 0x40), black, ContrastTarget::Apca(75.)),
+        ] {
+            let adjusted = adjust_for_contrast(fg, bg, target);
+            assert!(
+                target.is_met_by(adjusted, bg),
+                "{fg:?} on {bg:?} adjusted to {adjusted:?} does not meet {target:?}"
+            );
+            let [l0, _, h0] = fg.convert::<Oklch>().discard_alpha().components;
+            let [l1, c1, h1] = adjusted.convert::<Oklch>().discard_alpha().components;
+            assert!(
+                c1 < 1e-3 || (h1 - h0).abs() < 1.,
+                "hue is preserved: {h0} became {h1}"
+            );
+            // The adjustment is minimal: a slightly smaller change doesn't meet the target.
+            let closer = fg
+                .convert::<Oklch>()
+                .map_lightness(|_| l1 + (l0 - l1) * 0.02)
+                .map_to_gamut::<Srgb>();
+            assert!(
+                !target.is_met_by(closer, bg),
+                "{fg:?} on {bg:?} adjusted more than needed to {adjusted:?}"
+            );
+        }
+
+        // Already meeting the target.
+        let fg = rgb8(0x20, 0x20, 0x20).with_alpha(0.8);
+        assert_eq!(
+            adjust_for_contrast(fg, white, ContrastTarget::Wcag(4.5)),
+            fg,
+            "colors meeting the target are unchanged"
+        );
+
+        // Unreachable targets give the best extreme, which is black here (4.6 versus 4.5 for
+        // white).
+        let mid = rgb8(0x76, 0x76, 0x76);
+        let adjusted = adjust_for_contrast(mid, mid, ContrastTarget::Wcag(7.));
+        assert!(
+            (wcag_ratio(adjusted, mid) - wcag_ratio(black, mid)).abs() < 0.01,
+            "unreachable target gives the lightness extreme with most contrast, got {adjusted:?}"
+        );
+    }
+}
@@ -0,0 +1,487 @@
+// Copyright 2025 the Color Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Color difference formulas in CIE Lab.
+//!
+//! These are the color difference metrics, commonly called ΔE ("delta E"), defined by the CIE and
+//! the Colour Measurement Committee of the Society of Dyers and Colourists. They operate on
+//! [`Lab`] colors, which use a D50 white point in this crate.
+//!
+//! From least to most perceptually uniform, they are:
+//!
+//! - [`cie76`], the Euclidean distance in Lab;
+//! - [`cie94`], which weights the chroma and hue differences by chroma;
+//! - [`cmc`], which is popular in the textile industry; and
+//! - [`ciede2000`], which is the current CIE recommendation.
+//!
+//! [`cie94`] and [`cmc`] are not symmetric: the first color is the reference and the second is
+//! the sample being compared to it.
+//!
+//! The static color types have a `delta_e` method taking a [`Formula`], which converts to Lab
+//! before computing the difference. For a difference in Oklab ("deltaEOK"), convert to [`Oklab`]
+//! and use [`OpaqueColor::difference`].
+//!
+//! For high dynamic range colors, [`ez`] computes `ΔEz` in [`Jzazbz`]. The color appearance
+//! model CAM16 comes with a uniform color space, in which [`cam16_ucs`] computes differences.
+//!
+//! [`Oklab`]: crate::Oklab
+
+use crate::cam16::Cam16Ucs;
+use crate::{Jzazbz, Lab, OpaqueColor};
+
+#[cfg(all(not(feature = "std"), not(test)))]
+use crate::floatfuncs::FloatFuncs;
+
+/// A color difference formula.
+///
+/// See the [module level docs](self) for more information.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum Formula {
+    /// CIE76, see [`cie76`].
+    Cie76,
+    /// CIE94 with the given application weighting, see [`cie94`].
+    Cie94(Cie94Weighting),
+    /// CMC l:c, see [`cmc`].
+    Cmc {
+        /// The lightness weight `l`, commonly 2 for acceptability and 1 for perceptibility.
+        lightness: f32,
+        /// The chroma weight `c`, commonly 1.
+        chroma: f32,
+    },
+    /// CIEDE2000, see [`ciede2000`].
+    Ciede2000,
+}
+
+impl Formula {
+    /// Compute the difference between a reference and a sample color.
+    #[must_use]
+    pub fn compute(self, reference: OpaqueColor<Lab>, sample: OpaqueColor<Lab>) -> f32 {
+        match self {
+            Self::Cie76 => cie76(reference, sample),
+            Self::Cie94(weighting) => cie94(reference, sample, weighting),
+            Self::Cmc { lightness, chroma } => cmc(reference, sample, lightness, chroma),
+            Self::Ciede2000 => ciede2000(reference, sample),
+        }
+    }
+}
+
+/// The application-dependent weighting of CIE94.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Cie94Weighting {
+    /// Weighting for graphic arts, `kL = 1`, `K1 = 0.045` and `K2 = 0.015`.
+    #[default]
+    GraphicArts,
+    /// Weighting for textiles, `kL = 2`, `K1 = 0.048` and `K2 = 0.014`.
+    Textiles,
+}
+
+/// The chroma and hue difference between two Lab colors.
+///
+/// The hue difference is returned squared, as `ΔH² = Δa² + Δb² - ΔC²`, avoiding the need to
+/// compute hue angles.
+fn chroma_and_hue_difference(reference: [f32; 3], sample: [f32; 3]) -> (f32, f32, f32) {
+    let [_, a1, b1] = reference;
+    let [_, a2, b2] = sample;
+    let c1 = a1.hypot(b1);
+    let c2 = a2.hypot(b2);
+    let dc = c1 - c2;
+    let (da, db) = (a1 - a2, b1 - b2);
+    // Rounding can make this slightly negative.
+    let dh2 = (da * da + db * db - dc * dc).max(0.);
+    (c1, dc, dh2)
+}
+
+/// The CIE76 color difference.
+///
+/// This is the Euclidean distance between the colors in Lab. It is simple, but not very
+/// perceptually uniform, overestimating differences in saturated colors.
+///
+/// # Examples
+///
+/// ```rust
+/// use color::{Lab, OpaqueColor, delta_e};
+///
+/// let a = OpaqueColor::<Lab>::new([50., 0., 0.]);
+/// let b = OpaqueColor::<Lab>::new([53., 4., 0.]);
+/// assert_eq!(delta_e::cie76(a, b), 5.);
+/// ```
+#[must_use]
+pub fn cie76(a: OpaqueColor<Lab>, b: OpaqueColor<Lab>) -> f32 {
+    a.difference(b)
+}
+
+/// The CIE94 color difference.
+///
+/// This scales the chroma and hue differences by the chroma of the `reference` color. Note that
+/// the formula is not symmetric.
+#[must_use]
+pub fn cie94(
+    reference: OpaqueColor<Lab>,
+    sample: OpaqueColor<Lab>,
+    weighting: Cie94Weighting,
+) -> f32 {
+    let (k_l, k_1, k_2) = match weighting {
+        Cie94Weighting::GraphicArts => (1., 0.045, 0.015),
+        Cie94Weighting::Textiles => (2., 0.048, 0.014),
+    };
+    let (c1, dc, dh2) = chroma_and_hue_difference(reference.components, sample.components);
+    let dl = (reference.components[0] - sample.components[0]) / k_l;
+    let dc = dc / (1. + k_1 * c1);
+    let s_h = 1. + k_2 * c1;
+    (dl * dl + dc * dc + dh2 / (s_h * s_h)).sqrt()
+}
+
+/// The CMC l:c color difference.
+///
+/// The lightness weight `l` and chroma weight `c` are commonly chosen as 2:1 for judging
+/// acceptability and 1:1 for judging perceptibility. The weights are relative to the `reference`
+/// color, and the formula is not symmetric.
+#[must_use]
+pub fn cmc(reference: OpaqueColor<Lab>, sample: OpaqueColor<Lab>, l: f32, c: f32) -> f32 {
+    let [l1, a1, b1] = reference.components;
+    let (c1, dc, dh2) = chroma_and_hue_difference(reference.components, sample.components);
+    let mut h1 = b1.atan2(a1).to_degrees();
+    if h1 < 0. {
+        h1 += 360.;
+    }
+
+    let s_l = if l1 < 16. {
+        0.511
+    } else {
+        0.040975 * l1 / (1. + 0.01765 * l1)
+    };
+    let s_c = 0.0638 * c1 / (1. + 0.0131 * c1) + 0.638;
+    let c1_4 = (c1 * c1) * (c1 * c1);
+    let f = (c1_4 / (c1_4 + 1900.)).sqrt();
+    let t = if (164. ..=345.).contains(&h1) {
+        0.56 + (0.2 * (h1 + 168.).to_radians().sin_cos().1).abs()
+    } else {
+        0.36 + (0.4 * (h1 + 35.).to_radians().sin_cos().1).abs()
+    };
+    let s_h = s_c * (f * t + 1. - f);
+
+    let dl = (l1 - sample.components[0]) / (l * s_l);
+    let dc = dc / (c * s_c);
+    (dl * dl + dc * dc + dh2 / (s_h * s_h)).sqrt()
+}
+
+/// The CIEDE2000 color difference.
+///
+/// This is the CIE's current recommendation, correcting the remaining non-uniformity of CIE94
+/// in the blue region and for neutral colors. It is symmetric.
+///
+/// This follows the implementation notes of [Sharma, Wu and Dalal][sharma]. The parametric
+/// factors `kL`, `kC` and `kH` are all 1.
+///
+/// # Examples
+///
+/// ```rust
+/// use color::{Lab, OpaqueColor, delta_e};
+///
+/// let a = OpaqueColor::<Lab>::new([50., 2.6772, -79.7751]);
+/// let b = OpaqueColor::<Lab>::new([50., 0., -82.7485]);
+/// assert!((delta_e::ciede2000(a, b) - 2.0425).abs() < 1e-4);
+/// ```
+///
+/// [sharma]: https://hajim.rochester.edu/ece/sites/gsharma/ciede2000/
+#[must_use]
+pub fn ciede2000(a: OpaqueColor<Lab>, b: OpaqueColor<Lab>) -> f32 {
+    const POW25_7: f32 = 6_103_515_625.;
+
+    let [l1, a1, b1] = a.components;
+    let [l2, a2, b2] = b.components;
+
+    let c_mean = 0.5 * (a1.hypot(b1) + a2.hypot(b2));
+    let c_mean_7 = c_mean.powf(7.);
+    let g = 0.5 * (1. - (c_mean_7 / (c_mean_7 + POW25_7)).sqrt());
+    let a1 = (1. + g) * a1;
+    let a2 = (1. + g) * a2;
+    let c1 = a1.hypot(b1);
+    let c2 = a2.hypot(b2);
+    let hue = |a: f32, b: f32| {
+        if a == 0. && b == 0. {
+            0.
+        } else {
+            let h = b.atan2(a).to_degrees();
+            if h < 0. { h + 360. } else { h }
+        }
+    };
+    let h1 = hue(a1, b1);
+    let h2 = hue(a2, b2);
+
+    let dl = l2 - l1;
+    let dc = c2 - c1;
+    let chroma_product = c1 * c2;
+    let mut dh = h2 - h1;
+    if chroma_product == 0. {
+        dh = 0.;
+    } else if dh > 180. {
+        dh -= 360.;
+    } else if dh < -180. {
+        dh += 360.;
+    }
+    let dh = 2. * chroma_product.sqrt() * (0.5 * dh).to_radians().sin_cos().0;
+
+    let l_mean = 0.5 * (l1 + l2);
+    let c_mean = 0.5 * (c1 + c2);
+    let h_mean = if chroma_product == 0. {
+        h1 + h2
+    } else if (h1 - h2).abs() <= 180. {
+        0.5 * (h1 + h2)
+    } else if h1 + h2 < 360. {
+        0.5 * (h1 + h2 + 360.)
+    } else {
+        0.5 * (h1 + h2 - 360.)
+    };
+
+    let cos = |deg: f32| deg.to_radians().sin_cos().1;
+    let t = 1. - 0.17 * cos(h_mean - 30.) + 0.24 * cos(2. * h_mean) + 0.32 * cos(3. * h_mean + 6.)
+        - 0.20 * cos(4. * h_mean - 63.);
+    let d_theta = 30. * (-((h_mean - 275.) / 25.) * ((h_mean - 275.) / 25.)).exp();
+    let c_mean_7 = c_mean.powf(7.);
+    let r_c = 2. * (c_mean_7 / (c_mean_7 + POW25_7)).sqrt();
+    let l_offset = (l_mean - 50.) * (l_mean - 50.);
+    let s_l = 1. + 0.015 * l_offset / (20. + l_offset).sqrt();
+    let s_c = 1. + 0.045 * c_mean;
+    let s_h = 1. + 0.015 * c_mean * t;
+    let r_t = -(2. * d_theta).to_radians().sin_cos().0 * r_c;
+
+    let dl = dl / s_l;
+    let dc = dc / s_c;
+    let dh = dh / s_h;
+    (dl * dl + dc * dc + dh * dh + r_t * dc * dh).sqrt()
+}
+
+/// The `ΔEz` color difference in Jzazbz.
+///
+/// This combines the differences in lightness, chroma and hue of the cylindrical [`JzCzhz`]
+/// representation of the colors, as proposed alongside Jzazbz by [Safdar et al.][paper]. It is
+/// symmetric, and equal to the Euclidean distance in Jzazbz.
+///
+/// # Examples
+///
+/// ```rust
+/// use color::{Jzazbz, OpaqueColor, Srgb, delta_e};
+///
+/// let a = OpaqueColor::<Srgb>::new([0.2, 0.4, 0.6]).convert::<Jzazbz>();
+/// let b = OpaqueColor::<Srgb>::new([0.2, 0.4, 0.7]).convert::<Jzazbz>();
+/// assert!(delta_e::ez(a, b) > 0.005);
+/// ```
+///
+/// [`JzCzhz`]: crate::JzCzhz
+/// [paper]: https://doi.org/10.1364/OE.25.015131
+#[must_use]
+pub fn ez(a: OpaqueColor<Jzazbz>, b: OpaqueColor<Jzazbz>) -> f32 {
+    let [j1, a1, b1] = a.components;
+    let [j2, a2, b2] = b.components;
+    let c1 = a1.hypot(b1);
+    let c2 = a2.hypot(b2);
+    let dh = b2.atan2(a2) - b1.atan2(a1);
+    let dj = j2 - j1;
+    let dc = c2 - c1;
+    let dh = 2. * (c1 * c2).sqrt() * (0.5 * dh).sin_cos().0;
+    (dj * dj + dc * dc + dh * dh).sqrt()
+}
+
+/// The color difference `ΔE'` in CAM16-UCS.
+///
+/// This is the Euclidean distance in [`Cam16Ucs`], which [Li et al.][paper] derived to predict
+/// color differences of all magnitudes. It is symmetric.
+///
+/// # Examples
+///
+/// ```rust
+/// use color::cam16::Cam16Ucs;
+/// use color::{OpaqueColor, Srgb, delta_e};
+///
+/// let a = OpaqueColor::<Srgb>::new([0.2, 0.4, 0.6]).convert::<Cam16Ucs>();
+/// let b = OpaqueColor::<Srgb>::new([0.2, 0.4, 0.7]).convert::<Cam16Ucs>();
+/// assert!(delta_e::cam16_ucs(a, b) > 1.);
+/// ```
+///
+/// [paper]: https://doi.org/10.1002/col.22131
+#[must_use]
+pub fn cam16_ucs(a: OpaqueColor<Cam16Ucs>, b: OpaqueColor<Cam16Ucs>) -> f32 {
+    a.difference(b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Cie94Weighting, Formula, cie76, cie94, ciede2000, cmc, ez};
+    use crate::{AlphaColor, Jzazbz, Lab, OpaqueColor, Srgb};
+
+    /// Test data from Table 1 of Sharma, Wu and Dalal, "The CIEDE2000 Color-Difference Formula:
+    /// Implementation Notes, Supplementary Test Data, and Mathematical Observations".
+    const SHARMA: [([f32; 3], [f32; 3], f32); 34] = [
+        ([50., 2.6772, -79.7751], [50., 0., -82.7485], 2.0425),
+        ([50., 3.1571, -77.2803], [50., 0., -82.7485], 2.8615),
+        ([50., 2.8361, -74.0200], [50., 0., -82.7485], 3.4412),
+        ([50., -1.3802, -84.2814], [50., 0., -82.7485], 1.),
+        ([50., -1.1848, -84.8006], [50., 0., -82.7485], 1.),
+        ([50., -0.9009, -85.5211], [50., 0., -82.7485], 1.),
+        ([50., 0., 0.], [50., -1., 2.], 2.3669),
+        ([50., -1., 2.], [50., 0., 0.], 2.3669),
+        ([50., 2.49, -0.001], [50., -2.49, 0.0009], 7.1792),
+        ([50., 2.49, -0.001], [50., -2.49, 0.001], 7.1792),
+        ([50., 2.49, -0.001], [50., -2.49, 0.0011], 7.2195),
+        ([50., 2.49, -0.001], [50., -2.49, 0.0012], 7.2195),
+        ([50., -0.001, 2.49], [50., 0.0009, -2.49], 4.8045),
+        ([50., -0.001, 2.49], [50., 0.001, -2.49], 4.8045),
+        ([50., -0.001, 2.49], [50., 0.0011, -2.49], 4.7461),
+        ([50., 2.5, 0.], [50., 0., -2.5], 4.3065),
+        ([50., 2.5, 0.], [73., 25., -18.], 27.1492),
+        ([50., 2.5, 0.], [61., -5., 29.], 22.8977),
+        ([50., 2.5, 0.], [56., -27., -3.], 31.9030),
+        ([50., 2.5, 0.], [58., 24., 15.], 19.4535),
+        ([50., 2.5, 0.], [50., 3.1736, 0.5854], 1.),
+        ([50., 2.5, 0.], [50., 3.2972, 0.], 1.),
+        ([50., 2.5, 0.], [50., 1.8634, 0.5757], 1.),
+        ([50., 2.5, 0.], [50., 3.2592, 0.335], 1.),
+        (
+            [60.2574, -34.0099, 36.2677],
+            [60.4626, -34.1751, 39.4387],
+            1.2644,
+        ),
+        (
+            [63.0109, -31.0961, -5.8663],
+            [62.8187, -29.7946, -4.0864],
+            1.2630,
+        ),
+        (
+            [61.2901, 3.7196, -5.3901],
+            [61.4292, 2.2480, -4.9620],
+            1.8731,
+        ),
+        (
+            [35.0831, -44.1164, 3.7933],
+            [35.0232, -40.0716, 1.5901],
+            1.8645,
+        ),
+        (
+            [22.7233, 20.0904, -46.6940],
+            [23.0331, 14.9730, -42.5619],
+            2.0373,
+        ),
+        (
+            [36.4612, 47.8580, 18.3852],
+            [36.2715, 50.5065, 21.2231],
+            1.4146,
+        ),
+        (
+            [90.8027, -2.0831, 1.4410],
+            [91.1528, -1.6435, 0.0447],
+            1.4441,
+        ),
+        (
+            [90.9257, -0.5406, -0.9208],
+            [88.6381, -0.8985, -0.7239],
+            1.5381,
+        ),
+        (
+            [6.7747, -0.2908, -2.4247],
+            [5.8714, -0.0985, -2.2286],
+            0.6377,
+        ),
+        (
+            [2.0776, 0.0795, -1.1350],
+            [0.9033, -0.0636, -0.5514],
+            0.9082,
+        ),
+    ];
+
+    #[test]
+    fn ciede2000_sharma() {
+        for (i, (a, b, expected)) in SHARMA.into_iter().enumerate() {
+            let a = OpaqueColor::<Lab>::new(a);
+            let b = OpaqueColor::<Lab>::new(b);
+            for actual in [ciede2000(a, b), ciede2000(b, a)] {
+                assert!(
+                    (actual - expected).abs() < 1e-4,
+                    "pair {}: expected {expected}, got {actual}",
+                    i + 1
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn cie94_and_cmc() {
+        // Computed with an independent double precision implementation.
+        for (pair, expected) in [
+            (0, [4.0011, 1.3950, 1.4230, 1.7387, 1.7387]),
+            (6, [2.2361, 2.2361, 2.2361, 3.5048, 3.5048]),
+            (16, [36.8680, 34.6892, 28.2503, 37.9233, 42.1088]),
+            (18, [30.2531, 27.9141, 27.3286, 38.0618, 38.3601]),
+            (24, [3.1819, 1.3910, 1.3897, 1.4205, 1.4282]),
+            (32, [0.9441, 0.9385, 0.5182, 0.9528, 1.8032]),
+        ] {
+            let (a, b, _) = SHARMA[pair];
+            let a = OpaqueColor::<Lab>::new(a);
+            let b = OpaqueColor::<Lab>::new(b);
+            let actual = [
+                cie76(a, b),
+                cie94(a, b, Cie94Weighting::GraphicArts),
+                cie94(a, b, Cie94Weighting::Textiles),
+                cmc(a, b, 2., 1.),
+                cmc(a, b, 1., 1.),
+            ];
+            for (actual, expected) in actual.into_iter().zip(expected) {
+                assert!(
+                    (actual - expected).abs() < 1e-3,
+                    "pair {}: expected {expected}, got {actual}",
+                    pair + 1
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn typed_entry_points() {
+        let a = AlphaColor::<Srgb>::new([0.8, 0.2, 0.1, 1.]);
+        let b = AlphaColor::<Srgb>::new([0.7, 0.25, 0.1, 0.5]);
+        let expected = ciede2000(
+            a.convert::<Lab>().discard_alpha(),
+            b.convert::<Lab>().discard_alpha(),
+        );
+        let actual = a.delta_e(b, Formula::Ciede2000);
+        assert!(
+            (actual - expected).abs() < 1e-5,
+            "alpha is ignored: expected {expected}, got {actual}"
+        );
+        assert_eq!(
+            a.discard_alpha().delta_e(a.discard_alpha(), Formula::Cie76),
+            0.,
+            "a color does not differ from itself"
+        );
+        let cmc = Formula::Cmc {
+            lightness: 2.,
+            chroma: 1.,
+        };
+        assert_eq!(
+            cmc.compute(
+                a.convert::<Lab>().discard_alpha(),
+                b.convert::<Lab>().discard_alpha()
+            ),
+            a.discard_alpha().delta_e(b.discard_alpha(), cmc),
+            "the reference is the receiver"
+        );
+    }
+
+    #[test]
+    fn ez_jzazbz() {
+        for (a, b) in [
+            ([0.1, 0.02, 0.], [0.1, 0., 0.02]),
+            ([0.15, -0.01, 0.05], [0.2, 0.03, -0.04]),
+            ([0.222, 0., 0.], [0.3, 0.1, 0.1]),
+        ] {
+            let a = OpaqueColor::<Jzazbz>::new(a);
+            let b = OpaqueColor::<Jzazbz>::new(b);
+            assert!(
+                (ez(a, b) - a.difference(b)).abs() < 1e-6,
+                "ΔEz is the Euclidean distance in Jzazbz"
+            );
+            assert!((ez(a, b) - ez(b, a)).abs() < 1e-6, "ΔEz is symmetric");
+        }
+    }
+}
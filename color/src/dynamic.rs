@@ -4,10 +4,11 @@
 //! CSS colors and syntax.
 
 use crate::{
-    AlphaColor, Chromaticity, ColorSpace, ColorSpaceLayout, ColorSpaceTag, Flags, HueDirection,
-    LinearSrgb, Missing,
+    AdaptationMethod, AlphaColor, Chromaticity, ColorSpace, ColorSpaceLayout, ColorSpaceTag, Flags,
+    HueDirection, LinearSrgb, Missing, RgbSpaceDef, Srgb,
     cache_key::{BitEq, BitHash},
     color::{InterpolationAlphaSpace, add_alpha, fixup_hues_for_interpolate, split_alpha},
+    contrast,
 };
 use core::hash::{Hash, Hasher};
 
@@ -116,6 +117,42 @@ impl DynamicColor {
         }
     }
 
+    /// Convert to a [runtime-defined RGB color space](RgbSpaceDef).
+    ///
+    /// This returns the encoded RGB components and the alpha. Missing components are treated as
+    /// zero, as in [`DynamicColor::convert`].
+    ///
+    /// ```
+    /// use color::{Chromaticity, RgbSpaceDef, TransferFunction, parse_color};
+    ///
+    /// let srgb = RgbSpaceDef::new(
+    ///     Chromaticity { x: 0.64, y: 0.33 },
+    ///     Chromaticity { x: 0.30, y: 0.60 },
+    ///     Chromaticity { x: 0.15, y: 0.06 },
+    ///     Chromaticity::D65,
+    ///     TransferFunction::SRGB,
+    /// );
+    /// let [r, g, b, alpha] = parse_color("#ff000080").unwrap().to_rgb_space(&srgb);
+    /// assert!((r - 1.).abs() < 1e-4 && g.abs() < 1e-4 && b.abs() < 1e-4);
+    /// assert!((alpha - 0.5).abs() < 1e-2);
+    /// ```
+    #[must_use]
+    pub fn to_rgb_space(self, space: &RgbSpaceDef) -> [f32; 4] {
+        let [r, g, b, alpha] = self.convert(ColorSpaceTag::LinearSrgb).components;
+        let [r, g, b] = space.from_linear_srgb([r, g, b]);
+        [r, g, b, alpha]
+    }
+
+    /// Convert from a [runtime-defined RGB color space](RgbSpaceDef).
+    ///
+    /// The components are the encoded RGB components and the alpha. The resulting color is in
+    /// [linear sRGB](ColorSpaceTag::LinearSrgb), which is the central color space for conversions.
+    #[must_use]
+    pub fn from_rgb_space(space: &RgbSpaceDef, [r, g, b, alpha]: [f32; 4]) -> Self {
+        let [r, g, b] = space.to_linear_srgb([r, g, b]);
+        Self::new(ColorSpaceTag::LinearSrgb, [r, g, b, alpha])
+    }
+
     /// The const-generic parameter `ABSOLUTE` indicates whether the conversion performs chromatic
     /// adaptation. When `ABSOLUTE` is `true`, no chromatic adaptation is performed.
     fn convert_impl<const ABSOLUTE: bool>(self, cs: ColorSpaceTag) -> Self {
@@ -172,6 +209,25 @@ impl DynamicColor {
         self.convert_impl::<false>(cs)
     }
 
+    #[must_use]
+    /// Convert to a different color space, then set powerless components to missing.
+    ///
+    /// This is [`DynamicColor::convert`] followed by [`DynamicColor::normalize_powerless`].
+    /// Conversion to another color space already sets powerless components missing, but unlike
+    /// `convert`, this also normalizes colors that are already in the color space `cs`.
+    ///
+    /// ```
+    /// use color::{ColorSpaceTag, Missing, parse_color};
+    ///
+    /// let gray = parse_color("oklch(0.5 0 120)").unwrap();
+    /// assert!(gray.convert(ColorSpaceTag::Oklch).flags.missing().is_empty());
+    /// let oklch = gray.convert_normalized(ColorSpaceTag::Oklch);
+    /// assert_eq!(oklch.flags.missing(), Missing::single(2));
+    /// ```
+    pub fn convert_normalized(self, cs: ColorSpaceTag) -> Self {
+        self.convert(cs).normalize_powerless()
+    }
+
     #[must_use]
     /// Convert to a different color space, without chromatic adaptation.
     ///
@@ -189,13 +245,32 @@ impl DynamicColor {
     /// adapted to the given white point `to`. The linear Bradford transform is used to perform the
     /// chromatic adaptation.
     pub fn chromatically_adapt(self, from: Chromaticity, to: Chromaticity) -> Self {
+        self.chromatically_adapt_with(from, to, AdaptationMethod::Bradford, 1.)
+    }
+
+    #[must_use]
+    /// Chromatically adapt the color between the given white point chromaticities, using the given
+    /// chromatic adaptation transform and degree of adaptation.
+    ///
+    /// See [`ColorSpace::chromatically_adapt_with`].
+    pub fn chromatically_adapt_with(
+        self,
+        from: Chromaticity,
+        to: Chromaticity,
+        method: AdaptationMethod,
+        degree: f32,
+    ) -> Self {
         if from == to {
             return self;
         }
 
         // Treat missing components as zero, as per CSS Color Module Level 4 § 4.4.
         let (opaque, alpha) = split_alpha(self.zero_missing_components().components);
-        let components = add_alpha(self.cs.chromatically_adapt(opaque, from, to), alpha);
+        let components = add_alpha(
+            self.cs
+                .chromatically_adapt_with(opaque, from, to, method, degree),
+            alpha,
+        );
         Self {
             cs: self.cs,
             // After chromatically adapting the color, components may no longer be missing. Don't
@@ -301,6 +376,73 @@ impl DynamicColor {
         }
     }
 
+    /// Whether the color is within the natural gamut of the given color space.
+    ///
+    /// Components may exceed the gamut by at most `epsilon`, in the units of the components of
+    /// `cs`. Missing components are interpreted as 0, and the alpha channel is not taken into
+    /// account. See [`ColorSpace::is_in_gamut`] for more details.
+    ///
+    /// ```
+    /// use color::{ColorSpaceTag, parse_color};
+    ///
+    /// let red = parse_color("red").unwrap();
+    /// assert!(red.is_in_gamut(ColorSpaceTag::DisplayP3, 1e-4));
+    ///
+    /// let p3_red = parse_color("color(display-p3 1 0 0)").unwrap();
+    /// assert!(!p3_red.is_in_gamut(ColorSpaceTag::Srgb, 1e-4));
+    /// ```
+    #[must_use]
+    pub fn is_in_gamut(self, cs: ColorSpaceTag, epsilon: f32) -> bool {
+        let (opaque, _) = split_alpha(self.convert(cs).components);
+        cs.is_in_gamut(opaque, epsilon)
+    }
+
+    /// Convert to a different color space, mapping the color into the gamut of that color space.
+    ///
+    /// Unlike [`DynamicColor::clip`], which clamps each component independently and can visibly
+    /// shift hue and lightness, this reduces chroma in [Oklch] while preserving lightness and
+    /// hue, following the algorithm of [CSS Color Module Level 4 § 13.2][css-sec]. Clipping is
+    /// only used as the final step, once the clipped color is within a just noticeable
+    /// difference of the chroma-reduced color.
+    ///
+    /// The gamut of [`Hsl`], [`Hwb`] and [`Hsv`] is that of [`Srgb`]. Color spaces without gamut
+    /// limits, that is, the Lab-like and XYZ color spaces, are converted to without mapping.
+    ///
+    /// Missing components and alpha are handled as by [`DynamicColor::convert`]. If the color
+    /// has to be mapped, its color components are no longer considered missing.
+    ///
+    /// ```
+    /// use color::{ColorSpaceTag, parse_color};
+    ///
+    /// let p3_green = parse_color("color(display-p3 0 1 0)").unwrap();
+    /// let mapped = p3_green.map_to_gamut(ColorSpaceTag::Srgb);
+    /// assert!(mapped.components[..3].iter().all(|c| (0.0..=1.0).contains(c)));
+    /// ```
+    ///
+    /// [Oklch]: crate::Oklch
+    /// [`Hsl`]: crate::Hsl
+    /// [`Hwb`]: crate::Hwb
+    /// [`Hsv`]: crate::Hsv
+    /// [`Srgb`]: crate::Srgb
+    /// [css-sec]: https://www.w3.org/TR/css-color-4/#css-gamut-mapping
+    #[must_use]
+    pub fn map_to_gamut(self, cs: ColorSpaceTag) -> Self {
+        let converted = self.convert(cs);
+        let (opaque, alpha) = split_alpha(converted.components);
+        let (origin, _) = split_alpha(self.convert(ColorSpaceTag::Oklch).components);
+        let mapped = cs.map_to_gamut(opaque, origin);
+        if mapped == opaque {
+            return converted;
+        }
+        let mut result = Self {
+            cs,
+            flags: Flags::from_missing(converted.flags.missing() & Missing::single(3)),
+            components: add_alpha(mapped, alpha),
+        };
+        result.powerless_to_missing();
+        result
+    }
+
     fn split(self, alpha_type: InterpolationAlphaSpace) -> ([f32; 3], f32) {
         // Reference: §12.3 of Color 4 spec
         let (opaque, alpha) = split_alpha(self.components);
@@ -315,36 +457,83 @@ impl DynamicColor {
         (color, alpha)
     }
 
+    /// Set powerless hues to missing.
+    ///
+    /// This is the rule applied by conversion, gamut mapping and
+    /// [`DynamicColor::normalize_powerless`].
     fn powerless_to_missing(&mut self) {
         // Note: the spec seems vague on the details of what this should do,
         // and there is some controversy in discussion threads. For example,
         // in Lab-like spaces, if L is 0 do the other components become powerless?
 
-        // Note: we use hard-coded epsilons to check for approximate equality here, but these do
-        // not account for the normal value range of components. It might be somewhat more correct
-        // to, e.g., consider `0.000_01` approximately equal to `0` for a component with the
-        // natural range `0-100`, but not for a component with the natural range `0-0.5`.
+        /// The tolerance as a fraction of the natural range of the components.
+        const RELATIVE_EPSILON: f32 = 1e-5;
 
-        match self.cs {
+        let [_, c1, c2, _] = self.components;
+        let powerless_hue = match self.cs {
             // See CSS Color Module level 4 § 7, § 9.3, and § 9.4 (HSL, LCH, Oklch).
-            ColorSpaceTag::Hsl | ColorSpaceTag::Lch | ColorSpaceTag::Oklch
-                if self.components[1] < 1e-6 =>
-            {
-                let mut missing = self.flags.missing();
-                self.cs.set_h_missing(&mut missing, &mut self.components);
-                self.flags.set_missing(missing);
-            }
-
+            ColorSpaceTag::Hsl => c1 < 100. * RELATIVE_EPSILON,
+            ColorSpaceTag::Lch => c1 < 150. * RELATIVE_EPSILON,
+            ColorSpaceTag::Oklch => c1 < 0.4 * RELATIVE_EPSILON,
+            // Neutral colors have a small chroma in Jzazbz, as its achromatic axis is not
+            // exactly that of D65. This is about 2e-4 for sRGB white and grows slowly with
+            // luminance.
+            ColorSpaceTag::JzCzhz => c1 < 5e-4,
+            ColorSpaceTag::LchUv => c1 < 180. * RELATIVE_EPSILON,
+            ColorSpaceTag::Hsluv | ColorSpaceTag::Hpluv => c1 < 100. * RELATIVE_EPSILON,
+            ColorSpaceTag::Okhsl => c1 < RELATIVE_EPSILON,
             // See CSS Color Module level 4 § 8 (HWB).
-            ColorSpaceTag::Hwb if self.components[1] + self.components[2] > 100. - 1e-4 => {
-                let mut missing = self.flags.missing();
-                self.cs.set_h_missing(&mut missing, &mut self.components);
-                self.flags.set_missing(missing);
-            }
-            _ => {}
+            ColorSpaceTag::Hwb => c1 + c2 > 100. - 100. * RELATIVE_EPSILON,
+            ColorSpaceTag::Hsv => c1 < 100. * RELATIVE_EPSILON || c2 < 100. * RELATIVE_EPSILON,
+            ColorSpaceTag::Okhsv => c1 < RELATIVE_EPSILON || c2 < RELATIVE_EPSILON,
+            _ => false,
+        };
+        if powerless_hue {
+            let mut missing = self.flags.missing();
+            self.cs.set_h_missing(&mut missing, &mut self.components);
+            self.flags.set_missing(missing);
         }
     }
 
+    /// Set powerless components to missing.
+    ///
+    /// A component is powerless if changing its value does not change the color. This sets the
+    /// hue missing for:
+    ///
+    /// - [`Hsl`](crate::Hsl), [`Hsluv`](crate::Hsluv), [`Hpluv`](crate::Hpluv) and
+    ///   [`Okhsl`](crate::Okhsl) colors without saturation,
+    /// - [`Lch`](crate::Lch), [`Oklch`](crate::Oklch), [`LchUv`](crate::LchUv) and
+    ///   [`JzCzhz`](crate::JzCzhz) colors without chroma,
+    /// - [`Hwb`](crate::Hwb) colors where whiteness and blackness add up to 100 or more, and
+    /// - [`Hsv`](crate::Hsv) and [`Okhsv`](crate::Okhsv) colors without saturation or without
+    ///   value, that is, grays and black.
+    ///
+    /// The rules for HSL, HWB, LCH and Oklch follow CSS Color Module Level 4, and the others
+    /// follow by analogy. Other components, including those of Lab-like colors with a lightness
+    /// of 0 or 100, are not considered powerless, as the spec does not settle this and browsers
+    /// do not do it.
+    ///
+    /// Values are compared to zero (or 100) with a tolerance relative to the natural range of
+    /// the component, so colors that became gray only up to rounding errors also have powerless
+    /// hues.
+    ///
+    /// [`DynamicColor::convert`] applies the same rule to the converted color, so this is mostly
+    /// useful for colors that were not converted, such as parsed colors. Use
+    /// [`DynamicColor::convert_normalized`] to convert and normalize in one go.
+    ///
+    /// ```
+    /// use color::{AlphaColor, DynamicColor, Missing, Oklch};
+    ///
+    /// let gray = DynamicColor::from_alpha_color(AlphaColor::<Oklch>::new([0.5, 0.0, 120.0, 1.0]));
+    /// assert!(gray.flags.missing().is_empty());
+    /// assert_eq!(gray.normalize_powerless().flags.missing(), Missing::single(2));
+    /// ```
+    #[must_use]
+    pub fn normalize_powerless(mut self) -> Self {
+        self.powerless_to_missing();
+        self
+    }
+
     /// Interpolate two colors.
     ///
     /// The colors are interpolated linearly from `self` to `other` in the color space given by
@@ -504,6 +693,33 @@ impl DynamicColor {
         0.2126 * r + 0.7152 * g + 0.0722 * b
     }
 
+    /// The [WCAG 2.1 contrast ratio][wcag] between this color as the foreground and a
+    /// background color.
+    ///
+    /// See [`contrast::wcag_ratio`] for more details.
+    ///
+    /// [wcag]: https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio
+    #[must_use]
+    pub fn contrast_ratio(self, background: Self) -> f32 {
+        contrast::wcag_ratio(
+            self.to_alpha_color::<Srgb>(),
+            background.to_alpha_color::<Srgb>(),
+        )
+    }
+
+    /// The [APCA] lightness contrast `Lc` of this color as text on a background color.
+    ///
+    /// See [`contrast::apca`] for more details.
+    ///
+    /// [APCA]: https://github.com/Myndex/apca-w3
+    #[must_use]
+    pub fn apca_contrast(self, background: Self) -> f32 {
+        contrast::apca(
+            self.to_alpha_color::<Srgb>(),
+            background.to_alpha_color::<Srgb>(),
+        )
+    }
+
     /// Map components.
     #[must_use]
     pub fn map(self, f: impl FnOnce(f32, f32, f32, f32) -> [f32; 4]) -> Self {
@@ -530,22 +746,29 @@ impl DynamicColor {
     /// In a color space that naturally has a lightness component, map that value.
     /// Otherwise, do the mapping in [Oklab]. The lightness range is normalized so
     /// that 1.0 is white. That is the normal range for Oklab but differs from the
-    /// range in [Lab], [Lch], and [Hsl].
+    /// range in [Lab], [Lch], [Luv], [`LchUv`], [Hsl], [Hsluv], and [Hpluv].
     ///
     /// [Oklab]: crate::Oklab
     /// [Lab]: crate::Lab
     /// [Lch]: crate::Lch
+    /// [Luv]: crate::Luv
+    /// [`LchUv`]: crate::LchUv
     /// [Hsl]: crate::Hsl
+    /// [Hsluv]: crate::Hsluv
+    /// [Hpluv]: crate::Hpluv
     #[must_use]
     pub fn map_lightness(self, f: impl FnOnce(f32) -> f32) -> Self {
         match self.cs {
-            ColorSpaceTag::Lab | ColorSpaceTag::Lch => {
+            ColorSpaceTag::Lab | ColorSpaceTag::Lch | ColorSpaceTag::Luv | ColorSpaceTag::LchUv => {
                 self.map(|l, c1, c2, a| [100.0 * f(l * 0.01), c1, c2, a])
             }
             ColorSpaceTag::Oklab | ColorSpaceTag::Oklch => {
                 self.map(|l, c1, c2, a| [f(l), c1, c2, a])
             }
-            ColorSpaceTag::Hsl => self.map(|h, s, l, a| [h, s, 100.0 * f(l * 0.01), a]),
+            ColorSpaceTag::Hsl | ColorSpaceTag::Hsluv | ColorSpaceTag::Hpluv => {
+                self.map(|h, s, l, a| [h, s, 100.0 * f(l * 0.01), a])
+            }
+            ColorSpaceTag::Okhsl => self.map(|h, s, l, a| [h, s, f(l), a]),
             _ => self.map_in(ColorSpaceTag::Oklab, |l, a, b, alpha| [f(l), a, b, alpha]),
         }
     }
@@ -660,7 +883,9 @@ impl UnpremultipliedInterpolator {
 
 #[cfg(test)]
 mod tests {
-    use crate::{ColorSpaceTag, DynamicColor, Missing, parse_color};
+    use crate::{
+        AlphaColor, ColorSpace, ColorSpaceTag, DynamicColor, Hsl, Hsv, Missing, Srgb, parse_color,
+    };
 
     // `DynamicColor` was carefully packed. Ensure its size doesn't accidentally change.
     const _: () = if size_of::<DynamicColor>() != 20 {
@@ -729,6 +954,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn hsv_conversion_uses_shortcuts() {
+        // Converting between the hue-based sRGB spaces should not take a detour through linear
+        // sRGB.
+        for rgb in [[0.5, 0.2, 0.4], [0.9, 0.8, 0.1], [0.1, 0.3, 0.3]] {
+            let srgb = DynamicColor::from_alpha_color(AlphaColor::<Srgb>::new([
+                rgb[0], rgb[1], rgb[2], 1.,
+            ]));
+            let hsv = srgb.convert(ColorSpaceTag::Hsv);
+            assert_eq!(hsv.components[..3], Srgb::convert::<Hsv>(rgb));
+            assert_eq!(
+                srgb.convert(ColorSpaceTag::Hsl)
+                    .convert(ColorSpaceTag::Hsv)
+                    .components[..3],
+                Hsl::convert::<Hsv>(Srgb::convert::<Hsl>(rgb))
+            );
+            assert_eq!(
+                hsv.convert(ColorSpaceTag::Srgb).components[..3],
+                Hsv::convert::<Srgb>(Srgb::convert::<Hsv>(rgb))
+            );
+        }
+        // Scaling the chroma scales the saturation directly, too.
+        assert_eq!(
+            ColorSpaceTag::Hsv.scale_chroma([200., 50., 30.], 0.5),
+            [200., 25., 30.]
+        );
+    }
+
     #[test]
     fn achromatic_sets_hue_powerless() {
         let c = parse_color("oklab(0.2 0 0)").unwrap();
@@ -741,6 +994,47 @@ mod tests {
         );
     }
 
+    #[test]
+    fn roundtrip_sets_hue_powerless() {
+        // Rounding errors in conversions should not make the hue of gray colors meaningful.
+        for (color, cs) in [
+            ("oklch(0.5 0 120)", ColorSpaceTag::Oklch),
+            ("lch(50 0 120)", ColorSpaceTag::Lch),
+            ("gray", ColorSpaceTag::Lch),
+            ("color(display-p3 0.7 0.7 0.7)", ColorSpaceTag::Lch),
+            ("white", ColorSpaceTag::Hwb),
+            ("gray", ColorSpaceTag::JzCzhz),
+            ("white", ColorSpaceTag::JzCzhz),
+            ("gray", ColorSpaceTag::LchUv),
+            ("gray", ColorSpaceTag::Hsluv),
+            ("gray", ColorSpaceTag::Hpluv),
+            ("gray", ColorSpaceTag::Hsv),
+            ("black", ColorSpaceTag::Hsv),
+            ("white", ColorSpaceTag::Okhsl),
+            ("gray", ColorSpaceTag::Okhsv),
+        ] {
+            let c = parse_color(color)
+                .unwrap()
+                .convert(ColorSpaceTag::DisplayP3)
+                .convert(ColorSpaceTag::Srgb)
+                .convert(cs);
+            let hue = cs.layout().hue_channel().unwrap();
+            assert!(
+                c.flags.missing().contains(hue),
+                "Expected the hue of `{color}` to be missing after conversion to {cs:?}, got {c:?}"
+            );
+        }
+        assert_eq!(
+            parse_color("oklch(0.5 0 120)")
+                .unwrap()
+                .convert(ColorSpaceTag::Srgb)
+                .convert(ColorSpaceTag::Oklch)
+                .flags
+                .missing(),
+            Missing::single(2)
+        );
+    }
+
     #[test]
     fn powerless_components() {
         static COLORS_AND_POWERLESS: &[(&str, &[usize])] = &[
@@ -785,6 +1079,71 @@ mod tests {
         }
     }
 
+    #[test]
+    fn normalize_powerless() {
+        for (color, cs) in [
+            ("oklch(0.5 0 120)", ColorSpaceTag::Oklch),
+            ("lch(50 0.001 120)", ColorSpaceTag::Lch),
+            ("hsl(240 0.0005 50)", ColorSpaceTag::Hsl),
+            ("hwb(240 60 40)", ColorSpaceTag::Hwb),
+        ] {
+            let c = parse_color(color).unwrap();
+            let hue = cs.layout().hue_channel().unwrap();
+            // Conversion to the color space the color is already in leaves it unchanged.
+            assert!(c.convert(cs).flags.missing().is_empty());
+            assert_eq!(
+                c.normalize_powerless().flags.missing(),
+                Missing::single(hue)
+            );
+            assert_eq!(
+                c.convert_normalized(cs).flags.missing(),
+                Missing::single(hue)
+            );
+        }
+
+        for color in [
+            "oklch(0.5 0.01 120)",
+            "lch(50 0.01 120)",
+            "hsl(240 0.01 50)",
+        ] {
+            let c = parse_color(color).unwrap().normalize_powerless();
+            assert!(
+                c.flags.missing().is_empty(),
+                "Expected the hue of `{color}` not to be powerless"
+            );
+        }
+    }
+
+    #[test]
+    fn interpolate_from_gray() {
+        use crate::HueDirection;
+
+        // The hue of gray is missing after conversion, so the interpolation takes the other hue.
+        let gray = parse_color("rgb(128 128 128)").unwrap();
+        let blue = parse_color("blue").unwrap();
+        for cs in [
+            ColorSpaceTag::Hsl,
+            ColorSpaceTag::Hwb,
+            ColorSpaceTag::Lch,
+            ColorSpaceTag::Oklch,
+            ColorSpaceTag::JzCzhz,
+            ColorSpaceTag::LchUv,
+            ColorSpaceTag::Hsluv,
+            ColorSpaceTag::Hpluv,
+            ColorSpaceTag::Hsv,
+            ColorSpaceTag::Okhsl,
+            ColorSpaceTag::Okhsv,
+        ] {
+            let hue = cs.layout().hue_channel().unwrap();
+            let expected = blue.convert(cs).components[hue];
+            let mid = gray.interpolate(blue, cs, HueDirection::Shorter).eval(0.5);
+            assert!(
+                (mid.components[hue] - expected).abs() < 1e-3,
+                "Expected hue {expected} interpolating in {cs:?}, got {mid:?}"
+            );
+        }
+    }
+
     #[test]
     fn premultiplied_rectangular_interpolation() {
         use crate::HueDirection;
@@ -7,7 +7,7 @@
 macro_rules! define_float_funcs {
     ($(
         fn $name:ident(self $(,$arg:ident: $arg_ty:ty)*) -> $ret:ty
-        => $lfname:ident;
+        => $lfname:ident, $lfname64:ident;
     )+) => {
 
         /// Since core doesn't depend upon libm, this provides libm implementations
@@ -31,17 +31,35 @@ macro_rules! define_float_funcs {
             })+
         }
 
+        #[cfg(not(feature = "std"))]
+        impl FloatFuncs for f64 {
+            $(fn $name(self $(,$arg: $arg_ty)*) -> $ret {
+                #[cfg(feature = "libm")]
+                return libm::$lfname64(self $(,$arg)*);
+
+                #[cfg(not(feature = "libm"))]
+                compile_error!("color requires either the `std` or `libm` feature")
+            })+
+        }
+
     }
 }
 
 define_float_funcs! {
-    fn atan2(self, other: Self) -> Self => atan2f;
-    fn cbrt(self) -> Self => cbrtf;
-    fn ceil(self) -> Self => ceilf;
-    fn floor(self) -> Self => floorf;
-    fn hypot(self, other: Self) -> Self => hypotf;
+    fn acos(self) -> Self => acosf, acos;
+    fn asin(self) -> Self => asinf, asin;
+    fn atan(self) -> Self => atanf, atan;
+    fn atan2(self, other: Self) -> Self => atan2f, atan2;
+    fn cbrt(self) -> Self => cbrtf, cbrt;
+    fn ceil(self) -> Self => ceilf, ceil;
+    fn exp(self) -> Self => expf, exp;
+    fn floor(self) -> Self => floorf, floor;
+    fn hypot(self, other: Self) -> Self => hypotf, hypot;
+    fn ln(self) -> Self => logf, log;
     // Note: powi is missing because its libm implementation is not efficient
-    fn powf(self, n: Self) -> Self => powf;
-    fn sin_cos(self) -> (Self, Self) => sincosf;
-    fn sqrt(self) -> Self => sqrtf;
+    fn powf(self, n: Self) -> Self => powf, pow;
+    fn round(self) -> Self => roundf, round;
+    fn sin_cos(self) -> (Self, Self) => sincosf, sincos;
+    fn sqrt(self) -> Self => sqrtf, sqrt;
+    fn tan(self) -> Self => tanf, tan;
 }
@@ -0,0 +1,718 @@
+// Copyright 2025 the Color Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Gamut mapping.
+
+use crate::{
+    ColorSpace, ColorSpaceTag, Lch, Oklab, Oklch, OpaqueColor, Srgb,
+    colorspace::{OKLAB_LAB_TO_LMS, OKLAB_LMS_TO_SRGB},
+};
+
+#[cfg(all(not(feature = "std"), not(test)))]
+use crate::floatfuncs::FloatFuncs;
+
+/// The just noticeable difference used by the CSS gamut mapping algorithm, in deltaEOK.
+const JND: f32 = 0.02;
+
+/// The precision of the chroma binary search.
+const EPSILON: f32 = 0.0001;
+
+/// Whether the color space has no gamut limits.
+fn is_unbounded(cs: ColorSpaceTag) -> bool {
+    matches!(
+        cs,
+        ColorSpaceTag::Lab
+            | ColorSpaceTag::Lch
+            | ColorSpaceTag::Oklab
+            | ColorSpaceTag::Oklch
+            | ColorSpaceTag::ICtCp
+            | ColorSpaceTag::Jzazbz
+            | ColorSpaceTag::JzCzhz
+            | ColorSpaceTag::Luv
+            | ColorSpaceTag::LchUv
+            | ColorSpaceTag::XyzD50
+            | ColorSpaceTag::XyzD65
+    )
+}
+
+/// The deltaEOK between a color in `CS` and a color in Oklch.
+fn delta_eok<CS: ColorSpace>(src: [f32; 3], oklch: [f32; 3]) -> f32 {
+    OpaqueColor::<Oklab>::new(CS::convert::<Oklab>(src))
+        .difference(OpaqueColor::new(Oklch::convert::<Oklab>(oklch)))
+}
+
+/// Map a color into the gamut of `CS`.
+///
+/// The color is given both as `dest`, its components already converted to `CS`, and as
+/// `origin`, its components in [`Oklch`]. If the color is in gamut, `dest` is returned
+/// unchanged.
+///
+/// This implements the algorithm of [CSS Color Module Level 4 § 13.2][css-sec]: the chroma is
+/// reduced in Oklch until clipping the color to the gamut changes it by less than the just
+/// noticeable difference, at which point the clipped color is returned.
+///
+/// The gamut of [`Hsl`](crate::Hsl), [`Hwb`](crate::Hwb), [`Hsv`](crate::Hsv),
+/// [`Hsluv`](crate::Hsluv), [`Hpluv`](crate::Hpluv), [`Okhsl`](crate::Okhsl) and
+/// [`Okhsv`](crate::Okhsv) is that of [`Srgb`]. Lab-like spaces and XYZ spaces don't have a
+/// gamut, so colors are returned unchanged.
+///
+/// [css-sec]: https://www.w3.org/TR/css-color-4/#css-gamut-mapping
+pub(crate) fn map_to_gamut<CS: ColorSpace>(dest: [f32; 3], origin: [f32; 3]) -> [f32; 3] {
+    match CS::TAG {
+        Some(
+            ColorSpaceTag::Hsl
+            | ColorSpaceTag::Hwb
+            | ColorSpaceTag::Hsv
+            | ColorSpaceTag::Hsluv
+            | ColorSpaceTag::Hpluv
+            | ColorSpaceTag::Okhsl
+            | ColorSpaceTag::Okhsv,
+        ) => {
+            let srgb = CS::convert::<Srgb>(dest);
+            let mapped = map_to_gamut::<Srgb>(srgb, origin);
+            return if mapped == srgb {
+                dest
+            } else {
+                Srgb::convert::<CS>(mapped)
+            };
+        }
+        Some(cs) if is_unbounded(cs) => return dest,
+        _ => {}
+    }
+
+    if CS::is_in_gamut(dest, 0.) {
+        return dest;
+    }
+    let [l, c, h] = origin;
+    if l >= 1.0 {
+        return CS::WHITE_COMPONENTS;
+    }
+    if l <= 0.0 {
+        return Oklch::convert::<CS>([0., 0., 0.]);
+    }
+
+    let mut current = origin;
+    let mut clipped = CS::clip(dest);
+    if delta_eok::<CS>(clipped, current) < JND {
+        return clipped;
+    }
+
+    let mut min = 0.0;
+    let mut max = c;
+    let mut min_in_gamut = true;
+    while max - min > EPSILON {
+        let chroma = 0.5 * (min + max);
+        current = [l, chroma, h];
+        let candidate = Oklch::convert::<CS>(current);
+        if min_in_gamut && CS::is_in_gamut(candidate, 0.) {
+            min = chroma;
+            continue;
+        }
+        clipped = CS::clip(candidate);
+        let e = delta_eok::<CS>(clipped, current);
+        if e < JND {
+            if JND - e < EPSILON {
+                return clipped;
+            }
+            min_in_gamut = false;
+            min = chroma;
+        } else {
+            max = chroma;
+        }
+    }
+    clipped
+}
+
+impl Oklch {
+    /// The maximum chroma at the given lightness and hue that stays inside the gamut of a color
+    /// space.
+    ///
+    /// The lightness and chroma are in the units of [`Oklch`], the hue is in degrees. The gamut is
+    /// typically that of an RGB color space such as [`ColorSpaceTag::Srgb`],
+    /// [`ColorSpaceTag::DisplayP3`], or [`ColorSpaceTag::Rec2020`]. The gamut of
+    /// [`ColorSpaceTag::Hsl`], [`ColorSpaceTag::Hwb`], [`ColorSpaceTag::Hsv`],
+    /// [`ColorSpaceTag::Hsluv`], [`ColorSpaceTag::Hpluv`], [`ColorSpaceTag::Okhsl`] and
+    /// [`ColorSpaceTag::Okhsv`] is that of sRGB.
+    ///
+    /// Lightness outside of the range `(0, 1)` has a maximum chroma of 0. Color spaces without
+    /// gamut limits, that is, the Lab-like and XYZ color spaces, have an infinite maximum chroma.
+    ///
+    /// For the sRGB gamut, this uses the analytic approximation from [Björn Ottosson's gamut
+    /// clipping post][bjorn], which is accurate to within `1e-3` of the chroma found by bisection.
+    /// Other gamuts are found by bisection.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color::{ColorSpaceTag, Oklch, OpaqueColor, Srgb};
+    ///
+    /// let c = Oklch::max_chroma(0.7, 150., ColorSpaceTag::Srgb);
+    /// let boundary = OpaqueColor::<Oklch>::new([0.7, c, 150.]);
+    /// assert!(boundary.is_in_gamut::<Srgb>(1e-3));
+    /// assert!(!boundary.map(|l, c, h| [l, c + 0.01, h]).is_in_gamut::<Srgb>(1e-3));
+    /// ```
+    ///
+    /// [bjorn]: https://bottosson.github.io/posts/gamutclipping/
+    #[must_use]
+    pub fn max_chroma(l: f32, h: f32, gamut: ColorSpaceTag) -> f32 {
+        if is_srgb_gamut(gamut) {
+            if l <= 0. || l >= 1. {
+                return 0.;
+            }
+            let (b, a) = h.to_radians().sin_cos();
+            let cusp = find_cusp(a, b);
+            find_gamut_intersection(a, b, l, 1., l, cusp)
+        } else {
+            max_chroma_bisect::<Self>(l, h, gamut)
+        }
+    }
+
+    /// The cusp of the gamut of a color space at the given hue.
+    ///
+    /// The cusp is the point of maximum chroma at the hue; it is returned as `(L, C)` in the
+    /// units of [`Oklch`]. The hue is in degrees. Color spaces without gamut limits, that is, the
+    /// Lab-like and XYZ color spaces, don't have a cusp, and `None` is returned.
+    ///
+    /// See [`Oklch::max_chroma`] for details on the gamut.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color::{ColorSpaceTag, Oklch, OpaqueColor, Srgb};
+    ///
+    /// // The cusp of the sRGB gamut at the hue of sRGB red is sRGB red itself.
+    /// let [l, c, h] = OpaqueColor::<Srgb>::new([1., 0., 0.]).convert::<Oklch>().components;
+    /// let (cusp_l, cusp_c) = Oklch::cusp(h, ColorSpaceTag::Srgb).unwrap();
+    /// assert!((cusp_l - l).abs() < 1e-3);
+    /// assert!((cusp_c - c).abs() < 1e-3);
+    /// ```
+    #[must_use]
+    pub fn cusp(h: f32, gamut: ColorSpaceTag) -> Option<(f32, f32)> {
+        if is_srgb_gamut(gamut) {
+            let (b, a) = h.to_radians().sin_cos();
+            Some(find_cusp(a, b))
+        } else {
+            cusp_search::<Self>(h, gamut)
+        }
+    }
+}
+
+impl Lch {
+    /// The maximum chroma at the given lightness and hue that stays inside the gamut of a color
+    /// space.
+    ///
+    /// The lightness and chroma are in the units of [`Lch`], the hue is in degrees. Lightness
+    /// outside of the range `(0, 100)` has a maximum chroma of 0.
+    ///
+    /// See [`Oklch::max_chroma`] for details on the gamut.
+    #[must_use]
+    pub fn max_chroma(l: f32, h: f32, gamut: ColorSpaceTag) -> f32 {
+        max_chroma_bisect::<Self>(l, h, gamut)
+    }
+
+    /// The cusp of the gamut of a color space at the given hue.
+    ///
+    /// The cusp is returned as `(L, C)` in the units of [`Lch`]. See [`Oklch::cusp`] for more
+    /// details.
+    #[must_use]
+    pub fn cusp(h: f32, gamut: ColorSpaceTag) -> Option<(f32, f32)> {
+        cusp_search::<Self>(h, gamut)
+    }
+}
+
+/// Whether the gamut is that of sRGB, for which analytic solutions exist in Oklab.
+fn is_srgb_gamut(gamut: ColorSpaceTag) -> bool {
+    matches!(
+        gamut,
+        ColorSpaceTag::Srgb
+            | ColorSpaceTag::LinearSrgb
+            | ColorSpaceTag::Hsl
+            | ColorSpaceTag::Hwb
+            | ColorSpaceTag::Hsv
+            | ColorSpaceTag::Hsluv
+            | ColorSpaceTag::Hpluv
+            | ColorSpaceTag::Okhsl
+            | ColorSpaceTag::Okhsv
+    )
+}
+
+/// Find the maximum chroma of an LCh-like color space `CS` by bisection.
+fn max_chroma_bisect<CS: ColorSpace>(l: f32, h: f32, gamut: ColorSpaceTag) -> f32 {
+    let gamut = match gamut {
+        ColorSpaceTag::Hsl
+        | ColorSpaceTag::Hwb
+        | ColorSpaceTag::Hsv
+        | ColorSpaceTag::Hsluv
+        | ColorSpaceTag::Hpluv
+        | ColorSpaceTag::Okhsl
+        | ColorSpaceTag::Okhsv => ColorSpaceTag::Srgb,
+        _ if is_unbounded(gamut) => return f32::INFINITY,
+        _ => gamut,
+    };
+    let white = CS::WHITE_COMPONENTS[0];
+    if l <= 0. || l >= white {
+        return 0.;
+    }
+    let in_gamut =
+        |c: f32| gamut.is_in_gamut(gamut.from_linear_srgb(CS::to_linear_srgb([l, c, h])), 0.);
+
+    // Bracket the boundary, then bisect.
+    let mut lo = 0.;
+    let mut hi = 0.5 * white;
+    let mut doublings = 0;
+    while in_gamut(hi) {
+        if doublings == 16 {
+            // Practically unbounded.
+            return f32::INFINITY;
+        }
+        lo = hi;
+        hi *= 2.;
+        doublings += 1;
+    }
+    for _ in 0..32 {
+        let mid = 0.5 * (lo + hi);
+        if in_gamut(mid) {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    lo
+}
+
+/// Find the cusp of an LCh-like color space `CS` by golden-section search over lightness.
+///
+/// The maximum chroma as a function of lightness is unimodal, peaking at the cusp.
+fn cusp_search<CS: ColorSpace>(h: f32, gamut: ColorSpaceTag) -> Option<(f32, f32)> {
+    if is_unbounded(gamut) {
+        return None;
+    }
+    const INV_PHI: f32 = 0.618_034;
+    let mut lo = 0.;
+    let mut hi = CS::WHITE_COMPONENTS[0];
+    let mut l1 = hi - INV_PHI * (hi - lo);
+    let mut l2 = lo + INV_PHI * (hi - lo);
+    let mut c1 = max_chroma_bisect::<CS>(l1, h, gamut);
+    let mut c2 = max_chroma_bisect::<CS>(l2, h, gamut);
+    for _ in 0..40 {
+        if c1 < c2 {
+            lo = l1;
+            l1 = l2;
+            c1 = c2;
+            l2 = lo + INV_PHI * (hi - lo);
+            c2 = max_chroma_bisect::<CS>(l2, h, gamut);
+        } else {
+            hi = l2;
+            l2 = l1;
+            c2 = c1;
+            l1 = hi - INV_PHI * (hi - lo);
+            c1 = max_chroma_bisect::<CS>(l1, h, gamut);
+        }
+    }
+    Some(if c1 < c2 { (l2, c2) } else { (l1, c1) })
+}
+
+// The following is adapted from Björn Ottosson's gamut clipping post. Saturation here is
+// defined as `S = C / L`, and `a` and `b` must be normalized such that `a² + b² = 1`.
+//
+// See https://bottosson.github.io/posts/gamutclipping/.
+
+/// The coefficients relating a change in Oklab `a` and `b` along the hue `(a, b)` to a change in
+/// the nonlinear LMS cone responses.
+fn lms_coefficients(a: f32, b: f32) -> [f32; 3] {
+    [
+        OKLAB_LAB_TO_LMS[0][1] * a + OKLAB_LAB_TO_LMS[0][2] * b,
+        OKLAB_LAB_TO_LMS[1][1] * a + OKLAB_LAB_TO_LMS[1][2] * b,
+        OKLAB_LAB_TO_LMS[2][1] * a + OKLAB_LAB_TO_LMS[2][2] * b,
+    ]
+}
+
+/// Find the maximum saturation possible for a given hue that fits in sRGB.
+fn compute_max_saturation(a: f32, b: f32) -> f32 {
+    // Max saturation will be when one of r, g or b goes below zero. Select different
+    // coefficients depending on which component goes below zero first.
+    let (k, w) = if -1.881_703_3 * a - 0.809_364_9 * b > 1. {
+        // Red component
+        (
+            [
+                1.190_862_8,
+                1.765_767_3,
+                0.596_626_4,
+                0.755_152,
+                0.567_712_4,
+            ],
+            OKLAB_LMS_TO_SRGB[0],
+        )
+    } else if 1.814_441_1 * a - 1.194_452_8 * b > 1. {
+        // Green component
+        (
+            [
+                0.739_565_15,
+                -0.459_544_04,
+                0.082_854_27,
+                0.125_410_7,
+                0.145_032_04,
+            ],
+            OKLAB_LMS_TO_SRGB[1],
+        )
+    } else {
+        // Blue component
+        (
+            [
+                1.357_336_5,
+                -0.009_157_99,
+                -1.151_302_1,
+                -0.505_596_06,
+                0.006_921_67,
+            ],
+            OKLAB_LMS_TO_SRGB[2],
+        )
+    };
+
+    // Approximate max saturation using a polynomial.
+    let s = k[0] + k[1] * a + k[2] * b + k[3] * a * a + k[4] * a * b;
+
+    // Do one step of Halley's method to get closer. This gives an error less than 1e-6, except
+    // for some blue hues where dS/dh is close to infinite.
+    let [k_l, k_m, k_s] = lms_coefficients(a, b);
+    let l_ = 1. + s * k_l;
+    let m_ = 1. + s * k_m;
+    let s_ = 1. + s * k_s;
+
+    let l = l_ * l_ * l_;
+    let m = m_ * m_ * m_;
+    let s3 = s_ * s_ * s_;
+
+    let l_ds = 3. * k_l * l_ * l_;
+    let m_ds = 3. * k_m * m_ * m_;
+    let s_ds = 3. * k_s * s_ * s_;
+
+    let l_ds2 = 6. * k_l * k_l * l_;
+    let m_ds2 = 6. * k_m * k_m * m_;
+    let s_ds2 = 6. * k_s * k_s * s_;
+
+    let f = w[0] * l + w[1] * m + w[2] * s3;
+    let f1 = w[0] * l_ds + w[1] * m_ds + w[2] * s_ds;
+    let f2 = w[0] * l_ds2 + w[1] * m_ds2 + w[2] * s_ds2;
+
+    s - f * f1 / (f1 * f1 - 0.5 * f * f2)
+}
+
+/// Find the lightness and chroma of the sRGB gamut cusp for a given hue.
+pub(crate) fn find_cusp(a: f32, b: f32) -> (f32, f32) {
+    // First, find the maximum saturation (saturation S = C/L).
+    let s_cusp = compute_max_saturation(a, b);
+
+    // Convert to linear sRGB to find the first point where at least one of r, g or b >= 1.
+    let [r, g, b] = Oklab::to_linear_srgb([1., s_cusp * a, s_cusp * b]);
+    let l_cusp = (1. / r.max(g).max(b)).cbrt();
+    (l_cusp, l_cusp * s_cusp)
+}
+
+/// Find the intersection of a line with the sRGB gamut boundary.
+///
+/// The line is defined by `L = L0 * (1 - t) + t * L1` and `C = t * C1`; the returned value is
+/// `t` at the intersection.
+pub(crate) fn find_gamut_intersection(
+    a: f32,
+    b: f32,
+    l1: f32,
+    c1: f32,
+    l0: f32,
+    (cusp_l, cusp_c): (f32, f32),
+) -> f32 {
+    // Find the intersection for the upper and lower half separately.
+    if ((l1 - l0) * cusp_c - (cusp_l - l0) * c1) <= 0. {
+        // Lower half
+        return cusp_c * l0 / (c1 * cusp_l + cusp_c * (l0 - l1));
+    }
+
+    // Upper half. First intersect with the triangle...
+    let mut t = cusp_c * (l0 - 1.) / (c1 * (cusp_l - 1.) + cusp_c * (l0 - l1));
+
+    // ... then do one step of Halley's method.
+    let dl = l1 - l0;
+    let dc = c1;
+    let [k_l, k_m, k_s] = lms_coefficients(a, b);
+    let l_dt = dl + dc * k_l;
+    let m_dt = dl + dc * k_m;
+    let s_dt = dl + dc * k_s;
+
+    let l = l0 * (1. - t) + t * l1;
+    let c = t * c1;
+
+    let l_ = l + c * k_l;
+    let m_ = l + c * k_m;
+    let s_ = l + c * k_s;
+
+    let lms = [l_ * l_ * l_, m_ * m_ * m_, s_ * s_ * s_];
+    let lms_dt = [
+        3. * l_dt * l_ * l_,
+        3. * m_dt * m_ * m_,
+        3. * s_dt * s_ * s_,
+    ];
+    let lms_dt2 = [
+        6. * l_dt * l_dt * l_,
+        6. * m_dt * m_dt * m_,
+        6. * s_dt * s_dt * s_,
+    ];
+
+    let mut step = f32::MAX;
+    for w in OKLAB_LMS_TO_SRGB {
+        let x = w[0] * lms[0] + w[1] * lms[1] + w[2] * lms[2] - 1.;
+        let x1 = w[0] * lms_dt[0] + w[1] * lms_dt[1] + w[2] * lms_dt[2];
+        let x2 = w[0] * lms_dt2[0] + w[1] * lms_dt2[1] + w[2] * lms_dt2[2];
+        let u = x1 / (x1 * x1 - 0.5 * x * x2);
+        if u >= 0. {
+            step = step.min(-x * u);
+        }
+    }
+    t += step;
+    t
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        AlphaColor, ColorSpaceTag, DisplayP3, DynamicColor, Hsl, Lch, Missing, Oklch, OpaqueColor,
+        Srgb, parse_color,
+    };
+
+    fn hue_difference(a: f32, b: f32) -> f32 {
+        let d = (a - b).rem_euclid(360.);
+        d.min(360. - d)
+    }
+
+    #[test]
+    fn in_gamut_unchanged() {
+        let c = parse_color("color(srgb 0.2 0.5 0.8 / 0.5)").unwrap();
+        assert_eq!(c.map_to_gamut(ColorSpaceTag::Srgb), c);
+
+        let c = AlphaColor::<DisplayP3>::new([0.2, 0.5, 0.8, 1.]);
+        assert_eq!(
+            c.map_to_gamut::<DisplayP3>().components,
+            [0.2, 0.5, 0.8, 1.]
+        );
+    }
+
+    #[test]
+    fn p3_red_to_srgb() {
+        let red = OpaqueColor::<DisplayP3>::new([1., 0., 0.]);
+        let origin = red.convert::<Oklch>().components;
+        let mapped = red.map_to_gamut::<Srgb>();
+
+        for component in mapped.components {
+            assert!(
+                (0. ..=1.).contains(&component),
+                "{mapped:?} is not in the sRGB gamut"
+            );
+        }
+        let mapped_oklch = mapped.convert::<Oklch>().components;
+        assert!(
+            (mapped_oklch[0] - origin[0]).abs() < 0.02,
+            "lightness should be roughly preserved: {mapped_oklch:?} vs {origin:?}"
+        );
+        assert!(
+            hue_difference(mapped_oklch[2], origin[2]) < 2.,
+            "hue should be roughly preserved: {mapped_oklch:?} vs {origin:?}"
+        );
+        assert!(
+            mapped_oklch[1] < origin[1],
+            "chroma should be reduced: {mapped_oklch:?} vs {origin:?}"
+        );
+    }
+
+    #[test]
+    fn oklch_to_srgb() {
+        for (l, c, h) in [(0.7, 0.4, 150.), (0.3, 0.3, 300.), (0.9, 0.25, 30.)] {
+            let color = DynamicColor::from_alpha_color(AlphaColor::<Oklch>::new([l, c, h, 0.7]));
+            let mapped = color.map_to_gamut(ColorSpaceTag::Srgb);
+            assert_eq!(mapped.cs, ColorSpaceTag::Srgb);
+            assert_eq!(mapped.components[3], 0.7, "alpha should be preserved");
+            for component in &mapped.components[..3] {
+                assert!(
+                    (0. ..=1.).contains(component),
+                    "{mapped:?} is not in the sRGB gamut"
+                );
+            }
+            let [ml, _, mh, _] = mapped.convert(ColorSpaceTag::Oklch).components;
+            assert!((ml - l).abs() < 0.03, "lightness {ml} too far from {l}");
+            assert!(hue_difference(mh, h) < 3., "hue {mh} too far from {h}");
+        }
+    }
+
+    #[test]
+    fn extreme_lightness() {
+        let white = AlphaColor::<Oklch>::new([1.2, 0.3, 100., 1.]).map_to_gamut::<Srgb>();
+        assert_eq!(white.components, [1., 1., 1., 1.]);
+        let black = AlphaColor::<Oklch>::new([-0.1, 0.3, 100., 1.]).map_to_gamut::<Srgb>();
+        assert_eq!(black.components, [0., 0., 0., 1.]);
+    }
+
+    #[test]
+    fn hsl_uses_srgb_gamut() {
+        let c = parse_color("color(display-p3 0 1 0)").unwrap();
+        let mapped = c.map_to_gamut(ColorSpaceTag::Hsl);
+        assert_eq!(mapped.cs, ColorSpaceTag::Hsl);
+        let [h, s, l] = mapped.to_alpha_color::<Hsl>().discard_alpha().components;
+        let srgb = OpaqueColor::<Hsl>::new([h, s, l]).convert::<Srgb>();
+        for component in srgb.components {
+            assert!(
+                (-1e-4..=1. + 1e-4).contains(&component),
+                "{srgb:?} is not in the sRGB gamut"
+            );
+        }
+    }
+
+    #[test]
+    fn unbounded_targets() {
+        let c = parse_color("color(display-p3 1 0 0)").unwrap();
+        assert_eq!(
+            c.map_to_gamut(ColorSpaceTag::Oklab),
+            c.convert(ColorSpaceTag::Oklab)
+        );
+        assert_eq!(
+            c.map_to_gamut(ColorSpaceTag::XyzD65),
+            c.convert(ColorSpaceTag::XyzD65)
+        );
+    }
+
+    #[test]
+    fn missing_components() {
+        // In gamut, so missingness is carried over as in conversion.
+        let c = parse_color("color(srgb 0.5 none 0)").unwrap();
+        assert_eq!(
+            c.map_to_gamut(ColorSpaceTag::DisplayP3).flags.missing(),
+            Missing::single(1)
+        );
+
+        // Out of gamut, the color channels change, but missing alpha is preserved.
+        let c = parse_color("oklch(0.7 0.4 none / none)").unwrap();
+        let mapped = c.map_to_gamut(ColorSpaceTag::Srgb);
+        assert_eq!(mapped.flags.missing(), Missing::single(3));
+    }
+
+    #[test]
+    fn max_chroma_analytic_matches_bisection() {
+        for h in (0..360).step_by(5) {
+            let h = h as f32;
+            for l in [0.1, 0.3, 0.5, 0.7, 0.9, 0.99] {
+                let analytic = Oklch::max_chroma(l, h, ColorSpaceTag::Srgb);
+                let bisected = super::max_chroma_bisect::<Oklch>(l, h, ColorSpaceTag::Srgb);
+                assert!(
+                    (analytic - bisected).abs() < 1e-3,
+                    "max chroma at l={l}, h={h}: analytic {analytic}, bisected {bisected}"
+                );
+            }
+            let (analytic_l, analytic_c) = Oklch::cusp(h, ColorSpaceTag::Srgb).unwrap();
+            let (search_l, search_c) = super::cusp_search::<Oklch>(h, ColorSpaceTag::Srgb).unwrap();
+            assert!(
+                (analytic_c - search_c).abs() < 1e-3,
+                "cusp chroma at h={h}: analytic {analytic_c}, search {search_c}"
+            );
+            assert!(
+                (analytic_l - search_l).abs() < 1e-2,
+                "cusp lightness at h={h}: analytic {analytic_l}, search {search_l}"
+            );
+        }
+    }
+
+    #[test]
+    fn cusp_of_primaries() {
+        for (components, gamut) in [
+            ([1., 0., 0.], ColorSpaceTag::Srgb),
+            ([0., 1., 0.], ColorSpaceTag::Srgb),
+            ([0., 0., 1.], ColorSpaceTag::Srgb),
+            ([300., 100., 50.], ColorSpaceTag::Hsl),
+            ([0., 1., 0.], ColorSpaceTag::DisplayP3),
+        ] {
+            let primary = gamut.convert(ColorSpaceTag::Oklch, components);
+            let (l, c) = Oklch::cusp(primary[2], gamut).unwrap();
+            assert!(
+                (l - primary[0]).abs() < 2e-3 && (c - primary[1]).abs() < 2e-3,
+                "cusp of {gamut:?} at {components:?}: expected {primary:?}, got ({l}, {c})"
+            );
+        }
+
+        let [l, c, h] = OpaqueColor::<Srgb>::new([1., 0., 0.])
+            .convert::<Lch>()
+            .components;
+        let (cusp_l, cusp_c) = Lch::cusp(h, ColorSpaceTag::Srgb).unwrap();
+        assert!(
+            (cusp_l - l).abs() < 0.1 && (cusp_c - c).abs() < 0.1,
+            "Lch cusp of sRGB red: expected ({l}, {c}), got ({cusp_l}, {cusp_c})"
+        );
+    }
+
+    #[test]
+    fn max_chroma_is_boundary() {
+        for (l, h, gamut) in [
+            (0.6, 30., ColorSpaceTag::DisplayP3),
+            (0.8, 200., ColorSpaceTag::Rec2020),
+            (0.4, 270., ColorSpaceTag::A98Rgb),
+        ] {
+            let c = Oklch::max_chroma(l, h, gamut);
+            let inside = DynamicColor::from_alpha_color(AlphaColor::<Oklch>::new([l, c, h, 1.]));
+            let outside =
+                DynamicColor::from_alpha_color(AlphaColor::<Oklch>::new([l, c + 1e-3, h, 1.]));
+            assert!(
+                inside.is_in_gamut(gamut, 1e-5),
+                "oklch({l} {c} {h}) should be in {gamut:?}"
+            );
+            assert!(
+                !outside.is_in_gamut(gamut, 1e-5),
+                "oklch({l} {c} {h}) should be at the boundary of {gamut:?}"
+            );
+        }
+
+        // Wider gamuts contain narrower ones.
+        let srgb = Lch::max_chroma(50., 140., ColorSpaceTag::Srgb);
+        let p3 = Lch::max_chroma(50., 140., ColorSpaceTag::DisplayP3);
+        let rec2020 = Lch::max_chroma(50., 140., ColorSpaceTag::Rec2020);
+        assert!(
+            srgb < p3 && p3 < rec2020,
+            "expected {srgb} < {p3} < {rec2020}"
+        );
+    }
+
+    #[test]
+    fn max_chroma_edge_cases() {
+        for l in [-0.1, 0., 1., 1.5] {
+            assert_eq!(
+                Oklch::max_chroma(l, 120., ColorSpaceTag::Srgb),
+                0.,
+                "lightness {l} has no chroma"
+            );
+            assert_eq!(
+                Oklch::max_chroma(l, 120., ColorSpaceTag::DisplayP3),
+                0.,
+                "lightness {l} has no chroma"
+            );
+            assert_eq!(
+                Lch::max_chroma(l * 100., 120., ColorSpaceTag::Srgb),
+                0.,
+                "lightness {l} has no chroma"
+            );
+        }
+        assert_eq!(
+            Oklch::max_chroma(0.5, 120., ColorSpaceTag::Oklab),
+            f32::INFINITY,
+            "Oklab is unbounded"
+        );
+        assert_eq!(
+            Lch::max_chroma(50., 120., ColorSpaceTag::XyzD50),
+            f32::INFINITY,
+            "XYZ is unbounded"
+        );
+        assert_eq!(
+            Oklch::cusp(120., ColorSpaceTag::Lch),
+            None,
+            "Lch is unbounded"
+        );
+        assert_eq!(
+            Lch::cusp(120., ColorSpaceTag::XyzD65),
+            None,
+            "XYZ is unbounded"
+        );
+    }
+}
@@ -0,0 +1,282 @@
+// Copyright 2025 the Color Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The HCT color space and tonal palettes of Material Design.
+//!
+//! [`Hct`] combines the hue and chroma of the [CAM16](crate::cam16) color appearance model with
+//! the tone `L*` of [CIELAB](crate::Lab). Tone is a good predictor of contrast, which makes HCT
+//! well suited to building accessible color schemes: colors with the same tone difference have
+//! about the same contrast, regardless of their hue and chroma.
+//!
+//! Many combinations of hue, chroma and tone cannot be displayed. [`Hct::solve`] finds the sRGB
+//! color closest to a requested combination, keeping hue and tone and reducing chroma as
+//! needed. [`TonalPalette`] uses this to generate the tones of a key color.
+//!
+//! The results follow [Material Color Utilities][mcu].
+//!
+//! # Example
+//!
+//! ```rust
+//! use color::hct::TonalPalette;
+//! use color::{OpaqueColor, Srgb};
+//!
+//! let primary = TonalPalette::from_color(OpaqueColor::<Srgb>::new([0.4, 0.31, 0.64]));
+//! let container = primary.tone(90.);
+//! let on_container = primary.tone(10.);
+//! ```
+//!
+//! [mcu]: https://github.com/material-foundation/material-color-utilities
+
+use crate::cam16::{Cam16, ViewingConditions};
+use crate::colorspace::{lstar_from_y, y_from_lstar};
+use crate::{ColorSpace, ColorSpaceLayout, LinearSrgb, OpaqueColor, Srgb, XyzD65};
+
+/// 🌌 The HCT color space of Material Design.
+///
+/// Its components are `[h, C, T]` with
+/// - `h` - the hue angle in degrees, as in [CAM16](crate::cam16);
+/// - `C` - the chroma, as in CAM16, the natural lower bound of 0 being achromatic, reaching about
+///   113 for the red of sRGB; and
+/// - `T` - the tone, the lightness `L*` of [CIELAB](crate::Lab), with a natural bound between 0
+///   and 100.
+///
+/// The appearance is modeled in [the viewing conditions of HCT](ViewingConditions::HCT). In
+/// these, the observer is not completely adapted to the white point, so white has a chroma of
+/// about 2.9.
+///
+/// Converting to other color spaces finds the color with exactly the given components, which
+/// may be outside of any displayable gamut. Use [`Hct::solve`] to find a displayable color.
+#[derive(Clone, Copy, Debug)]
+pub struct Hct;
+
+impl Hct {
+    /// Find the sRGB color closest to the given hue, chroma and tone.
+    ///
+    /// If the requested color is out of the sRGB gamut, this keeps the hue and tone and finds the
+    /// highest chroma in gamut. The result has the requested tone, except for tones very close
+    /// to black and white, where no color of the requested hue exists.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color::hct::Hct;
+    /// use color::{ColorSpace, Srgb};
+    ///
+    /// // There is no sRGB color with this much chroma at this tone.
+    /// let solved = Hct::solve(120., 200., 50.);
+    /// let [_, chroma, tone] = Srgb::convert::<Hct>(solved.components);
+    /// assert!(chroma < 200.);
+    /// assert!((tone - 50.).abs() < 0.1);
+    /// ```
+    #[must_use]
+    pub fn solve(hue: f32, chroma: f32, tone: f32) -> OpaqueColor<Srgb> {
+        /// The precision of the chroma search.
+        const CHROMA_EPSILON: f32 = 1e-3;
+
+        if tone <= 0. {
+            return OpaqueColor::BLACK;
+        }
+        if tone >= 100. {
+            return OpaqueColor::WHITE;
+        }
+        let in_gamut = |chroma: f32| {
+            let rgb = Self::to_linear_srgb([hue, chroma, tone]);
+            LinearSrgb::is_in_gamut(rgb, 0.).then_some(rgb)
+        };
+
+        let rgb = if let Some(rgb) = in_gamut(chroma) {
+            rgb
+        } else {
+            // Bisect for the highest chroma in gamut. Chroma 0 is not necessarily in gamut, as
+            // it is not exactly gray.
+            let mut low = 0.;
+            let mut high = chroma.max(0.);
+            let mut best = None;
+            while high - low > CHROMA_EPSILON {
+                let mid = 0.5 * (low + high);
+                if let Some(rgb) = in_gamut(mid) {
+                    low = mid;
+                    best = Some(rgb);
+                } else {
+                    high = mid;
+                }
+            }
+            best.unwrap_or_else(|| LinearSrgb::clip(Self::to_linear_srgb([hue, low, tone])))
+        };
+        OpaqueColor::new(LinearSrgb::convert::<Srgb>(rgb))
+    }
+}
+
+impl ColorSpace for Hct {
+    const LAYOUT: ColorSpaceLayout = ColorSpaceLayout::HueFirst;
+
+    const WHITE_COMPONENTS: [f32; 3] = [209.542_94, 2.871_589, 100.];
+
+    fn to_linear_srgb([hue, chroma, tone]: [f32; 3]) -> [f32; 3] {
+        /// The maximum number of Newton iterations for finding the CAM16 lightness.
+        const MAX_ITERATIONS: usize = 8;
+
+        let conditions = ViewingConditions::HCT;
+        let y = y_from_lstar(tone);
+        if y <= 0. {
+            return [0.; 3];
+        }
+
+        // Find the CAM16 lightness `J` giving the luminance of the tone. The luminance is about
+        // proportional to the square of `J`, which is used as the derivative.
+        let mut j = tone;
+        let mut xyz = [0.; 3];
+        for _ in 0..MAX_ITERATIONS {
+            xyz = Cam16::from_jch(j, chroma, hue, &conditions).to_xyz(&conditions);
+            let error = xyz[1] - y;
+            if error.abs() <= 1e-6 * y || xyz[1] <= 0. {
+                break;
+            }
+            j -= error * j / (2. * xyz[1]);
+        }
+        XyzD65::to_linear_srgb(xyz)
+    }
+
+    fn from_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        let xyz = XyzD65::from_linear_srgb(src);
+        let cam = Cam16::from_xyz(xyz, &ViewingConditions::HCT);
+        [cam.hue, cam.chroma, lstar_from_y(xyz[1])]
+    }
+
+    fn scale_chroma([h, c, t]: [f32; 3], scale: f32) -> [f32; 3] {
+        [h, c * scale, t]
+    }
+
+    fn clip([h, c, t]: [f32; 3]) -> [f32; 3] {
+        [h, c.max(0.), t.clamp(0., 100.)]
+    }
+}
+
+/// The tones of a key color.
+///
+/// A tonal palette keeps the hue and chroma of a key color in [`Hct`], and varies the tone from 0
+/// (black) to 100 (white). Material Design builds color schemes from such palettes, commonly
+/// using tones 0, 10, 20, 30, 40, 50, 60, 70, 80, 90, 95, 99 and 100.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TonalPalette {
+    hue: f32,
+    chroma: f32,
+}
+
+impl TonalPalette {
+    /// Create a tonal palette of the given hue and chroma.
+    #[must_use]
+    pub const fn new(hue: f32, chroma: f32) -> Self {
+        Self { hue, chroma }
+    }
+
+    /// Create a tonal palette from the hue and chroma of a key color.
+    #[must_use]
+    pub fn from_color<CS: ColorSpace>(key_color: OpaqueColor<CS>) -> Self {
+        let [hue, chroma, _] = key_color.convert::<Hct>().components;
+        Self::new(hue, chroma)
+    }
+
+    /// The hue of the palette.
+    #[must_use]
+    pub const fn hue(&self) -> f32 {
+        self.hue
+    }
+
+    /// The chroma of the palette.
+    #[must_use]
+    pub const fn chroma(&self) -> f32 {
+        self.chroma
+    }
+
+    /// The sRGB color of the given tone, between 0 and 100.
+    ///
+    /// If the chroma of the palette cannot be reached at this tone, the color has the highest
+    /// chroma possible. See [`Hct::solve`].
+    #[must_use]
+    pub fn tone(&self, tone: f32) -> OpaqueColor<Srgb> {
+        Hct::solve(self.hue, self.chroma, tone)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Hct, TonalPalette};
+    use crate::{ColorSpace, OpaqueColor, Srgb};
+
+    #[test]
+    fn reference_values() {
+        // Values from the tests of Material Color Utilities.
+        for (rgb, expected) in [
+            ([255, 0, 0], [27.408, 113.357, 53.237]),
+            ([0, 255, 0], [142.139, 108.410, 87.737]),
+        ] {
+            let [r, g, b] = rgb;
+            let actual = OpaqueColor::from_rgb8(r, g, b).convert::<Hct>().components;
+            for (actual, expected) in actual.into_iter().zip(expected) {
+                assert!(
+                    (actual - expected).abs() < 0.01,
+                    "expected {expected}, got {actual}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn roundtrip() {
+        for hct in [
+            [27.4, 113., 53.2],
+            [282.8, 87., 32.3],
+            [120., 10., 50.],
+            [300., 40., 80.],
+            [10., 5., 20.],
+        ] {
+            let roundtripped = Srgb::convert::<Hct>(Hct::convert::<Srgb>(hct));
+            assert!(
+                OpaqueColor::<Hct>::new(hct).difference(OpaqueColor::new(roundtripped)) < 1e-2,
+                "expected {hct:?}, got {roundtripped:?}"
+            );
+        }
+        let white = Srgb::convert::<Hct>([1., 1., 1.]);
+        assert!(
+            OpaqueColor::<Hct>::new(white).difference(OpaqueColor::new(Hct::WHITE_COMPONENTS))
+                < 1e-3
+        );
+    }
+
+    #[test]
+    fn solve() {
+        // In gamut colors are found exactly.
+        let red = Hct::solve(27.408, 113.357, 53.237);
+        assert!(red.difference(OpaqueColor::new([1., 0., 0.])) < 1e-3);
+
+        // Out of gamut colors keep their hue and tone.
+        for (hue, tone) in [(0., 50.), (90., 90.), (200., 10.), (280., 60.)] {
+            let solved = Hct::solve(hue, 150., tone);
+            assert!(solved.components.iter().all(|x| (0. ..=1.).contains(x)));
+            let [h, c, t] = solved.convert::<Hct>().components;
+            assert!(c < 150.);
+            assert!((t - tone).abs() < 0.5, "expected tone {tone}, got {t}");
+            let hue_difference = (h - hue + 180.).rem_euclid(360.) - 180.;
+            assert!(hue_difference.abs() < 1., "expected hue {hue}, got {h}");
+        }
+
+        assert_eq!(Hct::solve(100., 50., 0.), OpaqueColor::BLACK);
+        assert_eq!(Hct::solve(100., 50., 100.), OpaqueColor::WHITE);
+    }
+
+    #[test]
+    fn tonal_palette() {
+        let palette = TonalPalette::from_color(OpaqueColor::<Srgb>::new([0., 0., 1.]));
+        assert!((palette.hue() - 282.8).abs() < 0.1);
+        let mut previous = 0.;
+        for tone in [
+            0., 10., 20., 30., 40., 50., 60., 70., 80., 90., 95., 99., 100.,
+        ] {
+            let [_, _, t] = palette.tone(tone).convert::<Hct>().components;
+            assert!((t - tone).abs() < 0.5, "expected tone {tone}, got {t}");
+            assert!(t >= previous);
+            previous = t;
+        }
+    }
+}
@@ -0,0 +1,1286 @@
+// Copyright 2025 the Color Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Reading and writing ICC color profiles.
+//!
+//! Images often embed an [ICC profile][icc] describing the color space of their pixels. This
+//! module reads matrix/TRC profiles, the kind of profile describing most RGB displays and image
+//! color spaces, and grayscale profiles. Their color space is returned as a [`RgbSpaceDef`],
+//! which converts colors to the color spaces of this crate.
+//!
+//! Profiles of versions 2 and 4 are supported. Profiles based on lookup tables (the `A2B` and
+//! `B2A` tags), as used for printers and many camera profiles, are not supported.
+//!
+//! In the other direction, [`write_profile`] writes a version 4 matrix/TRC profile for the
+//! built-in RGB color spaces, such as [`DisplayP3`](crate::DisplayP3), to embed in images.
+//! [`write_rgb_profile`] does the same for a [`RgbSpaceDef`].
+//!
+//! This module is available with the `icc` feature.
+//!
+//! # Examples
+//!
+//! Writing a profile:
+//!
+//! ```rust
+//! use color::DisplayP3;
+//! use color::icc::{IccProfile, write_profile};
+//!
+//! let mut buf = [0; 1024];
+//! let len = write_profile::<DisplayP3>(&mut buf).unwrap();
+//! let profile = IccProfile::parse(&buf[..len]).unwrap();
+//! assert_eq!(profile.description().unwrap().to_string(), "Display P3");
+//! ```
+//!
+//! Reading a profile:
+//!
+//! ```rust
+//! use color::icc::IccProfile;
+//! use color::{ColorSpaceTag, DynamicColor};
+//!
+//! fn to_xyz_d50(profile_data: &[u8], rgb: [f32; 3]) -> Option<DynamicColor> {
+//!     let profile = IccProfile::parse(profile_data).ok()?;
+//!     let space = profile.rgb_space().ok()?;
+//!     let [r, g, b] = rgb;
+//!     let color = DynamicColor::from_rgb_space(&space, [r, g, b, 1.]);
+//!     Some(color.convert(ColorSpaceTag::XyzD50))
+//! }
+//! ```
+//!
+//! [icc]: https://www.color.org/specification/ICC.1-2022-05.pdf
+
+use core::error::Error;
+use core::fmt;
+
+use crate::{
+    AdaptationMethod, Chromaticity, ColorSpace, ColorSpaceTag, Rec2020, RgbSpaceDef,
+    TransferFunction, XyzD65, matinv, matmatmul, matvecmul,
+};
+
+#[cfg(all(not(feature = "std"), not(test)))]
+use crate::floatfuncs::FloatFuncs;
+
+/// The size of the profile header.
+const HEADER_SIZE: usize = 128;
+
+/// The D50 white point of the profile connection space, as encoded in profiles.
+const PCS_ILLUMINANT: [f32; 3] = [0.9642, 1., 0.8249];
+
+/// The text of the copyright tag of written profiles.
+const COPYRIGHT_TEXT: &str = "CC0";
+
+/// The largest error of a sampled curve approximated by a parametric curve.
+const CURVE_TOLERANCE: f32 = 2e-3;
+
+/// An error reading or writing an ICC profile.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum IccError {
+    /// The data is shorter than its header, tag table or tags claim.
+    Truncated,
+    /// The data does not have the signature of an ICC profile.
+    InvalidSignature,
+    /// The major version of the profile is not 2 or 4.
+    UnsupportedVersion(u8),
+    /// The color space of the profile is not RGB or gray.
+    UnsupportedColorSpace([u8; 4]),
+    /// The profile connection space is not XYZ.
+    UnsupportedConnectionSpace([u8; 4]),
+    /// The profile is based on lookup tables, instead of a matrix and transfer curves.
+    LutBased,
+    /// A required tag is missing.
+    MissingTag([u8; 4]),
+    /// A tag has an unexpected type or invalid contents.
+    InvalidTag([u8; 4]),
+    /// A sampled transfer curve is not close to any parametric curve.
+    UnsupportedCurve([u8; 4]),
+    /// The transfer curves of the red, green and blue channels differ.
+    MismatchedCurves,
+    /// The color space to write is not an RGB color space.
+    NotRgbSpace,
+    /// The transfer function to write cannot be represented by a parametric curve.
+    UnsupportedTransferFunction,
+    /// The buffer to write to is too small, with the given size required.
+    BufferTooSmall(usize),
+}
+
+/// Write a signature, such as that of a tag, for display.
+fn write_signature(f: &mut fmt::Formatter<'_>, signature: [u8; 4]) -> fmt::Result {
+    match core::str::from_utf8(&signature) {
+        Ok(signature) => write!(f, "`{}`", signature.trim_end()),
+        Err(_) => write!(f, "{signature:02x?}"),
+    }
+}
+
+impl fmt::Display for IccError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Truncated => f.write_str("truncated profile"),
+            Self::InvalidSignature => f.write_str("not an ICC profile"),
+            Self::UnsupportedVersion(version) => write!(f, "unsupported version {version}"),
+            Self::UnsupportedColorSpace(signature) => {
+                f.write_str("unsupported color space ")?;
+                write_signature(f, signature)
+            }
+            Self::UnsupportedConnectionSpace(signature) => {
+                f.write_str("unsupported profile connection space ")?;
+                write_signature(f, signature)
+            }
+            Self::LutBased => f.write_str("unsupported profile based on lookup tables"),
+            Self::MissingTag(signature) => {
+                f.write_str("missing tag ")?;
+                write_signature(f, signature)
+            }
+            Self::InvalidTag(signature) => {
+                f.write_str("invalid tag ")?;
+                write_signature(f, signature)
+            }
+            Self::UnsupportedCurve(signature) => {
+                f.write_str("unsupported sampled curve in tag ")?;
+                write_signature(f, signature)
+            }
+            Self::MismatchedCurves => f.write_str("mismatched channel curves"),
+            Self::NotRgbSpace => f.write_str("not an RGB color space"),
+            Self::UnsupportedTransferFunction => f.write_str("unsupported transfer function"),
+            Self::BufferTooSmall(size) => write!(f, "buffer too small, {size} bytes required"),
+        }
+    }
+}
+
+impl Error for IccError {}
+
+/// The color space of the colors described by an ICC profile.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum IccColorSpace {
+    /// Colors with red, green and blue components.
+    Rgb,
+    /// Colors with a single gray component.
+    Gray,
+}
+
+/// An ICC profile, borrowing its data.
+///
+/// Parsing only checks the header and tag table. The color space is read by
+/// [`IccProfile::rgb_space`].
+#[derive(Clone, Copy, Debug)]
+pub struct IccProfile<'a> {
+    data: &'a [u8],
+    color_space: IccColorSpace,
+    tag_count: usize,
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Option<u16> {
+    let bytes = data.get(offset..offset + 2)?;
+    Some(u16::from_be_bytes([bytes[0], bytes[1]]))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes = data.get(offset..offset + 4)?;
+    Some(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+fn read_signature(data: &[u8], offset: usize) -> Option<[u8; 4]> {
+    data.get(offset..offset + 4)?.try_into().ok()
+}
+
+/// Read an `s15Fixed16Number`.
+#[expect(
+    clippy::cast_possible_wrap,
+    reason = "the number is stored in two's complement"
+)]
+fn read_s15_fixed16(data: &[u8], offset: usize) -> Option<f32> {
+    Some(read_u32(data, offset)? as i32 as f32 * (1. / 65536.))
+}
+
+impl<'a> IccProfile<'a> {
+    /// The signature of the red colorant tag.
+    pub const RED_COLORANT: [u8; 4] = *b"rXYZ";
+    /// The signature of the green colorant tag.
+    pub const GREEN_COLORANT: [u8; 4] = *b"gXYZ";
+    /// The signature of the blue colorant tag.
+    pub const BLUE_COLORANT: [u8; 4] = *b"bXYZ";
+    /// The signature of the red transfer curve tag.
+    pub const RED_TRC: [u8; 4] = *b"rTRC";
+    /// The signature of the green transfer curve tag.
+    pub const GREEN_TRC: [u8; 4] = *b"gTRC";
+    /// The signature of the blue transfer curve tag.
+    pub const BLUE_TRC: [u8; 4] = *b"bTRC";
+    /// The signature of the gray transfer curve tag.
+    pub const GRAY_TRC: [u8; 4] = *b"kTRC";
+    /// The signature of the media white point tag.
+    pub const MEDIA_WHITE_POINT: [u8; 4] = *b"wtpt";
+    /// The signature of the chromatic adaptation tag.
+    pub const CHROMATIC_ADAPTATION: [u8; 4] = *b"chad";
+    /// The signature of the profile description tag.
+    pub const DESCRIPTION: [u8; 4] = *b"desc";
+    /// The signature of the copyright tag.
+    pub const COPYRIGHT: [u8; 4] = *b"cprt";
+
+    /// Parse the header and tag table of an ICC profile.
+    pub fn parse(data: &'a [u8]) -> Result<Self, IccError> {
+        if data.len() < HEADER_SIZE + 4 {
+            return Err(IccError::Truncated);
+        }
+        if data[36..40] != *b"acsp" {
+            return Err(IccError::InvalidSignature);
+        }
+        let size = read_u32(data, 0).ok_or(IccError::Truncated)? as usize;
+        let data = data.get(..size).ok_or(IccError::Truncated)?;
+        if data.len() < HEADER_SIZE + 4 {
+            return Err(IccError::Truncated);
+        }
+        let version = data[8];
+        if version != 2 && version != 4 {
+            return Err(IccError::UnsupportedVersion(version));
+        }
+        let color_space = match read_signature(data, 16).ok_or(IccError::Truncated)? {
+            [b'R', b'G', b'B', b' '] => IccColorSpace::Rgb,
+            [b'G', b'R', b'A', b'Y'] => IccColorSpace::Gray,
+            signature => return Err(IccError::UnsupportedColorSpace(signature)),
+        };
+
+        let tag_count = read_u32(data, HEADER_SIZE).ok_or(IccError::Truncated)? as usize;
+        if tag_count > (data.len() - HEADER_SIZE - 4) / 12 {
+            return Err(IccError::Truncated);
+        }
+        let profile = Self {
+            data,
+            color_space,
+            tag_count,
+        };
+        for index in 0..tag_count {
+            let (_, offset, size) = profile.tag_entry(index);
+            if offset.checked_add(size).is_none_or(|end| end > data.len()) {
+                return Err(IccError::Truncated);
+            }
+        }
+        Ok(profile)
+    }
+
+    /// The signature, offset and size of a tag in the tag table.
+    fn tag_entry(&self, index: usize) -> ([u8; 4], usize, usize) {
+        let entry = HEADER_SIZE + 4 + 12 * index;
+        // The tag table is checked to be in bounds when parsing.
+        let signature = read_signature(self.data, entry).unwrap_or_default();
+        let offset = read_u32(self.data, entry + 4).unwrap_or_default() as usize;
+        let size = read_u32(self.data, entry + 8).unwrap_or_default() as usize;
+        (signature, offset, size)
+    }
+
+    /// The version of the profile, as major and minor version.
+    #[must_use]
+    pub fn version(&self) -> (u8, u8) {
+        (self.data[8], self.data[9] >> 4)
+    }
+
+    /// The color space of the colors described by the profile.
+    #[must_use]
+    pub fn color_space(&self) -> IccColorSpace {
+        self.color_space
+    }
+
+    /// The data of the tag with the given signature, if present.
+    ///
+    /// The data starts with the signature of the tag's type.
+    #[must_use]
+    pub fn tag(&self, signature: [u8; 4]) -> Option<&'a [u8]> {
+        (0..self.tag_count).find_map(|index| {
+            let (tag, offset, size) = self.tag_entry(index);
+            (tag == signature).then(|| &self.data[offset..offset + size])
+        })
+    }
+
+    /// The description of the profile, if present.
+    ///
+    /// Of descriptions in several languages, the English one is preferred.
+    #[must_use]
+    pub fn description(&self) -> Option<IccText<'a>> {
+        IccText::parse(self.tag(Self::DESCRIPTION)?)
+    }
+
+    /// The definition of the color space described by the profile.
+    ///
+    /// For gray profiles, the gray component `k` corresponds to the RGB components `[k, k, k]`
+    /// of the returned color space.
+    ///
+    /// The colorants and white point of the profile are adapted to the D50 white point of the
+    /// profile connection space. They are adapted back using the chromatic adaptation (`chad`)
+    /// tag or, for version 2 profiles without it, the media white point (`wtpt`) tag.
+    pub fn rgb_space(&self) -> Result<RgbSpaceDef, IccError> {
+        let is_lut_based = self.tag(*b"A2B0").is_some() || self.tag(*b"B2A0").is_some();
+        let missing = |signature| {
+            if is_lut_based {
+                IccError::LutBased
+            } else {
+                IccError::MissingTag(signature)
+            }
+        };
+        let connection_space = read_signature(self.data, 20).ok_or(IccError::Truncated)?;
+        if connection_space != *b"XYZ " {
+            return Err(if is_lut_based {
+                IccError::LutBased
+            } else {
+                IccError::UnsupportedConnectionSpace(connection_space)
+            });
+        }
+
+        // The matrix adapting from the white point of the connection space to the white point of
+        // the profile.
+        let illuminant = [
+            read_s15_fixed16(self.data, 68),
+            read_s15_fixed16(self.data, 72),
+            read_s15_fixed16(self.data, 76),
+        ];
+        let illuminant = match illuminant {
+            [Some(x), Some(y), Some(z)] if y > 0. => [x, y, z],
+            _ => PCS_ILLUMINANT,
+        };
+        let adaptation = if let Some(chad) = self.tag(Self::CHROMATIC_ADAPTATION) {
+            matinv(&parse_sf32_matrix(chad).ok_or(IccError::InvalidTag(*b"chad"))?)
+        } else if let (Some(wtpt), (2, _)) = (self.tag(Self::MEDIA_WHITE_POINT), self.version()) {
+            let white = parse_xyz(wtpt).ok_or(IccError::InvalidTag(*b"wtpt"))?;
+            Chromaticity::from_xyz(illuminant).chromatic_adaptation_matrix(
+                Chromaticity::from_xyz(white),
+                AdaptationMethod::Bradford,
+                1.,
+            )
+        } else {
+            [[1., 0., 0.], [0., 1., 0.], [0., 0., 1.]]
+        };
+        let white = Chromaticity::from_xyz(matvecmul(&adaptation, illuminant));
+
+        let trc = |signature| {
+            let data = self.tag(signature).ok_or_else(|| missing(signature))?;
+            parse_curve(data, signature)
+        };
+        match self.color_space {
+            IccColorSpace::Rgb => {
+                let colorant = |signature| {
+                    let data = self.tag(signature).ok_or_else(|| missing(signature))?;
+                    let xyz = parse_xyz(data).ok_or(IccError::InvalidTag(signature))?;
+                    Ok(Chromaticity::from_xyz(matvecmul(&adaptation, xyz)))
+                };
+                let red = colorant(Self::RED_COLORANT)?;
+                let green = colorant(Self::GREEN_COLORANT)?;
+                let blue = colorant(Self::BLUE_COLORANT)?;
+                let transfer = trc(Self::RED_TRC)?;
+                if trc(Self::GREEN_TRC)? != transfer || trc(Self::BLUE_TRC)? != transfer {
+                    return Err(IccError::MismatchedCurves);
+                }
+                Ok(RgbSpaceDef::new(red, green, blue, white, transfer))
+            }
+            IccColorSpace::Gray => {
+                // Any primaries reproduce the white point for equal components.
+                let transfer = trc(Self::GRAY_TRC)?;
+                Ok(RgbSpaceDef::new(
+                    Chromaticity { x: 0.64, y: 0.33 },
+                    Chromaticity { x: 0.30, y: 0.60 },
+                    Chromaticity { x: 0.15, y: 0.06 },
+                    white,
+                    transfer,
+                ))
+            }
+        }
+    }
+}
+
+/// The text of a tag, such as the profile description.
+///
+/// Use the [`Display`](fmt::Display) implementation to get the text.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IccText<'a>(IccTextInner<'a>);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum IccTextInner<'a> {
+    /// ASCII text, as in the `textDescriptionType` of version 2 profiles.
+    Ascii(&'a [u8]),
+    /// Big-endian UTF-16 text, as in the `multiLocalizedUnicodeType` of version 4 profiles.
+    Utf16(&'a [u8]),
+}
+
+impl<'a> IccText<'a> {
+    /// Parse a `textDescriptionType`, `multiLocalizedUnicodeType` or `textType` tag.
+    fn parse(data: &'a [u8]) -> Option<Self> {
+        match read_signature(data, 0)? {
+            [b'd', b'e', b's', b'c'] => {
+                let count = read_u32(data, 8)? as usize;
+                let text = data.get(12..count.checked_add(12)?)?;
+                Some(Self(IccTextInner::Ascii(text)))
+            }
+            [b't', b'e', b'x', b't'] => Some(Self(IccTextInner::Ascii(data.get(8..)?))),
+            [b'm', b'l', b'u', b'c'] => {
+                let record_size = read_u32(data, 12)? as usize;
+                if record_size < 12 {
+                    return None;
+                }
+                // Records past the end of the tag can't be read anyway.
+                let count = (read_u32(data, 8)? as usize).min(data.len() / record_size);
+                let record = |index: usize| {
+                    let offset = index.checked_mul(record_size)?.checked_add(16)?;
+                    let language = data.get(offset..offset + 2)?;
+                    let length = read_u32(data, offset + 4)? as usize;
+                    let start = read_u32(data, offset + 8)? as usize;
+                    Some((language, data.get(start..start.checked_add(length)?)?))
+                };
+                let (_, text) = (0..count)
+                    .filter_map(record)
+                    .find(|(language, _)| *language == b"en")
+                    .or_else(|| record(0))?;
+                Some(Self(IccTextInner::Utf16(text)))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for IccText<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use fmt::Write;
+
+        match self.0 {
+            IccTextInner::Ascii(text) => {
+                // The text is terminated by a null byte.
+                for &byte in text.iter().take_while(|&&byte| byte != 0) {
+                    let c = if byte.is_ascii() {
+                        char::from(byte)
+                    } else {
+                        char::REPLACEMENT_CHARACTER
+                    };
+                    f.write_char(c)?;
+                }
+            }
+            IccTextInner::Utf16(text) => {
+                let units = text
+                    .chunks_exact(2)
+                    .map(|bytes| u16::from_be_bytes([bytes[0], bytes[1]]));
+                for c in char::decode_utf16(units).take_while(|c| *c != Ok('\0')) {
+                    f.write_char(c.unwrap_or(char::REPLACEMENT_CHARACTER))?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parse an `XYZType` tag with a single XYZ value.
+fn parse_xyz(data: &[u8]) -> Option<[f32; 3]> {
+    if read_signature(data, 0)? != *b"XYZ " {
+        return None;
+    }
+    Some([
+        read_s15_fixed16(data, 8)?,
+        read_s15_fixed16(data, 12)?,
+        read_s15_fixed16(data, 16)?,
+    ])
+}
+
+/// Parse an `s15Fixed16ArrayType` tag holding a 3x3 matrix in row-major order.
+fn parse_sf32_matrix(data: &[u8]) -> Option<[[f32; 3]; 3]> {
+    if read_signature(data, 0)? != *b"sf32" {
+        return None;
+    }
+    let mut matrix = [[0.; 3]; 3];
+    for (index, value) in matrix.as_flattened_mut().iter_mut().enumerate() {
+        *value = read_s15_fixed16(data, 8 + 4 * index)?;
+    }
+    Some(matrix)
+}
+
+/// Parse a `curveType` or `parametricCurveType` tag.
+fn parse_curve(data: &[u8], signature: [u8; 4]) -> Result<TransferFunction, IccError> {
+    let invalid = IccError::InvalidTag(signature);
+    match read_signature(data, 0).ok_or(invalid)? {
+        [b'c', b'u', b'r', b'v'] => {
+            let count = read_u32(data, 8).ok_or(invalid)? as usize;
+            match count {
+                0 => Ok(TransferFunction::Linear),
+                1 => {
+                    let gamma = read_u16(data, 12).ok_or(invalid)?;
+                    Ok(TransferFunction::Gamma(f32::from(gamma) * (1. / 256.)))
+                }
+                _ => {
+                    let end = count
+                        .checked_mul(2)
+                        .and_then(|len| len.checked_add(12))
+                        .ok_or(IccError::Truncated)?;
+                    let table = data.get(12..end).ok_or(invalid)?;
+                    approximate_sampled_curve(table).ok_or(IccError::UnsupportedCurve(signature))
+                }
+            }
+        }
+        [b'p', b'a', b'r', b'a'] => {
+            let function = read_u16(data, 8).ok_or(invalid)?;
+            let mut params = [0.; 7];
+            let count = match function {
+                0 => 1,
+                1 => 3,
+                2 => 4,
+                3 => 5,
+                4 => 7,
+                _ => return Err(invalid),
+            };
+            for (index, param) in params[..count].iter_mut().enumerate() {
+                *param = read_s15_fixed16(data, 12 + 4 * index).ok_or(invalid)?;
+            }
+            let [g, a, b, c, d, e, f] = params;
+            Ok(match function {
+                0 => TransferFunction::Gamma(g),
+                // The curve starts where `a * X + b` is 0, and is constant below that.
+                1 => TransferFunction::Parametric {
+                    g,
+                    a,
+                    b,
+                    c: 0.,
+                    d: -b / a,
+                    e: 0.,
+                    f: 0.,
+                },
+                2 => TransferFunction::Parametric {
+                    g,
+                    a,
+                    b,
+                    c: 0.,
+                    d: -b / a,
+                    e: c,
+                    f: c,
+                },
+                _ => TransferFunction::Parametric {
+                    g,
+                    a,
+                    b,
+                    c,
+                    d,
+                    e,
+                    f,
+                },
+            })
+        }
+        _ => Err(invalid),
+    }
+}
+
+/// Approximate a sampled curve of big-endian 16-bit values by a parametric curve.
+///
+/// The curve is compared to the transfer functions of sRGB and Rec. 709, and to the closest pure
+/// gamma curve.
+#[expect(
+    clippy::cast_precision_loss,
+    reason = "the number of samples is far below the precision of f32"
+)]
+fn approximate_sampled_curve(table: &[u8]) -> Option<TransferFunction> {
+    let count = table.len() / 2;
+    let samples = || {
+        table
+            .chunks_exact(2)
+            .enumerate()
+            .map(move |(index, bytes)| {
+                let x = index as f32 / (count - 1) as f32;
+                let y = f32::from(u16::from_be_bytes([bytes[0], bytes[1]])) * (1. / 65535.);
+                (x, y)
+            })
+    };
+    let max_error = |transfer: TransferFunction| {
+        samples()
+            .map(|(x, y)| (transfer.to_linear(x) - y).abs())
+            .fold(0., f32::max)
+    };
+
+    // Fit a gamma by least squares in log-log space, ignoring the samples close to black.
+    let (numerator, denominator) = samples().filter(|&(x, y)| x > 0.01 && y > 1e-4).fold(
+        (0., 0.),
+        |(numerator, denominator), (x, y)| {
+            (numerator + x.ln() * y.ln(), denominator + x.ln() * x.ln())
+        },
+    );
+    let gamma = TransferFunction::Gamma(if denominator > 0. {
+        numerator / denominator
+    } else {
+        1.
+    });
+
+    [
+        TransferFunction::SRGB,
+        TransferFunction::REC709,
+        TransferFunction::Linear,
+        gamma,
+    ]
+    .into_iter()
+    .find(|&transfer| max_error(transfer) <= CURVE_TOLERANCE)
+}
+
+/// The description and transfer function of a built-in RGB color space.
+fn builtin_rgb_space(tag: ColorSpaceTag) -> Option<(&'static str, TransferFunction)> {
+    Some(match tag {
+        ColorSpaceTag::Srgb => ("sRGB", TransferFunction::SRGB),
+        ColorSpaceTag::LinearSrgb => ("Linear sRGB", TransferFunction::Linear),
+        ColorSpaceTag::DisplayP3 => ("Display P3", TransferFunction::SRGB),
+        ColorSpaceTag::A98Rgb => ("A98 RGB", TransferFunction::Gamma(563. / 256.)),
+        ColorSpaceTag::ProphotoRgb => (
+            "ProPhoto RGB",
+            TransferFunction::Parametric {
+                g: 1.8,
+                a: 1.,
+                b: 0.,
+                c: 1. / 16.,
+                d: 16. / 512.,
+                e: 0.,
+                f: 0.,
+            },
+        ),
+        ColorSpaceTag::Rec2020 => (
+            "Rec. 2020",
+            TransferFunction::Parametric {
+                g: 1. / 0.45,
+                a: 1. / Rec2020::A,
+                b: (Rec2020::A - 1.) / Rec2020::A,
+                c: 1. / 4.5,
+                d: Rec2020::B * 4.5,
+                e: 0.,
+                f: 0.,
+            },
+        ),
+        ColorSpaceTag::Rec2100Pq => ("Rec. 2100 PQ", TransferFunction::Pq),
+        ColorSpaceTag::Rec2100Hlg => ("Rec. 2100 HLG", TransferFunction::Hlg),
+        ColorSpaceTag::Aces2065_1 => ("ACES2065-1", TransferFunction::Linear),
+        ColorSpaceTag::AcesCg => ("ACEScg", TransferFunction::Linear),
+        _ => return None,
+    })
+}
+
+/// Write a version 4 ICC profile for a built-in RGB color space.
+///
+/// The profile is a compact matrix/TRC display profile, described by the name of the color
+/// space. It is written to the start of `buf`, returning its size. Profiles of the built-in
+/// color spaces are smaller than 512 bytes.
+///
+/// The transfer functions of [`Rec2100Pq`](crate::Rec2100Pq) and
+/// [`Rec2100Hlg`](crate::Rec2100Hlg) cannot be represented by this kind of profile.
+///
+/// # Errors
+///
+/// Returns [`IccError::NotRgbSpace`] for color spaces other than the built-in RGB color spaces,
+/// [`IccError::UnsupportedTransferFunction`] for the PQ and HLG color spaces, and
+/// [`IccError::BufferTooSmall`] if `buf` cannot hold the profile.
+pub fn write_profile<CS: ColorSpace>(buf: &mut [u8]) -> Result<usize, IccError> {
+    let (description, transfer) = CS::TAG
+        .and_then(builtin_rgb_space)
+        .ok_or(IccError::NotRgbSpace)?;
+    let pcs_white = Chromaticity::from_xyz(PCS_ILLUMINANT);
+    let to_pcs =
+        Chromaticity::D65.chromatic_adaptation_matrix(pcs_white, AdaptationMethod::Bradford, 1.);
+    // The transfer functions map 0 and 1 to themselves, so these are also the linear primaries.
+    let colorants = [[1., 0., 0.], [0., 1., 0.], [0., 0., 1.]]
+        .map(|rgb| matvecmul(&to_pcs, CS::convert::<XyzD65>(rgb)));
+    let adaptation =
+        CS::WHITE_POINT.chromatic_adaptation_matrix(pcs_white, AdaptationMethod::Bradford, 1.);
+    write_matrix_trc(colorants, adaptation, transfer, description, buf)
+}
+
+/// Write a version 4 ICC profile for an RGB color space definition.
+///
+/// This is [`write_profile`] for color spaces defined at runtime, with the given description.
+///
+/// # Errors
+///
+/// Returns [`IccError::UnsupportedTransferFunction`] for the [PQ](TransferFunction::Pq) and
+/// [HLG](TransferFunction::Hlg) transfer functions, and [`IccError::BufferTooSmall`] if `buf`
+/// cannot hold the profile.
+pub fn write_rgb_profile(
+    space: &RgbSpaceDef,
+    description: &str,
+    buf: &mut [u8],
+) -> Result<usize, IccError> {
+    let adaptation = space.white().chromatic_adaptation_matrix(
+        Chromaticity::from_xyz(PCS_ILLUMINANT),
+        AdaptationMethod::Bradford,
+        1.,
+    );
+    // The colorants are the columns of the matrix.
+    let colorants = transpose(matmatmul(&adaptation, &space.to_xyz_matrix()));
+    write_matrix_trc(colorants, adaptation, space.transfer(), description, buf)
+}
+
+/// Write a matrix/TRC profile, given the XYZ of the red, green and blue colorants adapted to the
+/// profile connection space, and the adaptation to it.
+fn write_matrix_trc(
+    colorants: [[f32; 3]; 3],
+    adaptation: [[f32; 3]; 3],
+    transfer: TransferFunction,
+    description: &str,
+    buf: &mut [u8],
+) -> Result<usize, IccError> {
+    const TAG_COUNT: usize = 10;
+    let trc_size = match transfer {
+        TransferFunction::Linear => 12,
+        TransferFunction::Gamma(_) => 16,
+        TransferFunction::Parametric { e, f, .. } if e == 0. && f == 0. => 32,
+        TransferFunction::Parametric { .. } => 40,
+        _ => return Err(IccError::UnsupportedTransferFunction),
+    };
+    let tag_sizes = [
+        mluc_size(description),
+        mluc_size(COPYRIGHT_TEXT),
+        20,
+        44,
+        20,
+        20,
+        20,
+        trc_size,
+    ];
+    let size = HEADER_SIZE
+        + 4
+        + 12 * TAG_COUNT
+        + tag_sizes
+            .iter()
+            .map(|size| size.next_multiple_of(4))
+            .sum::<usize>();
+    let buf = buf.get_mut(..size).ok_or(IccError::BufferTooSmall(size))?;
+    buf.fill(0);
+
+    buf[0..4].copy_from_slice(&u32_bytes(size));
+    // Version 4.4.
+    buf[8] = 4;
+    buf[9] = 0x40;
+    buf[12..16].copy_from_slice(b"mntr");
+    buf[16..20].copy_from_slice(b"RGB ");
+    buf[20..24].copy_from_slice(b"XYZ ");
+    // The creation date, 2025-01-01 00:00:00.
+    buf[24..26].copy_from_slice(&2025_u16.to_be_bytes());
+    buf[27] = 1;
+    buf[29] = 1;
+    buf[36..40].copy_from_slice(b"acsp");
+    for (index, x) in PCS_ILLUMINANT.into_iter().enumerate() {
+        buf[68 + 4 * index..72 + 4 * index].copy_from_slice(&s15_fixed16_bytes(x));
+    }
+    buf[HEADER_SIZE..HEADER_SIZE + 4].copy_from_slice(&u32_bytes(TAG_COUNT));
+
+    let mut writer = TagWriter {
+        buf,
+        entry: HEADER_SIZE + 4,
+        offset: HEADER_SIZE + 4 + 12 * TAG_COUNT,
+    };
+    write_mluc(
+        writer.tag(&[IccProfile::DESCRIPTION], tag_sizes[0]),
+        description,
+    );
+    write_mluc(
+        writer.tag(&[IccProfile::COPYRIGHT], tag_sizes[1]),
+        COPYRIGHT_TEXT,
+    );
+    write_xyz(
+        writer.tag(&[IccProfile::MEDIA_WHITE_POINT], 20),
+        PCS_ILLUMINANT,
+    );
+    let chad = writer.tag(&[IccProfile::CHROMATIC_ADAPTATION], 44);
+    chad[0..4].copy_from_slice(b"sf32");
+    for (index, x) in adaptation.as_flattened().iter().enumerate() {
+        chad[8 + 4 * index..12 + 4 * index].copy_from_slice(&s15_fixed16_bytes(*x));
+    }
+    for (signature, xyz) in [
+        IccProfile::RED_COLORANT,
+        IccProfile::GREEN_COLORANT,
+        IccProfile::BLUE_COLORANT,
+    ]
+    .into_iter()
+    .zip(colorants)
+    {
+        write_xyz(writer.tag(&[signature], 20), xyz);
+    }
+    // The channels share their transfer curve.
+    let trc = writer.tag(
+        &[
+            IccProfile::RED_TRC,
+            IccProfile::GREEN_TRC,
+            IccProfile::BLUE_TRC,
+        ],
+        trc_size,
+    );
+    write_curve(trc, transfer);
+    Ok(size)
+}
+
+/// Writes the tag table and data of a profile, in order.
+struct TagWriter<'a> {
+    buf: &'a mut [u8],
+    entry: usize,
+    offset: usize,
+}
+
+impl TagWriter<'_> {
+    /// Add a tag shared by the given signatures, returning its data to write.
+    fn tag(&mut self, signatures: &[[u8; 4]], size: usize) -> &mut [u8] {
+        for signature in signatures {
+            let entry = &mut self.buf[self.entry..self.entry + 12];
+            entry[0..4].copy_from_slice(signature);
+            entry[4..8].copy_from_slice(&u32_bytes(self.offset));
+            entry[8..12].copy_from_slice(&u32_bytes(size));
+            self.entry += 12;
+        }
+        let data = &mut self.buf[self.offset..self.offset + size];
+        self.offset += size.next_multiple_of(4);
+        data
+    }
+}
+
+fn transpose([r, g, b]: [[f32; 3]; 3]) -> [[f32; 3]; 3] {
+    [[r[0], g[0], b[0]], [r[1], g[1], b[1]], [r[2], g[2], b[2]]]
+}
+
+#[expect(
+    clippy::cast_possible_truncation,
+    reason = "written profiles are far smaller than 4 GiB"
+)]
+fn u32_bytes(x: usize) -> [u8; 4] {
+    (x as u32).to_be_bytes()
+}
+
+/// Encode an `s15Fixed16Number`.
+#[expect(
+    clippy::cast_possible_truncation,
+    reason = "out of range values saturate, and are not expected"
+)]
+fn s15_fixed16_bytes(x: f32) -> [u8; 4] {
+    ((x * 65536.).round() as i32).to_be_bytes()
+}
+
+/// The size of a `multiLocalizedUnicodeType` tag with a single record.
+fn mluc_size(text: &str) -> usize {
+    28 + 2 * text.encode_utf16().count()
+}
+
+/// Write a `multiLocalizedUnicodeType` tag with a single English record.
+fn write_mluc(data: &mut [u8], text: &str) {
+    data[0..4].copy_from_slice(b"mluc");
+    data[8..12].copy_from_slice(&u32_bytes(1));
+    data[12..16].copy_from_slice(&u32_bytes(12));
+    data[16..20].copy_from_slice(b"enUS");
+    let length = data.len() - 28;
+    data[20..24].copy_from_slice(&u32_bytes(length));
+    data[24..28].copy_from_slice(&u32_bytes(28));
+    for (unit, bytes) in text.encode_utf16().zip(data[28..].chunks_exact_mut(2)) {
+        bytes.copy_from_slice(&unit.to_be_bytes());
+    }
+}
+
+/// Write an `XYZType` tag with a single XYZ value.
+fn write_xyz(data: &mut [u8], xyz: [f32; 3]) {
+    data[0..4].copy_from_slice(b"XYZ ");
+    for (index, x) in xyz.into_iter().enumerate() {
+        data[8 + 4 * index..12 + 4 * index].copy_from_slice(&s15_fixed16_bytes(x));
+    }
+}
+
+/// Write a `curveType` or `parametricCurveType` tag, sized by [`write_matrix_trc`].
+fn write_curve(data: &mut [u8], transfer: TransferFunction) {
+    let (function, params): (u16, &[f32]) = match transfer {
+        TransferFunction::Parametric { g, a, b, c, d, .. } if data.len() == 32 => {
+            (3, &[g, a, b, c, d])
+        }
+        TransferFunction::Parametric {
+            g,
+            a,
+            b,
+            c,
+            d,
+            e,
+            f,
+        } => (4, &[g, a, b, c, d, e, f]),
+        TransferFunction::Gamma(g) => (0, &[g]),
+        _ => {
+            // An identity curve.
+            data[0..4].copy_from_slice(b"curv");
+            return;
+        }
+    };
+    data[0..4].copy_from_slice(b"para");
+    data[8..10].copy_from_slice(&function.to_be_bytes());
+    for (index, x) in params.iter().enumerate() {
+        data[12 + 4 * index..16 + 4 * index].copy_from_slice(&s15_fixed16_bytes(*x));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use super::{IccColorSpace, IccError, IccProfile, write_profile, write_rgb_profile};
+    use crate::{
+        A98Rgb, Aces2065_1, AcesCg, AdaptationMethod, Chromaticity, ColorSpace, DisplayP3,
+        LinearSrgb, Oklab, OpaqueColor, ProphotoRgb, Rec2020, Rec2100Pq, RgbSpaceDef, Srgb,
+        TransferFunction, XyzD50, matvecmul,
+    };
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
+
+    /// Encode an `s15Fixed16Number`.
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "the test values are in range"
+    )]
+    fn s15_fixed16(x: f32) -> [u8; 4] {
+        ((x * 65536.).round() as i32).to_be_bytes()
+    }
+
+    fn xyz_tag(xyz: [f32; 3]) -> Vec<u8> {
+        let mut tag = b"XYZ \0\0\0\0".to_vec();
+        for x in xyz {
+            tag.extend(s15_fixed16(x));
+        }
+        tag
+    }
+
+    fn sf32_tag(matrix: [[f32; 3]; 3]) -> Vec<u8> {
+        let mut tag = b"sf32\0\0\0\0".to_vec();
+        for x in matrix.as_flattened() {
+            tag.extend(s15_fixed16(*x));
+        }
+        tag
+    }
+
+    fn para_tag(function: u16, params: &[f32]) -> Vec<u8> {
+        let mut tag = b"para\0\0\0\0".to_vec();
+        tag.extend(function.to_be_bytes());
+        tag.extend([0, 0]);
+        for x in params {
+            tag.extend(s15_fixed16(*x));
+        }
+        tag
+    }
+
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "the test values are in range"
+    )]
+    fn curv_tag(samples: &[f32]) -> Vec<u8> {
+        let mut tag = b"curv\0\0\0\0".to_vec();
+        tag.extend((samples.len() as u32).to_be_bytes());
+        for x in samples {
+            tag.extend(((x * 65535.).round() as u16).to_be_bytes());
+        }
+        tag
+    }
+
+    /// Assemble a display profile from its tags.
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "the test profiles are small"
+    )]
+    fn profile(version: u8, color_space: [u8; 4], tags: &[(&[u8; 4], Vec<u8>)]) -> Vec<u8> {
+        let mut header = [0_u8; 128];
+        header[8] = version;
+        header[12..16].copy_from_slice(b"mntr");
+        header[16..20].copy_from_slice(&color_space);
+        header[20..24].copy_from_slice(b"XYZ ");
+        header[36..40].copy_from_slice(b"acsp");
+        for (index, x) in [0.9642, 1., 0.8249].into_iter().enumerate() {
+            header[68 + 4 * index..72 + 4 * index].copy_from_slice(&s15_fixed16(x));
+        }
+        let mut data = header.to_vec();
+        data.extend((tags.len() as u32).to_be_bytes());
+        let mut offset = 132 + 12 * tags.len();
+        for (signature, tag) in tags {
+            data.extend(*signature);
+            data.extend((offset as u32).to_be_bytes());
+            data.extend((tag.len() as u32).to_be_bytes());
+            offset += tag.len().next_multiple_of(4);
+        }
+        for (_, tag) in tags {
+            data.extend(tag);
+            data.resize(data.len().next_multiple_of(4), 0);
+        }
+        let size = data.len() as u32;
+        data[0..4].copy_from_slice(&size.to_be_bytes());
+        data
+    }
+
+    /// The colorant tags of sRGB, adapted to D50.
+    fn srgb_colorants() -> [(&'static [u8; 4], Vec<u8>); 3] {
+        let colorant = |rgb| xyz_tag(LinearSrgb::convert::<XyzD50>(rgb));
+        [
+            (b"rXYZ", colorant([1., 0., 0.])),
+            (b"gXYZ", colorant([0., 1., 0.])),
+            (b"bXYZ", colorant([0., 0., 1.])),
+        ]
+    }
+
+    fn assert_srgb(data: &[u8], epsilon: f32) {
+        let space = IccProfile::parse(data).unwrap().rgb_space().unwrap();
+        for rgb in [
+            [1., 1., 1.],
+            [0.2, 0.6, 0.4],
+            [0.9, 0.1, 0.05],
+            [0.01, 0., 0.02],
+        ] {
+            let actual = space.to_linear_srgb(rgb);
+            let expected = Srgb::to_linear_srgb(rgb);
+            assert!(
+                OpaqueColor::<LinearSrgb>::new(actual).difference(OpaqueColor::new(expected))
+                    < epsilon,
+                "expected {expected:?}, got {actual:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn srgb_v4() {
+        let chad = Chromaticity::D65.chromatic_adaptation_matrix(
+            Chromaticity::D50,
+            AdaptationMethod::Bradford,
+            1.,
+        );
+        let trc = para_tag(3, &[2.4, 1. / 1.055, 0.055 / 1.055, 1. / 12.92, 0.04045]);
+        let mut tags = srgb_colorants().to_vec();
+        tags.extend([
+            (b"wtpt", xyz_tag([0.9642, 1., 0.8249])),
+            (b"chad", sf32_tag(chad)),
+            (b"rTRC", trc.clone()),
+            (b"gTRC", trc.clone()),
+            (b"bTRC", trc),
+        ]);
+        let data = profile(4, *b"RGB ", &tags);
+        let profile = IccProfile::parse(&data).unwrap();
+        assert_eq!(profile.version(), (4, 0));
+        assert_eq!(profile.color_space(), IccColorSpace::Rgb);
+        assert!(profile.tag(*b"chad").is_some());
+        assert!(profile.tag(*b"desc").is_none());
+        assert_srgb(&data, 1e-3);
+    }
+
+    #[test]
+    fn srgb_v2_sampled_curve() {
+        // Version 2 profiles may give the white point by the media white point instead.
+        let samples: Vec<f32> = (0..1024)
+            .map(|i| Srgb::to_linear_srgb([i as f32 / 1023., 0., 0.])[0])
+            .collect();
+        let trc = curv_tag(&samples);
+        let mut tags = srgb_colorants().to_vec();
+        let d65 = Chromaticity::D65.to_xyz();
+        tags.extend([
+            (b"wtpt", xyz_tag(d65)),
+            (b"rTRC", trc.clone()),
+            (b"gTRC", trc.clone()),
+            (b"bTRC", trc),
+        ]);
+        assert_srgb(&profile(2, *b"RGB ", &tags), 1e-3);
+
+        // A sampled curve that is not close to a parametric curve.
+        let samples = [0., 0.5, 0.1, 1.];
+        let mut tags = srgb_colorants().to_vec();
+        tags.extend([
+            (b"rTRC", curv_tag(&samples)),
+            (b"gTRC", curv_tag(&samples)),
+            (b"bTRC", curv_tag(&samples)),
+        ]);
+        let data = profile(2, *b"RGB ", &tags);
+        assert_eq!(
+            IccProfile::parse(&data).unwrap().rgb_space(),
+            Err(IccError::UnsupportedCurve(*b"rTRC"))
+        );
+    }
+
+    #[test]
+    fn gray() {
+        let data = profile(
+            4,
+            *b"GRAY",
+            &[
+                (b"wtpt", xyz_tag([0.9642, 1., 0.8249])),
+                (b"kTRC", curv_tag(&[0.5 * 256. / 65535.])),
+            ],
+        );
+        let profile = IccProfile::parse(&data).unwrap();
+        assert_eq!(profile.color_space(), IccColorSpace::Gray);
+        let space = profile.rgb_space().unwrap();
+        assert_eq!(space.transfer(), TransferFunction::Gamma(0.5));
+        // Gray has the chromaticity of the white point.
+        let xyz = matvecmul(&space.to_xyz_matrix(), [0.25; 3]);
+        assert!((xyz[1] - 0.25).abs() < 1e-5);
+        let white = Chromaticity::from_xyz(xyz);
+        assert!((white.x - 0.3457).abs() < 1e-3 && (white.y - 0.3585).abs() < 1e-3);
+    }
+
+    #[test]
+    fn errors() {
+        let trc = para_tag(0, &[2.2]);
+        let mut tags = srgb_colorants().to_vec();
+        tags.extend([(b"rTRC", trc.clone()), (b"gTRC", trc.clone())]);
+        let data = profile(4, *b"RGB ", &tags);
+        assert_eq!(
+            IccProfile::parse(&data).unwrap().rgb_space(),
+            Err(IccError::MissingTag(*b"bTRC"))
+        );
+
+        tags.push((b"bTRC", para_tag(0, &[1.8])));
+        let data = profile(4, *b"RGB ", &tags);
+        assert_eq!(
+            IccProfile::parse(&data).unwrap().rgb_space(),
+            Err(IccError::MismatchedCurves)
+        );
+
+        let data = profile(4, *b"RGB ", &[(b"A2B0", b"mAB \0\0\0\0".to_vec())]);
+        assert_eq!(
+            IccProfile::parse(&data).unwrap().rgb_space(),
+            Err(IccError::LutBased)
+        );
+
+        let data = profile(4, *b"CMYK", &[]);
+        assert_eq!(
+            IccProfile::parse(&data).unwrap_err(),
+            IccError::UnsupportedColorSpace(*b"CMYK")
+        );
+        assert_eq!(
+            IccProfile::parse(&profile(5, *b"RGB ", &[])).unwrap_err(),
+            IccError::UnsupportedVersion(5)
+        );
+        assert_eq!(
+            IccProfile::parse(&data[..100]).unwrap_err(),
+            IccError::Truncated
+        );
+        let data = profile(4, *b"RGB ", &tags);
+        assert_eq!(
+            IccProfile::parse(&data[..data.len() - 4]).unwrap_err(),
+            IccError::Truncated
+        );
+        assert_eq!(
+            IccProfile::parse(&[0; 200]).unwrap_err(),
+            IccError::InvalidSignature
+        );
+        // The profile size in the header is smaller than the header itself.
+        let mut data = profile(4, *b"RGB ", &tags);
+        data[0..4].copy_from_slice(&8_u32.to_be_bytes());
+        assert_eq!(IccProfile::parse(&data).unwrap_err(), IccError::Truncated);
+
+        let mut curv = b"curv\0\0\0\0".to_vec();
+        curv.extend(u32::MAX.to_be_bytes());
+        curv.extend([0; 8]);
+        let data = profile(
+            4,
+            *b"GRAY",
+            &[(b"wtpt", xyz_tag([0.9642, 1., 0.8249])), (b"kTRC", curv)],
+        );
+        // The table length overflows on 32-bit targets and runs past the tag on others.
+        let error = IccProfile::parse(&data).unwrap().rgb_space().unwrap_err();
+        assert!(
+            error == IccError::InvalidTag(*b"kTRC") || error == IccError::Truncated,
+            "unexpected error {error:?}"
+        );
+
+        let description = |tag: Vec<u8>| {
+            let data = profile(4, *b"RGB ", &[(b"desc", tag)]);
+            IccProfile::parse(&data)
+                .unwrap()
+                .description()
+                .map(|text| text.to_string())
+        };
+        let mluc = |count: u32, length: u32, start: u32| {
+            let mut tag = b"mluc\0\0\0\0".to_vec();
+            for x in [count, 12] {
+                tag.extend(x.to_be_bytes());
+            }
+            tag.extend(b"deDE");
+            for x in [length, start] {
+                tag.extend(x.to_be_bytes());
+            }
+            tag.extend([0, b'H', 0, b'i']);
+            tag
+        };
+
+        let mut desc = b"desc\0\0\0\0".to_vec();
+        desc.extend(u32::MAX.to_be_bytes());
+        desc.extend(b"Hi\0");
+        assert_eq!(description(desc), None);
+
+        assert_eq!(description(mluc(1, 4, 28)).as_deref(), Some("Hi"));
+        // Records past the end of the tag are not read, falling back to the first record.
+        assert_eq!(description(mluc(u32::MAX, 4, 28)).as_deref(), Some("Hi"));
+        assert_eq!(description(mluc(1, 100, 28)), None);
+        assert_eq!(description(mluc(1, u32::MAX, u32::MAX)), None);
+    }
+
+    /// Check that the written profile of a color space reads back as that color space.
+    fn check_written<CS: ColorSpace>(description: &str) {
+        let mut buf = [0; 1024];
+        let len = write_profile::<CS>(&mut buf).unwrap();
+        assert!(len < 512 && len % 4 == 0, "unexpected size {len}");
+        let profile = IccProfile::parse(&buf[..len]).unwrap();
+        assert_eq!(profile.version(), (4, 4));
+        assert_eq!(profile.description().unwrap().to_string(), description);
+        let space = profile.rgb_space().unwrap();
+        for rgb in [
+            [1., 1., 1.],
+            [0.2, 0.6, 0.4],
+            [0.9, 0.1, 0.05],
+            [0.01, 0., 0.02],
+        ] {
+            let actual = space.to_linear_srgb(rgb);
+            let expected = CS::to_linear_srgb(rgb);
+            assert!(
+                OpaqueColor::<LinearSrgb>::new(actual).difference(OpaqueColor::new(expected))
+                    < 1e-4,
+                "expected {expected:?}, got {actual:?} for {description}"
+            );
+        }
+    }
+
+    #[test]
+    fn write_builtin() {
+        check_written::<Srgb>("sRGB");
+        check_written::<LinearSrgb>("Linear sRGB");
+        check_written::<DisplayP3>("Display P3");
+        check_written::<A98Rgb>("A98 RGB");
+        check_written::<ProphotoRgb>("ProPhoto RGB");
+        check_written::<Rec2020>("Rec. 2020");
+        check_written::<Aces2065_1>("ACES2065-1");
+        check_written::<AcesCg>("ACEScg");
+    }
+
+    #[test]
+    fn write_rgb_space() {
+        let space = RgbSpaceDef::new(
+            Chromaticity { x: 0.64, y: 0.33 },
+            Chromaticity { x: 0.21, y: 0.71 },
+            Chromaticity { x: 0.15, y: 0.06 },
+            Chromaticity::D50,
+            TransferFunction::Parametric {
+                g: 2.4,
+                a: 0.95,
+                b: 0.05,
+                c: 0.1,
+                d: 0.04,
+                e: 0.01,
+                f: 0.005,
+            },
+        );
+        let mut buf = [0; 1024];
+        let len = write_rgb_profile(&space, "Ünïcode 🎨", &mut buf).unwrap();
+        let profile = IccProfile::parse(&buf[..len]).unwrap();
+        assert_eq!(profile.description().unwrap().to_string(), "Ünïcode 🎨");
+        let actual = profile.rgb_space().unwrap();
+        for (actual, expected) in [
+            (actual.red(), space.red()),
+            (actual.green(), space.green()),
+            (actual.blue(), space.blue()),
+            (actual.white(), space.white()),
+        ] {
+            assert!((actual.x - expected.x).abs() < 1e-4 && (actual.y - expected.y).abs() < 1e-4);
+        }
+        for x in [0., 0.02, 0.5, 1.] {
+            assert!((actual.transfer().to_linear(x) - space.transfer().to_linear(x)).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn write_errors() {
+        let mut buf = [0; 1024];
+        assert_eq!(write_profile::<Oklab>(&mut buf), Err(IccError::NotRgbSpace));
+        assert_eq!(
+            write_profile::<Rec2100Pq>(&mut buf),
+            Err(IccError::UnsupportedTransferFunction)
+        );
+        let len = write_profile::<Srgb>(&mut buf).unwrap();
+        assert_eq!(
+            write_profile::<Srgb>(&mut buf[..len - 1]),
+            Err(IccError::BufferTooSmall(len))
+        );
+    }
+}
@@ -44,14 +44,16 @@
 //!   * Print color spaces (CMYK).
 //!   * Spectral colors.
 //!   * Color spaces with more than 3 components generally.
-//!   * [ICC] color profiles.
+//!   * [ICC] color profiles, beyond reading and writing the matrix/TRC profiles of RGB and gray
+//!     color spaces (with the `icc` feature).
 //!   * [ACES] color transforms.
-//!   * Appearance models and other color science not needed for rendering.
+//!   * Appearance models other than [CAM16](cam16), and other color science not needed for
+//!     rendering.
 //!   * Quantizing and packing to lower bit depths.
 //!
 //! The [`Rgba8`] and [`PremulRgba8`] types are a partial exception to this last item, as
 //! those representation are ubiquitous and requires special logic for serializing to
-//! maximize compatibility.
+//! maximize compatibility. So are the integer [Y′CbCr](ycbcr) code values of decoded video.
 //!
 //! Some of these capabilities may be added as other crates within the `color` repository,
 //! and we will also facilitate interoperability with other color crates in the Rust
@@ -66,6 +68,7 @@
 //!   [`HueDirection`], [`OpaqueColor`], [`PremulColor`], [`PremulRgba8`], and [`Rgba8`].
 //! - `serde`: Implement `serde::Deserialize` and `serde::Serialize` on [`AlphaColor`],
 //!   [`DynamicColor`], [`OpaqueColor`], [`PremulColor`], [`PremulRgba8`], and [`Rgba8`].
+//! - `icc`: Read and write ICC profiles of RGB color spaces with the `icc` module.
 //!
 //! At least one of `std` and `libm` is required; `std` overrides `libm`.
 //!
@@ -87,17 +90,24 @@
 #![no_std]
 
 pub mod cache_key;
+pub mod cam16;
 mod chromaticity;
 mod color;
 mod colorspace;
+pub mod contrast;
+pub mod delta_e;
 mod dynamic;
 mod flags;
+mod gamut;
 mod gradient;
+pub mod hct;
 pub mod palette;
+mod rgb_space;
 mod rgba8;
 mod serialize;
 mod tag;
 mod x11_colors;
+pub mod ycbcr;
 
 // Note: this may become feature-gated; we'll decide this soon
 // (This line is isolated so that the comment binds to it with import ordering)
@@ -106,20 +116,26 @@ mod parse;
 #[cfg(feature = "bytemuck")]
 mod impl_bytemuck;
 
+#[cfg(feature = "icc")]
+pub mod icc;
+
 #[cfg(all(not(feature = "std"), not(test)))]
 mod floatfuncs;
 
-pub use chromaticity::Chromaticity;
+pub use chromaticity::{AdaptationMethod, Chromaticity};
 pub use color::{AlphaColor, HueDirection, OpaqueColor, PremulColor};
 pub use colorspace::{
-    A98Rgb, Aces2065_1, AcesCg, ColorSpace, ColorSpaceLayout, DisplayP3, Hsl, Hwb, Lab, Lch,
-    LinearSrgb, Oklab, Oklch, ProphotoRgb, Rec2020, Srgb, XyzD50, XyzD65,
+    A98Rgb, Aces2065_1, AcesCg, ColorSpace, ColorSpaceLayout, DisplayP3, Hpluv, Hsl, Hsluv, Hsv,
+    Hwb, ICtCp, JzCzhz, Jzazbz, Lab, Lch, LchUv, LinearSrgb, Luv, Okhsl, Okhsv, Oklab, Oklch,
+    ProphotoRgb, Rec2020, Rec2100Hlg, Rec2100Pq, Srgb, XyzD50, XyzD65,
 };
 pub use dynamic::{DynamicColor, Interpolator, UnpremultipliedInterpolator};
 pub use flags::{Flags, Missing};
 pub use gradient::{GradientIter, UnpremultipliedGradientIter, gradient, gradient_unpremultiplied};
-pub use parse::{ParseError, parse_color, parse_color_prefix};
+pub use parse::{ParseError, ParseErrorKind, parse_color, parse_color_prefix};
+pub use rgb_space::{RgbSpaceDef, TransferFunction};
 pub use rgba8::{PremulRgba8, Rgba8};
+pub use serialize::{CssDisplay, CssSerializer};
 pub use tag::ColorSpaceTag;
 
 const fn u8_to_f32(x: u8) -> f32 {
@@ -156,6 +172,34 @@ const fn matmatmul(ma: &[[f32; 3]; 3], mb: &[[f32; 3]; 3]) -> [[f32; 3]; 3] {
     ]
 }
 
+/// The inverse of a 3x3-matrix `m`.
+///
+/// The matrix must be invertible.
+const fn matinv(m: &[[f32; 3]; 3]) -> [[f32; 3]; 3] {
+    // The adjugate, divided by the determinant.
+    let c00 = m[1][1] * m[2][2] - m[1][2] * m[2][1];
+    let c01 = m[1][2] * m[2][0] - m[1][0] * m[2][2];
+    let c02 = m[1][0] * m[2][1] - m[1][1] * m[2][0];
+    let det_recip = 1. / (m[0][0] * c00 + m[0][1] * c01 + m[0][2] * c02);
+    [
+        [
+            c00 * det_recip,
+            (m[0][2] * m[2][1] - m[0][1] * m[2][2]) * det_recip,
+            (m[0][1] * m[1][2] - m[0][2] * m[1][1]) * det_recip,
+        ],
+        [
+            c01 * det_recip,
+            (m[0][0] * m[2][2] - m[0][2] * m[2][0]) * det_recip,
+            (m[0][2] * m[1][0] - m[0][0] * m[1][2]) * det_recip,
+        ],
+        [
+            c02 * det_recip,
+            (m[0][1] * m[2][0] - m[0][0] * m[2][1]) * det_recip,
+            (m[0][0] * m[1][1] - m[0][1] * m[1][0]) * det_recip,
+        ],
+    ]
+}
+
 /// Multiplication `ma * mb` of a 3x3-matrix `ma` by a 3x3-diagonal matrix `mb`.
 ///
 /// Diagonal matrix `mb` is given by
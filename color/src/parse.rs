@@ -6,21 +6,34 @@
 use core::error::Error;
 use core::f64;
 use core::fmt;
+use core::ops::Range;
 use core::str;
 use core::str::FromStr;
 
 use crate::{
-    AlphaColor, ColorSpace, ColorSpaceTag, DynamicColor, Flags, Missing, OpaqueColor, PremulColor,
-    Srgb,
+    AlphaColor, ColorSpace, ColorSpaceTag, DynamicColor, Flags, HueDirection, Missing, OpaqueColor,
+    PremulColor, Srgb,
 };
 
-// TODO: maybe include string offset
+#[cfg(all(not(feature = "std"), not(test)))]
+use crate::floatfuncs::FloatFuncs;
+
 /// Error type for parse errors.
 ///
-/// Discussion question: should it also contain a string offset?
+/// Besides the [kind](ParseErrorKind) of error, this reports where in the input string the error
+/// occurred, and, where applicable, what was expected there and a suggested correction.
 #[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    kind: ParseErrorKind,
+    span: Range<usize>,
+    expected: &'static [&'static str],
+    suggestion: Option<&'static str>,
+}
+
+/// The kind of a [`ParseError`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
 #[non_exhaustive]
-pub enum ParseError {
+pub enum ParseErrorKind {
     /// Unclosed comment
     UnclosedComment,
     /// Unknown angle dimension
@@ -47,11 +60,82 @@ pub enum ParseError {
     ExpectedEndOfString,
     /// Wrong number of hex digits
     WrongNumberOfHexDigits,
+    /// Invalid math expression
+    InvalidMathExpression,
+    /// Invalid percentage
+    InvalidPercentage,
+    /// Unknown hue interpolation method
+    UnknownHueInterpolationMethod,
+}
+
+impl ParseError {
+    const fn new(kind: ParseErrorKind, span: Range<usize>) -> Self {
+        Self {
+            kind,
+            span,
+            expected: &[],
+            suggestion: None,
+        }
+    }
+
+    const fn with_expected(mut self, expected: &'static [&'static str]) -> Self {
+        self.expected = expected;
+        self
+    }
+
+    /// Shift the span by `offset` bytes, for errors from parsing a substring.
+    fn offset(mut self, offset: usize) -> Self {
+        self.span = self.span.start + offset..self.span.end + offset;
+        self
+    }
+
+    /// The kind of error.
+    #[must_use]
+    pub fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    /// The byte range of the input string where the error occurred.
+    ///
+    /// The range may be empty, for example when the input ended unexpectedly.
+    #[must_use]
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+
+    /// The tokens that were expected at the position of the error.
+    ///
+    /// The tokens are given in the notation of CSS value definitions, such as `<number>`,
+    /// `none`, or `)`. This may be empty if there is no short description of what was expected.
+    #[must_use]
+    pub fn expected(&self) -> &'static [&'static str] {
+        self.expected
+    }
+
+    /// A suggested replacement for the input at the [span](Self::span) of the error.
+    ///
+    /// This is the closest named color for [`ParseErrorKind::UnknownColorIdentifier`] errors
+    /// that look like a misspelled color name.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color::{ParseErrorKind, parse_color};
+    ///
+    /// let err = parse_color("  lightgren").unwrap_err();
+    /// assert_eq!(err.kind(), ParseErrorKind::UnknownColorIdentifier);
+    /// assert_eq!(err.span(), 2..11);
+    /// assert_eq!(err.suggestion(), Some("lightgreen"));
+    /// ```
+    #[must_use]
+    pub fn suggestion(&self) -> Option<&'static str> {
+        self.suggestion
+    }
 }
 
 impl Error for ParseError {}
 
-impl fmt::Display for ParseError {
+impl fmt::Display for ParseErrorKind {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let msg = match *self {
             Self::UnclosedComment => "unclosed comment",
@@ -67,15 +151,121 @@ impl fmt::Display for ParseError {
             Self::ExpectedComma => "expected comma",
             Self::ExpectedEndOfString => "expected end of string",
             Self::WrongNumberOfHexDigits => "wrong number of hex digits",
+            Self::InvalidMathExpression => "invalid math expression",
+            Self::InvalidPercentage => "invalid percentage",
+            Self::UnknownHueInterpolationMethod => "unknown hue interpolation method",
         };
         f.write_str(msg)
     }
 }
 
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at {}..{}", self.kind, self.span.start, self.span.end)?;
+        if let Some((first, rest)) = self.expected.split_first() {
+            write!(f, ", expected {first}")?;
+            for expected in rest {
+                write!(f, " or {expected}")?;
+            }
+        }
+        if let Some(suggestion) = self.suggestion {
+            write!(f, ", did you mean `{suggestion}`?")?;
+        }
+        Ok(())
+    }
+}
+
+/// Tokens expected for a color component.
+const EXPECTED_COMPONENT: &[&str] = &["<number>", "<percentage>", "none"];
+/// Tokens expected for a hue component.
+const EXPECTED_ANGLE: &[&str] = &["<number>", "<angle>", "none"];
+/// Units of angles.
+const EXPECTED_ANGLE_UNIT: &[&str] = &["deg", "grad", "rad", "turn"];
+/// Color spaces of the `color()` function.
+const EXPECTED_PREDEFINED_COLOR_SPACE: &[&str] = &[
+    "srgb",
+    "srgb-linear",
+    "display-p3",
+    "a98-rgb",
+    "prophoto-rgb",
+    "rec2020",
+    "rec2100-pq",
+    "rec2100-hlg",
+    "--jzazbz",
+    "--jzczhz",
+    "--luv",
+    "--lchuv",
+    "--hsluv",
+    "--hpluv",
+    "--hsv",
+    "--okhsl",
+    "--okhsv",
+    "xyz",
+    "xyz-d50",
+    "xyz-d65",
+];
+/// Color spaces that can be named by identifier.
+const EXPECTED_COLOR_SPACE: &[&str] = &[
+    "srgb",
+    "srgb-linear",
+    "lab",
+    "lch",
+    "oklab",
+    "oklch",
+    "display-p3",
+    "a98-rgb",
+    "prophoto-rgb",
+    "rec2020",
+    "rec2100-pq",
+    "rec2100-hlg",
+    "ictcp",
+    "--jzazbz",
+    "--jzczhz",
+    "--luv",
+    "--lchuv",
+    "--hsluv",
+    "--hpluv",
+    "--hsv",
+    "--okhsl",
+    "--okhsv",
+    "hsl",
+    "hwb",
+    "xyz-d50",
+    "xyz",
+    "xyz-d65",
+];
+/// Values inside math functions.
+const EXPECTED_CALC_VALUE: &[&str] = &["<number>", "<percentage>", "<angle>", "("];
+/// Hue interpolation methods.
+const EXPECTED_HUE_INTERPOLATION_METHOD: &[&str] =
+    &["shorter", "longer", "increasing", "decreasing"];
+
 #[derive(Default)]
 struct Parser<'a> {
     s: &'a str,
     ix: usize,
+    /// The channel keywords, when parsing relative color syntax.
+    channels: Option<Channels>,
+}
+
+/// The channel keywords of relative color syntax, bound to the channels of the origin color.
+#[derive(Clone, Copy, Debug)]
+struct Channels {
+    names: [&'static str; 3],
+    /// The values of the channels and alpha, in the units of the color function's numbers.
+    values: [f64; 4],
+}
+
+impl Channels {
+    fn get(&self, keyword: &str) -> Option<f64> {
+        if keyword.eq_ignore_ascii_case("alpha") {
+            return Some(self.values[3]);
+        }
+        self.names
+            .iter()
+            .position(|name| keyword.eq_ignore_ascii_case(name))
+            .map(|i| self.values[i])
+    }
 }
 
 /// A parsed value.
@@ -87,6 +277,98 @@ enum Value<'a> {
     Dimension(f64, &'a str),
 }
 
+/// A value inside a math function, with its type.
+///
+/// Angles are in degrees.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum CalcValue {
+    Number(f64),
+    Percent(f64),
+    Angle(f64),
+}
+
+impl CalcValue {
+    fn value(self) -> f64 {
+        match self {
+            Self::Number(n) | Self::Percent(n) | Self::Angle(n) => n,
+        }
+    }
+
+    fn map(self, f: impl FnOnce(f64) -> f64) -> Self {
+        match self {
+            Self::Number(n) => Self::Number(f(n)),
+            Self::Percent(n) => Self::Percent(f(n)),
+            Self::Angle(n) => Self::Angle(f(n)),
+        }
+    }
+
+    /// Combine two values of the same type, returning `None` if the types differ.
+    fn zip(self, other: Self, f: impl FnOnce(f64, f64) -> f64) -> Option<Self> {
+        if core::mem::discriminant(&self) == core::mem::discriminant(&other) {
+            Some(self.map(|a| f(a, other.value())))
+        } else {
+            None
+        }
+    }
+
+    fn number(self) -> Option<f64> {
+        match self {
+            Self::Number(n) => Some(n),
+            _ => None,
+        }
+    }
+
+    /// The value of an angle in radians; numbers are interpreted as radians.
+    fn radians(self) -> Option<f64> {
+        match self {
+            Self::Number(n) => Some(n),
+            Self::Angle(n) => Some(n.to_radians()),
+            Self::Percent(_) => None,
+        }
+    }
+
+    fn mul(self, other: Self) -> Option<Self> {
+        match (self, other) {
+            (Self::Number(a), b) => Some(b.map(|b| a * b)),
+            (a, Self::Number(b)) => Some(a.map(|a| a * b)),
+            _ => None,
+        }
+    }
+
+    fn div(self, other: Self) -> Option<Self> {
+        let b = other.number()?;
+        Some(self.map(|a| a / b))
+    }
+}
+
+/// The rounding strategy of the `round()` math function.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum RoundingStrategy {
+    Nearest,
+    Up,
+    Down,
+    ToZero,
+}
+
+/// Round towards zero.
+fn trunc(n: f64) -> f64 {
+    if n < 0. { n.ceil() } else { n.floor() }
+}
+
+/// The scale to convert an angle in the unit `dim` to degrees.
+fn angle_scale(dim: &str) -> Option<f64> {
+    let mut buf = [0; LOWERCASE_BUF_SIZE];
+    match make_lowercase(dim, &mut buf) {
+        "deg" => Some(1.0),
+        // TODO: to make doubly sure this is computed at compile-time, this can be
+        // wrapped in a `const` block when our MSRV is 1.83 or greater.
+        "rad" => Some(1_f64.to_degrees()),
+        "grad" => Some(0.9),
+        "turn" => Some(360.0),
+        _ => None,
+    }
+}
+
 /// Whether or not we are parsing modern or legacy mode syntax.
 #[derive(Clone, Copy, Debug, PartialEq)]
 enum Mode {
@@ -124,7 +406,11 @@ fn color_from_components(components: [Option<f64>; 4], cs: ColorSpaceTag) -> Dyn
 impl<'a> Parser<'a> {
     fn new(s: &'a str) -> Self {
         let ix = 0;
-        Parser { s, ix }
+        Parser {
+            s,
+            ix,
+            channels: None,
+        }
     }
 
     // This will be called at the start of most tokens.
@@ -133,7 +419,11 @@ impl<'a> Parser<'a> {
             if let Some(i) = self.s[self.ix + 2..].find("*/") {
                 self.ix += i + 4;
             } else {
-                return Err(ParseError::UnclosedComment);
+                return Err(ParseError::new(
+                    ParseErrorKind::UnclosedComment,
+                    self.ix..self.s.len(),
+                )
+                .with_expected(&["*/"]));
             }
         }
         Ok(())
@@ -232,6 +522,23 @@ impl<'a> Parser<'a> {
         None
     }
 
+    // Complies with the dashed-ident production, with the same exceptions as `ident`.
+    fn dashed_ident(&mut self) -> Option<&'a str> {
+        let tail = &self.s[self.ix..];
+        if !tail.starts_with("--") {
+            return None;
+        }
+        let len = tail
+            .bytes()
+            .position(|b| !(b.is_ascii_alphanumeric() || b == b'_' || b == b'-'))
+            .unwrap_or(tail.len());
+        if len == 2 {
+            return None;
+        }
+        self.ix += len;
+        Some(&tail[..len])
+    }
+
     fn ch(&mut self, ch: u8) -> bool {
         if self.consume_comments().is_err() {
             return false;
@@ -280,6 +587,41 @@ impl<'a> Parser<'a> {
         true
     }
 
+    /// Create an error spanning from `start` to the current position.
+    ///
+    /// If nothing was consumed since `start`, the error spans the next character instead.
+    fn error(&self, kind: ParseErrorKind, start: usize) -> ParseError {
+        let end = if start == self.ix {
+            start + self.s[start..].chars().next().map_or(0, char::len_utf8)
+        } else {
+            self.ix
+        };
+        ParseError::new(kind, start..end)
+    }
+
+    /// Read the opening parenthesis of a color function's arguments.
+    fn open(&mut self) -> Result<(), ParseError> {
+        if self.raw_ch(b'(') {
+            Ok(())
+        } else {
+            Err(self
+                .error(ParseErrorKind::ExpectedArguments, self.ix)
+                .with_expected(&["("]))
+        }
+    }
+
+    /// Read a closing parenthesis, optionally preceded by whitespace.
+    fn close(&mut self) -> Result<(), ParseError> {
+        self.ws();
+        if self.ch(b')') {
+            Ok(())
+        } else {
+            Err(self
+                .error(ParseErrorKind::ExpectedClosingParenthesis, self.ix)
+                .with_expected(&[")"]))
+        }
+    }
+
     fn value(&mut self) -> Option<Value<'a>> {
         if let Some(number) = self.number() {
             if self.raw_ch(b'%') {
@@ -294,56 +636,413 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Parse a value, resolving channel keywords and math functions.
+    fn resolved_value(&mut self) -> Result<Option<Value<'a>>, ParseError> {
+        let start = self.ix;
+        let value = self.value();
+        if let Some(Value::Symbol(s)) = value {
+            if let Some(n) = self.channels.and_then(|channels| channels.get(s)) {
+                return Ok(Some(Value::Number(n)));
+            }
+            if self.raw_ch(b'(') {
+                if let Some(result) = self.math_function(s, start)? {
+                    // NaN becomes zero and infinities are clamped when leaving a top-level
+                    // calculation.
+                    let result = result.map(|n| {
+                        if n.is_nan() {
+                            0.
+                        } else {
+                            n.clamp(f32::MIN.into(), f32::MAX.into())
+                        }
+                    });
+                    let value = match result {
+                        CalcValue::Number(n) => Value::Number(n),
+                        CalcValue::Percent(n) => Value::Percent(n),
+                        CalcValue::Angle(n) => Value::Dimension(n, "deg"),
+                    };
+                    return Ok(Some(value));
+                }
+                self.ix -= 1;
+            }
+        }
+        Ok(value)
+    }
+
+    /// Parse the arguments of a math function, after the opening parenthesis.
+    ///
+    /// Returns `None` if `name` is not the name of a math function. The name starts at the byte
+    /// offset `start`.
+    ///
+    /// Reference: § 10 of CSS Values and Units 4 spec.
+    fn math_function(&mut self, name: &str, start: usize) -> Result<Option<CalcValue>, ParseError> {
+        let mut buf = [0; LOWERCASE_BUF_SIZE];
+        let value = match make_lowercase(name, &mut buf) {
+            "calc" => Some(self.calc_sum()?),
+            "min" => self.math_fold(|a, b| a.zip(b, f64::min))?,
+            "max" => self.math_fold(|a, b| a.zip(b, f64::max))?,
+            "hypot" => self.math_fold(|a, b| a.zip(b, f64::hypot))?,
+            "clamp" => {
+                let [min, value, max] = self.math_args()?;
+                value
+                    .zip(max, f64::min)
+                    .and_then(|value| value.zip(min, f64::max))
+            }
+            "round" => {
+                let strategy = self.rounding_strategy()?;
+                let a = self.calc_sum()?;
+                let b = if self.math_comma() {
+                    Some(self.calc_sum()?)
+                } else {
+                    // The step may only be omitted for numbers.
+                    CalcValue::Number(1.).zip(a, |b, _| b)
+                };
+                b.and_then(|b| {
+                    a.zip(b, |a, b| {
+                        let q = a / b;
+                        let rounded = match strategy {
+                            RoundingStrategy::Nearest => (q + 0.5).floor(),
+                            RoundingStrategy::Up => q.ceil(),
+                            RoundingStrategy::Down => q.floor(),
+                            RoundingStrategy::ToZero => trunc(q),
+                        };
+                        rounded * b
+                    })
+                })
+            }
+            "mod" => {
+                let [a, b] = self.math_args()?;
+                a.zip(b, |a, b| a - b * (a / b).floor())
+            }
+            "rem" => {
+                let [a, b] = self.math_args()?;
+                a.zip(b, |a, b| a - b * trunc(a / b))
+            }
+            "sin" => self
+                .calc_sum()?
+                .radians()
+                .map(|a| CalcValue::Number(a.sin_cos().0)),
+            "cos" => self
+                .calc_sum()?
+                .radians()
+                .map(|a| CalcValue::Number(a.sin_cos().1)),
+            "tan" => self
+                .calc_sum()?
+                .radians()
+                .map(|a| CalcValue::Number(a.tan())),
+            "asin" => self
+                .calc_sum()?
+                .number()
+                .map(|n| CalcValue::Angle(n.asin().to_degrees())),
+            "acos" => self
+                .calc_sum()?
+                .number()
+                .map(|n| CalcValue::Angle(n.acos().to_degrees())),
+            "atan" => self
+                .calc_sum()?
+                .number()
+                .map(|n| CalcValue::Angle(n.atan().to_degrees())),
+            "atan2" => {
+                let [a, b] = self.math_args()?;
+                a.zip(b, f64::atan2)
+                    .map(|a| CalcValue::Angle(a.value().to_degrees()))
+            }
+            "pow" => {
+                let [a, b] = self.math_args()?;
+                a.number()
+                    .zip(b.number())
+                    .map(|(a, b)| CalcValue::Number(a.powf(b)))
+            }
+            "sqrt" => self
+                .calc_sum()?
+                .number()
+                .map(|n| CalcValue::Number(n.sqrt())),
+            "log" => {
+                let a = self.calc_sum()?.number();
+                if self.math_comma() {
+                    let b = self.calc_sum()?.number();
+                    a.zip(b).map(|(a, b)| CalcValue::Number(a.ln() / b.ln()))
+                } else {
+                    a.map(|a| CalcValue::Number(a.ln()))
+                }
+            }
+            "exp" => self
+                .calc_sum()?
+                .number()
+                .map(|n| CalcValue::Number(n.exp())),
+            "abs" => Some(self.calc_sum()?.map(f64::abs)),
+            "sign" => {
+                let n = self.calc_sum()?.value();
+                Some(CalcValue::Number(if n > 0. {
+                    1.
+                } else if n < 0. {
+                    -1.
+                } else {
+                    // Zero (preserving its sign) and NaN.
+                    n
+                }))
+            }
+            _ => return Ok(None),
+        };
+        self.close()?;
+        // The arguments are type checked once the whole function is parsed, so the error spans
+        // the function.
+        value
+            .map(Some)
+            .ok_or_else(|| self.error(ParseErrorKind::InvalidMathExpression, start))
+    }
+
+    /// Read a comma separating math function arguments, returning whether one was read.
+    fn math_comma(&mut self) -> bool {
+        let start = self.ix;
+        self.ws();
+        if self.ch(b',') {
+            true
+        } else {
+            self.ix = start;
+            false
+        }
+    }
+
+    /// Parse a fixed number of math function arguments.
+    fn math_args<const N: usize>(&mut self) -> Result<[CalcValue; N], ParseError> {
+        let mut args = [CalcValue::Number(0.); N];
+        for (i, arg) in args.iter_mut().enumerate() {
+            if i > 0 && !self.math_comma() {
+                self.ws();
+                return Err(self
+                    .error(ParseErrorKind::ExpectedComma, self.ix)
+                    .with_expected(&[","]));
+            }
+            *arg = self.calc_sum()?;
+        }
+        Ok(args)
+    }
+
+    /// Parse one or more math function arguments, combining them with `f`.
+    ///
+    /// The result is `None` if `f` fails to combine any of the arguments.
+    fn math_fold(
+        &mut self,
+        f: impl Fn(CalcValue, CalcValue) -> Option<CalcValue>,
+    ) -> Result<Option<CalcValue>, ParseError> {
+        let mut value = Some(self.calc_sum()?);
+        while self.math_comma() {
+            let b = self.calc_sum()?;
+            value = value.and_then(|a| f(a, b));
+        }
+        Ok(value)
+    }
+
+    /// Parse the optional rounding strategy of `round()`, including the following comma.
+    fn rounding_strategy(&mut self) -> Result<RoundingStrategy, ParseError> {
+        self.ws();
+        let start = self.ix;
+        let mut buf = [0; LOWERCASE_BUF_SIZE];
+        let strategy = match self.ident().map(|id| make_lowercase(id, &mut buf)) {
+            Some("nearest") => RoundingStrategy::Nearest,
+            Some("up") => RoundingStrategy::Up,
+            Some("down") => RoundingStrategy::Down,
+            Some("to-zero") => RoundingStrategy::ToZero,
+            _ => {
+                self.ix = start;
+                return Ok(RoundingStrategy::Nearest);
+            }
+        };
+        if !self.math_comma() {
+            self.ws();
+            return Err(self
+                .error(ParseErrorKind::ExpectedComma, self.ix)
+                .with_expected(&[","]));
+        }
+        Ok(strategy)
+    }
+
+    fn calc_sum(&mut self) -> Result<CalcValue, ParseError> {
+        self.ws();
+        let start = self.ix;
+        let mut value = self.calc_product()?;
+        loop {
+            let op_start = self.ix;
+            let ws_before = self.ws();
+            let op = self.ix;
+            let sign = if self.ch(b'+') {
+                1.
+            } else if self.ch(b'-') {
+                -1.
+            } else {
+                self.ix = op_start;
+                return Ok(value);
+            };
+            // The operators `+` and `-` must be surrounded by whitespace.
+            if !ws_before || !self.ws() {
+                return Err(ParseError::new(
+                    ParseErrorKind::InvalidMathExpression,
+                    op..op + 1,
+                ));
+            }
+            let b = self.calc_product()?;
+            value = value
+                .zip(b, |a, b| a + sign * b)
+                .ok_or_else(|| self.error(ParseErrorKind::InvalidMathExpression, start))?;
+        }
+    }
+
+    fn calc_product(&mut self) -> Result<CalcValue, ParseError> {
+        self.ws();
+        let start = self.ix;
+        let mut value = self.calc_value()?;
+        loop {
+            let op_start = self.ix;
+            self.ws();
+            let result = if self.ch(b'*') {
+                value.mul(self.calc_value()?)
+            } else if self.ch(b'/') {
+                value.div(self.calc_value()?)
+            } else {
+                self.ix = op_start;
+                return Ok(value);
+            };
+            value =
+                result.ok_or_else(|| self.error(ParseErrorKind::InvalidMathExpression, start))?;
+        }
+    }
+
+    fn calc_value(&mut self) -> Result<CalcValue, ParseError> {
+        self.ws();
+        if self.ch(b'(') {
+            let value = self.calc_sum()?;
+            self.close()?;
+            return Ok(value);
+        }
+        let start = self.ix;
+        let value = self.value();
+        match value {
+            Some(Value::Number(n)) => Ok(CalcValue::Number(n)),
+            Some(Value::Percent(n)) => Ok(CalcValue::Percent(n)),
+            Some(Value::Dimension(n, dim)) => angle_scale(dim)
+                .map(|scale| CalcValue::Angle(n * scale))
+                .ok_or_else(|| {
+                    self.error(ParseErrorKind::UnknownAngleDimension, start)
+                        .with_expected(EXPECTED_ANGLE_UNIT)
+                }),
+            Some(Value::Symbol(s)) => {
+                if let Some(n) = self.channels.and_then(|channels| channels.get(s)) {
+                    return Ok(CalcValue::Number(n));
+                }
+                if self.raw_ch(b'(') {
+                    return self.math_function(s, start)?.ok_or_else(|| {
+                        ParseError::new(ParseErrorKind::InvalidMathExpression, start..self.ix - 1)
+                    });
+                }
+                let mut buf = [0; LOWERCASE_BUF_SIZE];
+                match make_lowercase(s, &mut buf) {
+                    "e" => Ok(CalcValue::Number(f64::consts::E)),
+                    "pi" => Ok(CalcValue::Number(f64::consts::PI)),
+                    "infinity" => Ok(CalcValue::Number(f64::INFINITY)),
+                    "-infinity" => Ok(CalcValue::Number(f64::NEG_INFINITY)),
+                    "nan" => Ok(CalcValue::Number(f64::NAN)),
+                    _ => Err(self.error(ParseErrorKind::InvalidMathExpression, start)),
+                }
+            }
+            None => Err(self
+                .error(ParseErrorKind::InvalidMathExpression, start)
+                .with_expected(EXPECTED_CALC_VALUE)),
+        }
+    }
+
+    /// Parse the `from <color>` origin of relative color syntax, if present.
+    ///
+    /// Reference: § 4 of CSS Color 5 spec.
+    fn relative_origin(&mut self) -> Result<Option<DynamicColor>, ParseError> {
+        self.ws();
+        let start = self.ix;
+        if !self
+            .ident()
+            .is_some_and(|id| id.eq_ignore_ascii_case("from"))
+        {
+            self.ix = start;
+            return Ok(None);
+        }
+        self.ws();
+        let (ix, origin) =
+            parse_color_prefix(&self.s[self.ix..]).map_err(|err| err.offset(self.ix))?;
+        self.ix += ix;
+        Ok(Some(origin))
+    }
+
+    /// Bind the channel keywords of relative color syntax to the channels of `origin`, converted
+    /// to the color space `cs`.
+    ///
+    /// The color channels are multiplied by `scale` to get the units of the color function's
+    /// numbers. Missing channels are bound to zero.
+    fn bind_channels(
+        &mut self,
+        origin: DynamicColor,
+        cs: ColorSpaceTag,
+        names: [&'static str; 3],
+        scale: f64,
+    ) {
+        let origin = origin.convert(cs);
+        let missing = origin.flags.missing();
+        let mut values = [0.; 4];
+        for (i, value) in values.iter_mut().enumerate() {
+            if !missing.contains(i) {
+                let scale = if i < 3 { scale } else { 1. };
+                *value = f64::from(origin.components[i]) * scale;
+            }
+        }
+        self.channels = Some(Channels { names, values });
+    }
+
     /// Parse a color component.
     fn scaled_component(&mut self, scale: f64, pct_scale: f64) -> Result<Option<f64>, ParseError> {
         self.ws();
-        let value = self.value();
+        let start = self.ix;
+        let value = self.resolved_value()?;
         match value {
             Some(Value::Number(n)) => Ok(Some(n * scale)),
             Some(Value::Percent(n)) => Ok(Some(n * pct_scale)),
             Some(Value::Symbol(s)) if s.eq_ignore_ascii_case("none") => Ok(None),
-            _ => Err(ParseError::UnknownColorComponent),
+            _ => Err(self
+                .error(ParseErrorKind::UnknownColorComponent, start)
+                .with_expected(EXPECTED_COMPONENT)),
         }
     }
 
     fn angle(&mut self) -> Result<Option<f64>, ParseError> {
         self.ws();
-        let value = self.value();
+        let start = self.ix;
+        let value = self.resolved_value()?;
         match value {
             Some(Value::Number(n)) => Ok(Some(n)),
             Some(Value::Symbol(s)) if s.eq_ignore_ascii_case("none") => Ok(None),
-            Some(Value::Dimension(n, dim)) => {
-                let mut buf = [0; LOWERCASE_BUF_SIZE];
-                let dim_lc = make_lowercase(dim, &mut buf);
-                let scale = match dim_lc {
-                    "deg" => 1.0,
-                    "rad" => {
-                        // TODO: to make doubly sure this is computed at compile-time, this can be
-                        // wrapped in a `const` block when our MSRV is 1.83 or greater.
-                        1_f64.to_degrees()
-                    }
-                    "grad" => 0.9,
-                    "turn" => 360.0,
-                    _ => return Err(ParseError::UnknownAngleDimension),
-                };
-                Ok(Some(n * scale))
-            }
-            _ => Err(ParseError::UnknownAngle),
+            Some(Value::Dimension(n, dim)) => angle_scale(dim)
+                .map(|scale| Some(n * scale))
+                .ok_or_else(|| {
+                    self.error(ParseErrorKind::UnknownAngleDimension, start)
+                        .with_expected(EXPECTED_ANGLE_UNIT)
+                }),
+            _ => Err(self
+                .error(ParseErrorKind::UnknownAngle, start)
+                .with_expected(EXPECTED_ANGLE)),
         }
     }
 
     fn optional_comma(&mut self, comma: bool) -> Result<(), ParseError> {
         self.ws();
         if comma && !self.ch(b',') {
-            Err(ParseError::ExpectedComma)
+            Err(self
+                .error(ParseErrorKind::ExpectedComma, self.ix)
+                .with_expected(&[","]))
         } else {
             Ok(())
         }
     }
 
     fn rgb(&mut self) -> Result<DynamicColor, ParseError> {
-        if !self.raw_ch(b'(') {
-            return Err(ParseError::ExpectedArguments);
+        self.open()?;
+        if let Some(origin) = self.relative_origin()? {
+            self.bind_channels(origin, ColorSpaceTag::Srgb, ["r", "g", "b"], 255.);
         }
         // TODO: in legacy mode, be stricter about not mixing numbers
         // and percentages, and disallowing "none"
@@ -361,10 +1060,7 @@ impl<'a> Parser<'a> {
             .scaled_component(1. / 255., 0.01)?
             .map(|x| x.clamp(0., 1.));
         let alpha = self.alpha(mode)?;
-        self.ws();
-        if !self.ch(b')') {
-            return Err(ParseError::ExpectedClosingParenthesis);
-        }
+        self.close()?;
         Ok(color_from_components([r, g, b, alpha], ColorSpaceTag::Srgb))
     }
 
@@ -372,8 +1068,8 @@ impl<'a> Parser<'a> {
     ///
     /// The value may be either number or a percentage.
     ///
-    /// The alpha value defaults to `1.0` if not present. The value will be clamped
-    /// to the range [0, 1].
+    /// The alpha value defaults to `1.0` if not present, or to the alpha of the origin color in
+    /// relative color syntax. The value will be clamped to the range [0, 1].
     ///
     /// If the value is `"none"`, then `Ok(None)` will be returned.
     ///
@@ -386,13 +1082,20 @@ impl<'a> Parser<'a> {
         if self.ch(mode.alpha_separator()) {
             Ok(self.scaled_component(1., 0.01)?.map(|a| a.clamp(0., 1.)))
         } else {
-            Ok(Some(1.0))
+            Ok(Some(
+                self.channels.map_or(1.0, |channels| channels.values[3]),
+            ))
         }
     }
 
     fn lab(&mut self, lmax: f64, c: f64, tag: ColorSpaceTag) -> Result<DynamicColor, ParseError> {
-        if !self.raw_ch(b'(') {
-            return Err(ParseError::ExpectedArguments);
+        self.open()?;
+        if let Some(origin) = self.relative_origin()? {
+            let names = match tag {
+                ColorSpaceTag::ICtCp => ["i", "ct", "cp"],
+                _ => ["l", "a", "b"],
+            };
+            self.bind_channels(origin, tag, names, 1.);
         }
         let l = self
             .scaled_component(1., 0.01 * lmax)?
@@ -400,16 +1103,14 @@ impl<'a> Parser<'a> {
         let a = self.scaled_component(1., c)?;
         let b = self.scaled_component(1., c)?;
         let alpha = self.alpha(Mode::Modern)?;
-        self.ws();
-        if !self.ch(b')') {
-            return Err(ParseError::ExpectedClosingParenthesis);
-        }
+        self.close()?;
         Ok(color_from_components([l, a, b, alpha], tag))
     }
 
     fn lch(&mut self, lmax: f64, c: f64, tag: ColorSpaceTag) -> Result<DynamicColor, ParseError> {
-        if !self.raw_ch(b'(') {
-            return Err(ParseError::ExpectedArguments);
+        self.open()?;
+        if let Some(origin) = self.relative_origin()? {
+            self.bind_channels(origin, tag, ["l", "c", "h"], 1.);
         }
         let l = self
             .scaled_component(1., 0.01 * lmax)?
@@ -417,16 +1118,14 @@ impl<'a> Parser<'a> {
         let c = self.scaled_component(1., c)?.map(|x| x.max(0.));
         let h = self.angle()?;
         let alpha = self.alpha(Mode::Modern)?;
-        self.ws();
-        if !self.ch(b')') {
-            return Err(ParseError::ExpectedClosingParenthesis);
-        }
+        self.close()?;
         Ok(color_from_components([l, c, h, alpha], tag))
     }
 
     fn hsl(&mut self) -> Result<DynamicColor, ParseError> {
-        if !self.raw_ch(b'(') {
-            return Err(ParseError::ExpectedArguments);
+        self.open()?;
+        if let Some(origin) = self.relative_origin()? {
+            self.bind_channels(origin, ColorSpaceTag::Hsl, ["h", "s", "l"], 1.);
         }
         let h = self.angle()?;
         let comma = self.ch(b',');
@@ -435,35 +1134,32 @@ impl<'a> Parser<'a> {
         self.optional_comma(comma)?;
         let l = self.scaled_component(1., 1.)?;
         let alpha = self.alpha(mode)?;
-        self.ws();
-        if !self.ch(b')') {
-            return Err(ParseError::ExpectedClosingParenthesis);
-        }
+        self.close()?;
         Ok(color_from_components([h, s, l, alpha], ColorSpaceTag::Hsl))
     }
 
     fn hwb(&mut self) -> Result<DynamicColor, ParseError> {
-        if !self.raw_ch(b'(') {
-            return Err(ParseError::ExpectedArguments);
+        self.open()?;
+        if let Some(origin) = self.relative_origin()? {
+            self.bind_channels(origin, ColorSpaceTag::Hwb, ["h", "w", "b"], 1.);
         }
         let h = self.angle()?;
         let w = self.scaled_component(1., 1.)?;
         let b = self.scaled_component(1., 1.)?;
         let alpha = self.alpha(Mode::Modern)?;
-        self.ws();
-        if !self.ch(b')') {
-            return Err(ParseError::ExpectedClosingParenthesis);
-        }
+        self.close()?;
         Ok(color_from_components([h, w, b, alpha], ColorSpaceTag::Hwb))
     }
 
     fn color(&mut self) -> Result<DynamicColor, ParseError> {
-        if !self.raw_ch(b'(') {
-            return Err(ParseError::ExpectedArguments);
-        }
+        self.open()?;
+        let origin = self.relative_origin()?;
         self.ws();
-        let Some(id) = self.ident() else {
-            return Err(ParseError::ExpectedColorSpaceIdentifier);
+        let start = self.ix;
+        let Some(id) = self.ident().or_else(|| self.dashed_ident()) else {
+            return Err(self
+                .error(ParseErrorKind::ExpectedColorSpaceIdentifier, start)
+                .with_expected(EXPECTED_PREDEFINED_COLOR_SPACE));
         };
         let mut buf = [0; LOWERCASE_BUF_SIZE];
         let id_lc = make_lowercase(id, &mut buf);
@@ -474,19 +1170,208 @@ impl<'a> Parser<'a> {
             "a98-rgb" => ColorSpaceTag::A98Rgb,
             "prophoto-rgb" => ColorSpaceTag::ProphotoRgb,
             "rec2020" => ColorSpaceTag::Rec2020,
+            "rec2100-pq" => ColorSpaceTag::Rec2100Pq,
+            "rec2100-hlg" => ColorSpaceTag::Rec2100Hlg,
+            "--jzazbz" => ColorSpaceTag::Jzazbz,
+            "--jzczhz" => ColorSpaceTag::JzCzhz,
+            "--luv" => ColorSpaceTag::Luv,
+            "--lchuv" => ColorSpaceTag::LchUv,
+            "--hsluv" => ColorSpaceTag::Hsluv,
+            "--hpluv" => ColorSpaceTag::Hpluv,
+            "--hsv" => ColorSpaceTag::Hsv,
+            "--okhsl" => ColorSpaceTag::Okhsl,
+            "--okhsv" => ColorSpaceTag::Okhsv,
             "xyz-d50" => ColorSpaceTag::XyzD50,
             "xyz" | "xyz-d65" => ColorSpaceTag::XyzD65,
-            _ => return Err(ParseError::UnknownColorSpace),
+            _ => {
+                return Err(self
+                    .error(ParseErrorKind::UnknownColorSpace, start)
+                    .with_expected(EXPECTED_PREDEFINED_COLOR_SPACE));
+            }
+        };
+        if let Some(origin) = origin {
+            let names = match cs {
+                ColorSpaceTag::XyzD50 | ColorSpaceTag::XyzD65 => ["x", "y", "z"],
+                ColorSpaceTag::Jzazbz => ["jz", "az", "bz"],
+                ColorSpaceTag::JzCzhz => ["jz", "cz", "hz"],
+                ColorSpaceTag::Luv => ["l", "u", "v"],
+                ColorSpaceTag::LchUv => ["l", "c", "h"],
+                ColorSpaceTag::Hsluv | ColorSpaceTag::Hpluv | ColorSpaceTag::Okhsl => {
+                    ["h", "s", "l"]
+                }
+                ColorSpaceTag::Hsv | ColorSpaceTag::Okhsv => ["h", "s", "v"],
+                _ => ["r", "g", "b"],
+            };
+            self.bind_channels(origin, cs, names, 1.);
+        }
+        // Percentages refer to the ranges of the components used by Color.js.
+        let [c0, c1, c2] = match cs {
+            ColorSpaceTag::JzCzhz => [
+                self.scaled_component(1., 0.01)?,
+                self.scaled_component(1., 0.01)?,
+                self.angle()?,
+            ],
+            ColorSpaceTag::Luv => [
+                self.scaled_component(1., 1.)?,
+                self.scaled_component(1., 2.15)?,
+                self.scaled_component(1., 2.15)?,
+            ],
+            ColorSpaceTag::LchUv => [
+                self.scaled_component(1., 1.)?,
+                self.scaled_component(1., 2.2)?,
+                self.angle()?,
+            ],
+            ColorSpaceTag::Hsluv | ColorSpaceTag::Hpluv | ColorSpaceTag::Hsv => [
+                self.angle()?,
+                self.scaled_component(1., 1.)?,
+                self.scaled_component(1., 1.)?,
+            ],
+            ColorSpaceTag::Okhsl | ColorSpaceTag::Okhsv => [
+                self.angle()?,
+                self.scaled_component(1., 0.01)?,
+                self.scaled_component(1., 0.01)?,
+            ],
+            _ => [
+                self.scaled_component(1., 0.01)?,
+                self.scaled_component(1., 0.01)?,
+                self.scaled_component(1., 0.01)?,
+            ],
         };
-        let r = self.scaled_component(1., 0.01)?;
-        let g = self.scaled_component(1., 0.01)?;
-        let b = self.scaled_component(1., 0.01)?;
         let alpha = self.alpha(Mode::Modern)?;
+        self.close()?;
+        Ok(color_from_components([c0, c1, c2, alpha], cs))
+    }
+
+    /// Parse the arguments of `color-mix()`.
+    ///
+    /// The interpolation method is optional and defaults to Oklab.
+    ///
+    /// Reference: § 2 of CSS Color 5 spec.
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "deliberate choice of f32 for colors"
+    )]
+    fn color_mix(&mut self) -> Result<DynamicColor, ParseError> {
+        self.open()?;
+        self.ws();
+        let start = self.ix;
+        let (cs, direction) = if self.ident().is_some_and(|id| id.eq_ignore_ascii_case("in")) {
+            let method = self.interpolation_method()?;
+            self.optional_comma(true)?;
+            method
+        } else {
+            self.ix = start;
+            (ColorSpaceTag::Oklab, HueDirection::default())
+        };
+        self.ws();
+        let components_start = self.ix;
+        let (color1, p1) = self.mix_component()?;
+        self.optional_comma(true)?;
+        let (color2, p2) = self.mix_component()?;
+        let components_end = self.ix;
+        self.close()?;
+
+        // Reference: § 2.1 of CSS Color 5 spec.
+        let (p1, p2) = match (p1, p2) {
+            (None, None) => (50., 50.),
+            (Some(p1), None) => (p1, 100. - p1),
+            (None, Some(p2)) => (100. - p2, p2),
+            (Some(p1), Some(p2)) => (p1, p2),
+        };
+        let sum = p1 + p2;
+        if sum == 0. {
+            return Err(ParseError::new(
+                ParseErrorKind::InvalidPercentage,
+                components_start..components_end,
+            ));
+        }
+        let mut color = color1
+            .interpolate(color2, cs, direction)
+            .eval((p2 / sum) as f32);
+        if sum < 100. {
+            color.components[3] *= (sum * 0.01) as f32;
+        }
+        if let Some(ix) = cs.layout().hue_channel() {
+            let h = color.components[ix];
+            color.components[ix] = h - 360. * (h * (1. / 360.)).floor();
+        }
+        Ok(color)
+    }
+
+    /// Parse a color interpolation method, after the `in` keyword.
+    ///
+    /// Reference: § 12.1 of CSS Color 4 spec.
+    fn interpolation_method(&mut self) -> Result<(ColorSpaceTag, HueDirection), ParseError> {
+        self.ws();
+        let start = self.ix;
+        let cs: ColorSpaceTag = self
+            .ident()
+            .or_else(|| self.dashed_ident())
+            .ok_or_else(|| {
+                self.error(ParseErrorKind::ExpectedColorSpaceIdentifier, start)
+                    .with_expected(EXPECTED_COLOR_SPACE)
+            })?
+            .parse()
+            .map_err(|err: ParseError| err.offset(start))?;
+        self.ws();
+        let start = self.ix;
+        let Some(id) = self.ident() else {
+            return Ok((cs, HueDirection::default()));
+        };
+        let mut buf = [0; LOWERCASE_BUF_SIZE];
+        let direction = match make_lowercase(id, &mut buf) {
+            "shorter" => HueDirection::Shorter,
+            "longer" => HueDirection::Longer,
+            "increasing" => HueDirection::Increasing,
+            "decreasing" => HueDirection::Decreasing,
+            _ => {
+                return Err(self
+                    .error(ParseErrorKind::UnknownHueInterpolationMethod, start)
+                    .with_expected(EXPECTED_HUE_INTERPOLATION_METHOD));
+            }
+        };
+        self.ws();
+        if !self
+            .ident()
+            .is_some_and(|id| id.eq_ignore_ascii_case("hue"))
+        {
+            return Err(self
+                .error(ParseErrorKind::UnknownHueInterpolationMethod, start)
+                .with_expected(&["hue"]));
+        }
+        if cs.layout().hue_channel().is_none() {
+            // Hue interpolation methods are only allowed for color spaces with a hue channel.
+            return Err(self.error(ParseErrorKind::UnknownHueInterpolationMethod, start));
+        }
+        Ok((cs, direction))
+    }
+
+    /// Parse a color and its optional percentage in `color-mix()`, in either order.
+    fn mix_component(&mut self) -> Result<(DynamicColor, Option<f64>), ParseError> {
+        let mut percentage = self.mix_percentage()?;
+        self.ws();
+        let (ix, color) =
+            parse_color_prefix(&self.s[self.ix..]).map_err(|err| err.offset(self.ix))?;
+        self.ix += ix;
+        if percentage.is_none() {
+            percentage = self.mix_percentage()?;
+        }
+        Ok((color, percentage))
+    }
+
+    fn mix_percentage(&mut self) -> Result<Option<f64>, ParseError> {
         self.ws();
-        if !self.ch(b')') {
-            return Err(ParseError::ExpectedClosingParenthesis);
+        let start = self.ix;
+        match self.resolved_value()? {
+            Some(Value::Percent(p)) if (0. ..=100.).contains(&p) => Ok(Some(p)),
+            Some(Value::Percent(_)) => Err(self
+                .error(ParseErrorKind::InvalidPercentage, start)
+                .with_expected(&["<percentage [0,100]>"])),
+            _ => {
+                self.ix = start;
+                Ok(None)
+            }
         }
-        Ok(color_from_components([r, g, b, alpha], cs))
     }
 }
 
@@ -500,12 +1385,6 @@ impl<'a> Parser<'a> {
 /// Tries to return a suitable error for any invalid string, but may be
 /// a little lax on some details.
 pub fn parse_color_prefix(s: &str) -> Result<(usize, DynamicColor), ParseError> {
-    #[inline]
-    fn set_from_named_color_space(mut color: DynamicColor) -> DynamicColor {
-        color.flags.set_named_color_space();
-        color
-    }
-
     if let Some(stripped) = s.strip_prefix('#') {
         let (ix, channels) = get_4bit_hex_channels(stripped)?;
         let color = color_from_4bit_hex(channels);
@@ -519,46 +1398,47 @@ pub fn parse_color_prefix(s: &str) -> Result<(usize, DynamicColor), ParseError>
     if let Some(id) = parser.ident() {
         let mut buf = [0; LOWERCASE_BUF_SIZE];
         let id_lc = make_lowercase(id, &mut buf);
-        let color = match id_lc {
-            "rgb" | "rgba" => parser.rgb().map(set_from_named_color_space),
-            "lab" => parser
-                .lab(100.0, 1.25, ColorSpaceTag::Lab)
-                .map(set_from_named_color_space),
-            "lch" => parser
-                .lch(100.0, 1.25, ColorSpaceTag::Lch)
-                .map(set_from_named_color_space),
-            "oklab" => parser
-                .lab(1.0, 0.004, ColorSpaceTag::Oklab)
-                .map(set_from_named_color_space),
-            "oklch" => parser
-                .lch(1.0, 0.004, ColorSpaceTag::Oklch)
-                .map(set_from_named_color_space),
-            "hsl" | "hsla" => parser.hsl().map(set_from_named_color_space),
-            "hwb" => parser.hwb().map(set_from_named_color_space),
+        let mut color = match id_lc {
+            "rgb" | "rgba" => parser.rgb(),
+            "lab" => parser.lab(100.0, 1.25, ColorSpaceTag::Lab),
+            "lch" => parser.lch(100.0, 1.25, ColorSpaceTag::Lch),
+            "oklab" => parser.lab(1.0, 0.004, ColorSpaceTag::Oklab),
+            "oklch" => parser.lch(1.0, 0.004, ColorSpaceTag::Oklch),
+            "ictcp" => parser.lab(1.0, 0.005, ColorSpaceTag::ICtCp),
+            "hsl" | "hsla" => parser.hsl(),
+            "hwb" => parser.hwb(),
             "color" => parser.color(),
+            "color-mix" => parser.color_mix(),
             _ => {
                 if let Some(ix) = crate::x11_colors::lookup_palette_index(id_lc) {
                     let [r, g, b, a] = crate::x11_colors::COLORS[ix];
                     let mut color =
                         DynamicColor::from_alpha_color(AlphaColor::from_rgba8(r, g, b, a));
                     color.flags.set_named_color(ix);
-                    Ok(color)
+                    return Ok((parser.ix, color));
                 } else {
-                    Err(ParseError::UnknownColorIdentifier)
+                    let mut err = parser.error(ParseErrorKind::UnknownColorIdentifier, 0);
+                    err.suggestion = suggest_color_name(id_lc);
+                    Err(err)
                 }
             }
         }?;
+        // Colors specified with the named color space functions are flagged as such, except for
+        // relative colors.
+        if !matches!(id_lc, "color" | "color-mix") && parser.channels.is_none() {
+            color.flags.set_named_color_space();
+        }
 
         Ok((parser.ix, color))
     } else {
-        Err(ParseError::UnknownColorSyntax)
+        Err(parser.error(ParseErrorKind::UnknownColorSyntax, 0))
     }
 }
 
 /// Parse a color string in CSS syntax into a color.
 ///
 /// This parses the entire string; trailing characters cause an
-/// [`ExpectedEndOfString`](ParseError::ExpectedEndOfString) parse error. Leading and trailing
+/// [`ExpectedEndOfString`](ParseErrorKind::ExpectedEndOfString) parse error. Leading and trailing
 /// whitespace are ignored. See also [`parse_color_prefix`].
 ///
 /// # Errors
@@ -566,13 +1446,19 @@ pub fn parse_color_prefix(s: &str) -> Result<(usize, DynamicColor), ParseError>
 /// Tries to return a suitable error for any invalid string, but may be
 /// a little lax on some details.
 pub fn parse_color(s: &str) -> Result<DynamicColor, ParseError> {
-    let s = s.trim();
-    let (ix, color) = parse_color_prefix(s)?;
+    let trimmed = s.trim_start();
+    // Spans of errors are relative to the untrimmed string.
+    let offset = s.len() - trimmed.len();
+    let s = trimmed.trim_end();
+    let (ix, color) = parse_color_prefix(s).map_err(|err| err.offset(offset))?;
 
     if ix == s.len() {
         Ok(color)
     } else {
-        Err(ParseError::ExpectedEndOfString)
+        Err(ParseError::new(
+            ParseErrorKind::ExpectedEndOfString,
+            offset + ix..offset + s.len(),
+        ))
     }
 }
 
@@ -636,7 +1522,13 @@ const fn get_4bit_hex_channels(hex_str: &str) -> Result<(usize, [u8; 8]), ParseE
         ],
         6 => [hex[0], hex[1], hex[2], hex[3], hex[4], hex[5], 15, 15],
         8 => hex,
-        _ => return Err(ParseError::WrongNumberOfHexDigits),
+        // The span includes the leading `#`.
+        _ => {
+            return Err(ParseError::new(
+                ParseErrorKind::WrongNumberOfHexDigits,
+                0..i + 1,
+            ));
+        }
     };
 
     Ok((i, four_bit_channels))
@@ -676,11 +1568,78 @@ impl FromStr for ColorSpaceTag {
             "display-p3" => Ok(Self::DisplayP3),
             "a98-rgb" => Ok(Self::A98Rgb),
             "prophoto-rgb" => Ok(Self::ProphotoRgb),
+            "rec2020" => Ok(Self::Rec2020),
+            "rec2100-pq" => Ok(Self::Rec2100Pq),
+            "rec2100-hlg" => Ok(Self::Rec2100Hlg),
+            "ictcp" => Ok(Self::ICtCp),
+            "--jzazbz" => Ok(Self::Jzazbz),
+            "--jzczhz" => Ok(Self::JzCzhz),
+            "--luv" => Ok(Self::Luv),
+            "--lchuv" => Ok(Self::LchUv),
+            "--hsluv" => Ok(Self::Hsluv),
+            "--hpluv" => Ok(Self::Hpluv),
+            "--hsv" => Ok(Self::Hsv),
+            "--okhsl" => Ok(Self::Okhsl),
+            "--okhsv" => Ok(Self::Okhsv),
+            "hsl" => Ok(Self::Hsl),
+            "hwb" => Ok(Self::Hwb),
             "xyz-d50" => Ok(Self::XyzD50),
             "xyz" | "xyz-d65" => Ok(Self::XyzD65),
-            _ => Err(ParseError::UnknownColorSpace),
+            _ => Err(
+                ParseError::new(ParseErrorKind::UnknownColorSpace, 0..s.len())
+                    .with_expected(EXPECTED_COLOR_SPACE),
+            ),
+        }
+    }
+}
+
+/// Find the named color closest to the unknown color identifier `name`, if any is close enough
+/// to likely be what was meant.
+///
+/// This uses the optimal string alignment distance, which counts insertions, deletions,
+/// substitutions and transpositions of adjacent characters. `name` must be lowercase.
+fn suggest_color_name(name: &str) -> Option<&'static str> {
+    let name = name.as_bytes();
+    if name.len() > LOWERCASE_BUF_SIZE {
+        return None;
+    }
+    let max_distance = (name.len() / 3).max(1);
+    let mut best = None;
+    let mut best_distance = max_distance + 1;
+    // The previous two rows and the current row of the distance matrix.
+    let mut rows = [[0_usize; LOWERCASE_BUF_SIZE + 1]; 3];
+    for candidate_name in crate::x11_colors::NAMES {
+        let candidate = candidate_name.as_bytes();
+        if candidate.len().abs_diff(name.len()) >= best_distance {
+            continue;
+        }
+        for (j, d) in rows[2].iter_mut().enumerate() {
+            *d = j;
+        }
+        for i in 1..=candidate.len() {
+            rows.rotate_left(1);
+            let [prev2, prev, cur] = &mut rows;
+            cur[0] = i;
+            for j in 1..=name.len() {
+                let cost = usize::from(candidate[i - 1] != name[j - 1]);
+                let mut d = (prev[j] + 1).min(cur[j - 1] + 1).min(prev[j - 1] + cost);
+                if i > 1
+                    && j > 1
+                    && candidate[i - 1] == name[j - 2]
+                    && candidate[i - 2] == name[j - 1]
+                {
+                    d = d.min(prev2[j - 2] + 1);
+                }
+                cur[j] = d;
+            }
+        }
+        let distance = rows[2][name.len()];
+        if distance < best_distance {
+            best_distance = distance;
+            best = Some(candidate_name);
         }
     }
+    best
 }
 
 const LOWERCASE_BUF_SIZE: usize = 32;
@@ -707,9 +1666,13 @@ fn make_lowercase<'a>(s: &'a str, buf: &'a mut [u8; LOWERCASE_BUF_SIZE]) -> &'a
 
 #[cfg(test)]
 mod tests {
-    use crate::DynamicColor;
+    extern crate alloc;
+
+    use alloc::string::ToString;
 
-    use super::{Mode, ParseError, Parser, parse_color, parse_color_prefix};
+    use crate::{ColorSpaceTag, DynamicColor};
+
+    use super::{Mode, ParseErrorKind, Parser, parse_color, parse_color_prefix};
 
     fn assert_close_color(c1: DynamicColor, c2: DynamicColor) {
         const EPSILON: f32 = 1e-4;
@@ -719,8 +1682,8 @@ mod tests {
         }
     }
 
-    fn assert_err(c: &str, err: ParseError) {
-        assert_eq!(parse_color(c).unwrap_err(), err);
+    fn assert_err(c: &str, kind: ParseErrorKind) {
+        assert_eq!(parse_color(c).unwrap_err().kind(), kind);
     }
 
     #[test]
@@ -741,20 +1704,20 @@ mod tests {
         assert_close_color(red, parse_color("#f00f").unwrap());
         assert_close_color(red, parse_color("#ff0000ff").unwrap());
         assert_eq!(
-            parse_color("#f00fa").unwrap_err(),
-            ParseError::WrongNumberOfHexDigits
+            parse_color("#f00fa").unwrap_err().kind(),
+            ParseErrorKind::WrongNumberOfHexDigits
         );
     }
 
     #[test]
     fn consume_string() {
         assert_eq!(
-            parse_color("#ff0000ffa").unwrap_err(),
-            ParseError::ExpectedEndOfString
+            parse_color("#ff0000ffa").unwrap_err().kind(),
+            ParseErrorKind::ExpectedEndOfString
         );
         assert_eq!(
-            parse_color("rgba(255, 100, 0, 1)a").unwrap_err(),
-            ParseError::ExpectedEndOfString
+            parse_color("rgba(255, 100, 0, 1)a").unwrap_err().kind(),
+            ParseErrorKind::ExpectedEndOfString
         );
     }
 
@@ -827,7 +1790,7 @@ mod tests {
 
         assert_err(
             "hwb(1turns 20% 30% / 50%)",
-            ParseError::UnknownAngleDimension,
+            ParseErrorKind::UnknownAngleDimension,
         );
     }
 
@@ -847,4 +1810,412 @@ mod tests {
             assert_close_color(parse_color(c1).unwrap(), parse_color(c2).unwrap());
         }
     }
+
+    #[test]
+    fn relative_color() {
+        for (relative, absolute) in [
+            ("rgb(from red r g b)", "rgb(255 0 0)"),
+            ("rgb(from red b g r)", "rgb(0 0 255)"),
+            (
+                "rgb(from #123456 calc(r + 10) g b / 50%)",
+                "rgb(28 52 86 / 0.5)",
+            ),
+            ("rgb(from rgb(10 20 30 / 0.5) r g b)", "rgb(10 20 30 / 0.5)"),
+            (
+                "rgb(from rgb(10 20 30 / 0.5) r g b / calc(alpha * 0.5))",
+                "rgb(10 20 30 / 0.25)",
+            ),
+            ("RGB(FROM red R G B / ALPHA)", "rgb(255 0 0)"),
+            ("hsl(from red calc(h + 120) s l)", "hsl(120 100 50)"),
+            ("hwb(from hsl(0 100% 50%) h w calc(b + 20))", "hwb(0 0 20)"),
+            ("lab(from lab(50 10 20) l calc(-1 * a) b)", "lab(50 -10 20)"),
+            ("oklch(from oklch(0.5 none 120) l c h)", "oklch(0.5 0 120)"),
+            (
+                "oklch(from oklch(0.5 0.1 120) calc(l * 0.8) c h / 50%)",
+                "oklch(0.4 0.1 120 / 0.5)",
+            ),
+            ("color(from red srgb r g b)", "color(srgb 1 0 0)"),
+            (
+                "color(from color(xyz 0.1 0.2 0.3) xyz-d65 z y x)",
+                "color(xyz 0.3 0.2 0.1)",
+            ),
+            ("rgb(from rgb(from red r g 255) r g b)", "rgb(255 0 255)"),
+            (
+                "color(from color(srgb 0.2 0.4 0.6) srgb calc((r + g) / 2) calc(b - 0.1) 50%)",
+                "color(srgb 0.3 0.5 0.5)",
+            ),
+            (
+                "ictcp(from ictcp(0.5 0.1 -0.2) i cp ct)",
+                "ictcp(0.5 -0.2 0.1)",
+            ),
+            (
+                "color(from color(--jzazbz 0.1 0.02 -0.03) --jzazbz jz bz az)",
+                "color(--jzazbz 0.1 -0.03 0.02)",
+            ),
+            (
+                "color(from color(--hsluv 30 40 50) --hsluv calc(h + 180) s calc(l / 2))",
+                "color(--hsluv 210 40 25)",
+            ),
+        ] {
+            let relative_color = parse_color(relative).unwrap();
+            let absolute_color = parse_color(absolute).unwrap();
+            assert_close_color(relative_color, absolute_color);
+        }
+
+        // The origin color is converted to the color space of the color function.
+        let p3 = parse_color("color(from red display-p3 r g b)").unwrap();
+        assert_close_color(
+            p3,
+            parse_color("red")
+                .unwrap()
+                .convert(ColorSpaceTag::DisplayP3),
+        );
+        let oklch = parse_color("oklch(from #3366ff calc(l * 0.8) c h)").unwrap();
+        let expected = parse_color("#3366ff")
+            .unwrap()
+            .convert(ColorSpaceTag::Oklch)
+            .map(|l, c, h, a| [l * 0.8, c, h, a]);
+        assert_close_color(oklch, expected);
+
+        // Relative colors are not flagged as specified in a named color space.
+        assert!(!parse_color("rgb(from red r g b)").unwrap().flags.named());
+        assert!(parse_color("rgb(255 0 0)").unwrap().flags.named());
+
+        // Channel keywords are only valid in relative color syntax and must match the function.
+        assert_err("rgb(r g b)", ParseErrorKind::UnknownColorComponent);
+        assert_err("rgb(from red l g b)", ParseErrorKind::UnknownColorComponent);
+        assert_err("rgb(from r g b)", ParseErrorKind::UnknownColorIdentifier);
+    }
+
+    #[test]
+    fn calc() {
+        for (calc, absolute) in [
+            ("rgb(calc(255 / 2) 0 0)", "rgb(127.5 0 0)"),
+            ("rgb(calc(10% * 2) 0 0)", "rgb(20% 0 0)"),
+            ("rgb(calc((1 + 2) * 3) 0 0)", "rgb(9 0 0)"),
+            ("rgb(calc(1 + 2 * 3) 0 0)", "rgb(7 0 0)"),
+            ("rgb(calc(8 - 4 - 2) 0 0)", "rgb(2 0 0)"),
+            ("rgb(calc( /**/ 1 /**/ ) calc(calc(2)) 0)", "rgb(1 2 0)"),
+            ("hsl(calc(60 * 2) 50% 50%)", "hsl(120 50% 50%)"),
+            ("rgb(0 0 0 / calc(1 / 0))", "rgb(0 0 0 / 1)"),
+            ("rgb(0 0 0 / calc(NaN))", "rgb(0 0 0 / 0)"),
+            ("rgb(calc(-infinity) 0 0)", "rgb(0 0 0)"),
+            ("hsl(calc(120deg + 30deg) 50% 50%)", "hsl(150 50% 50%)"),
+            ("hsl(calc(0.25turn * 2) 50% 50%)", "hsl(180 50% 50%)"),
+            (
+                "hsl(calc(90deg / 2 + 1rad - 1rad) 50% 50%)",
+                "hsl(45 50% 50%)",
+            ),
+            (
+                "rgb(min(10, 20, 5) max(1, 7) clamp(0, 300, 255))",
+                "rgb(5 7 255)",
+            ),
+            (
+                "rgb(min(10%, 20%) max(1%, 7%) clamp(0%, -5%, 100%))",
+                "rgb(10% 7% 0%)",
+            ),
+            (
+                "rgb(round(12.5) round(up, 12.1) round(down, 12.9))",
+                "rgb(13 13 12)",
+            ),
+            (
+                "rgb(round(to-zero, -12.9) round(27, 10) round(-12.5))",
+                "rgb(-12 30 -12)",
+            ),
+            ("rgb(round(nearest, 33%, 10%) 0 0)", "rgb(30% 0 0)"),
+            ("rgb(mod(18, 5) mod(-18, 5) rem(-18, 5))", "rgb(3 2 -3)"),
+            ("hsl(mod(-90deg, 360deg) 50% 50%)", "hsl(270 50% 50%)"),
+            (
+                "rgb(calc(sin(30deg) * 100) calc(cos(pi) * -100) calc(tan(45deg) * 100))",
+                "rgb(50 100 100)",
+            ),
+            ("rgb(calc(sin(pi / 6) * 100) 0 0)", "rgb(50 0 0)"),
+            ("hsl(asin(1) 50% 50%)", "hsl(90 50% 50%)"),
+            ("hsl(acos(-1) 50% 50%)", "hsl(180 50% 50%)"),
+            ("hsl(atan(1) 50% 50%)", "hsl(45 50% 50%)"),
+            ("hsl(atan2(1, -1) 50% 50%)", "hsl(135 50% 50%)"),
+            ("hsl(atan2(10%, 10%) 50% 50%)", "hsl(45 50% 50%)"),
+            ("rgb(pow(2, 7) sqrt(400) hypot(30, 40))", "rgb(128 20 50)"),
+            ("rgb(hypot(3%, 4%) 0 0)", "rgb(5% 0 0)"),
+            (
+                "rgb(log(e) log(1000, 10) calc(exp(2) / e / e))",
+                "rgb(1 3 1)",
+            ),
+            ("rgb(abs(-20) calc(sign(-20) * -5) sign(0))", "rgb(20 5 0)"),
+            ("rgb(calc(abs(-20%) + 10%) 0 0)", "rgb(30% 0 0)"),
+            ("rgb(CALC(Min(2, 3) * PI / pi) 0 0)", "rgb(2 0 0)"),
+            (
+                "oklch(from red calc(l / 2) clamp(0, c, 0.1) calc(h + 180))",
+                "oklch(from red calc(0.5 * l) 0.1 calc(180 + h))",
+            ),
+        ] {
+            assert_close_color(parse_color(calc).unwrap(), parse_color(absolute).unwrap());
+        }
+
+        for calc in [
+            "rgb(calc(1 + 1%) 0 0)",
+            "rgb(calc(1% * 1%) 0 0)",
+            "rgb(calc(1 / 1%) 0 0)",
+            "rgb(calc(1 +1) 0 0)",
+            "rgb(calc(1+ 1) 0 0)",
+            "rgb(calc(1 + ) 0 0)",
+            "rgb(calc(none) 0 0)",
+            "color(from red srgb calc(r - 10%) g b)",
+            "rgb(min(1, 1%) 0 0)",
+            "rgb(round(1%) 0 0)",
+            "rgb(mod(1, 1deg) 0 0)",
+            "rgb(sin(10%) 0 0)",
+            "rgb(pow(2, 1%) 0 0)",
+            "rgb(sqrt(4deg) 0 0)",
+            "hsl(asin(1deg) 50% 50%)",
+            "hsl(calc(10deg + 10) 50% 50%)",
+            "rgb(calc(foo) 0 0)",
+            "rgb(calc(rgb(1 2 3)) 0 0)",
+        ] {
+            assert_err(calc, ParseErrorKind::InvalidMathExpression);
+        }
+        assert_err(
+            "rgb(calc(1 0 0)",
+            ParseErrorKind::ExpectedClosingParenthesis,
+        );
+        assert_err("rgb(clamp(1, 2) 0 0)", ParseErrorKind::ExpectedComma);
+        assert_err("rgb(round(up 1) 0 0)", ParseErrorKind::ExpectedComma);
+        assert_err("rgb(calc(1foo) 0 0)", ParseErrorKind::UnknownAngleDimension);
+        assert_err(
+            "rgb(calc(10deg) 0 0)",
+            ParseErrorKind::UnknownColorComponent,
+        );
+    }
+
+    #[test]
+    fn color_mix() {
+        for (mix, expected) in [
+            ("color-mix(in srgb, red, blue)", "color(srgb 0.5 0 0.5)"),
+            ("color-mix(in srgb, red 30%, blue)", "color(srgb 0.3 0 0.7)"),
+            ("color-mix(in srgb, 30% red, blue)", "color(srgb 0.3 0 0.7)"),
+            ("color-mix(in srgb, red, 70% blue)", "color(srgb 0.3 0 0.7)"),
+            (
+                "color-mix(in srgb, red 60%, blue 60%)",
+                "color(srgb 0.5 0 0.5)",
+            ),
+            // Percentages summing to less than 100% result in an alpha multiplier.
+            (
+                "color-mix(in srgb, red 20%, blue 60%)",
+                "color(srgb 0.25 0 0.75 / 0.8)",
+            ),
+            // Premultiplied interpolation.
+            (
+                "color-mix(in srgb, rgb(255 0 0 / 0.5), blue)",
+                "color(srgb 0.333333 0 0.666667 / 0.75)",
+            ),
+            // Missing components take the other color's value.
+            (
+                "color-mix(in srgb, rgb(none 0 0), rgb(255 0 255))",
+                "color(srgb 1 0 0.5)",
+            ),
+            (
+                "color-mix(in srgb, color-mix(in srgb, red, blue), white)",
+                "color(srgb 0.75 0.5 0.75)",
+            ),
+            (
+                "color-mix(in hsl, hsl(0 100% 50%), hsl(90 100% 50%))",
+                "hsl(45 100 50)",
+            ),
+            (
+                "color-mix(in hsl longer hue, hsl(0 100% 50%), hsl(90 100% 50%))",
+                "hsl(225 100 50)",
+            ),
+            (
+                "color-mix(in oklch decreasing hue, oklch(0.5 0.1 10), oklch(0.7 0.1 50))",
+                "oklch(0.6 0.1 210)",
+            ),
+            (
+                "color-mix(in lch increasing hue, lch(50 10 350), lch(70 20 10))",
+                "lch(60 15 0)",
+            ),
+            (
+                "COLOR-MIX(IN SRGB-LINEAR, red, lime)",
+                "color(srgb-linear 0.5 0.5 0)",
+            ),
+            (
+                "color-mix(in xyz, color(xyz 0.2 0.4 0.6), color(xyz 0.4 0.6 0.8))",
+                "color(xyz 0.3 0.5 0.7)",
+            ),
+            (
+                "color-mix(in --jzczhz longer hue, color(--jzczhz 0.1 0.05 0), color(--jzczhz 0.2 0.1 90))",
+                "color(--jzczhz 0.15 0.075 225)",
+            ),
+            (
+                "color-mix(in --okhsl, color(--okhsl 0 100% 40%), color(--okhsl 90 0.5 0.6))",
+                "color(--okhsl 45 0.75 0.5)",
+            ),
+        ] {
+            let mix_color = parse_color(mix).unwrap();
+            let expected_color = parse_color(expected).unwrap();
+            assert_close_color(mix_color, expected_color);
+        }
+
+        // The default interpolation space is Oklab.
+        assert_close_color(
+            parse_color("color-mix(red, blue)").unwrap(),
+            parse_color("color-mix(in oklab, red, blue)").unwrap(),
+        );
+        assert!(
+            !parse_color("color-mix(in srgb, red, blue)")
+                .unwrap()
+                .flags
+                .named()
+        );
+
+        for (mix, err) in [
+            (
+                "color-mix(in srgb, red 0%, blue 0%)",
+                ParseErrorKind::InvalidPercentage,
+            ),
+            (
+                "color-mix(in srgb, red 120%, blue)",
+                ParseErrorKind::InvalidPercentage,
+            ),
+            (
+                "color-mix(in srgb, red -10%, blue)",
+                ParseErrorKind::InvalidPercentage,
+            ),
+            (
+                "color-mix(in srgb longer hue, red, blue)",
+                ParseErrorKind::UnknownHueInterpolationMethod,
+            ),
+            (
+                "color-mix(in oklch longest hue, red, blue)",
+                ParseErrorKind::UnknownHueInterpolationMethod,
+            ),
+            (
+                "color-mix(in oklch longer, red, blue)",
+                ParseErrorKind::UnknownHueInterpolationMethod,
+            ),
+            (
+                "color-mix(in rgb, red, blue)",
+                ParseErrorKind::UnknownColorSpace,
+            ),
+            (
+                "color-mix(in srgb red, blue)",
+                ParseErrorKind::UnknownHueInterpolationMethod,
+            ),
+            (
+                "color-mix(in srgb, red blue)",
+                ParseErrorKind::ExpectedComma,
+            ),
+            ("color-mix(in srgb, red)", ParseErrorKind::ExpectedComma),
+            (
+                "color-mix(in srgb, red, blue",
+                ParseErrorKind::ExpectedClosingParenthesis,
+            ),
+        ] {
+            assert_err(mix, err);
+        }
+    }
+
+    #[test]
+    fn error_spans() {
+        for (s, kind, span) in [
+            ("#f00fa", ParseErrorKind::WrongNumberOfHexDigits, 0..6),
+            ("  #ff0000ffa ", ParseErrorKind::ExpectedEndOfString, 11..12),
+            (
+                "rgb(255 0 foo)",
+                ParseErrorKind::UnknownColorComponent,
+                10..13,
+            ),
+            (
+                "rgb(255 0 0",
+                ParseErrorKind::ExpectedClosingParenthesis,
+                11..11,
+            ),
+            ("rgb(255, 0 0)", ParseErrorKind::ExpectedComma, 11..12),
+            ("rgb 255 0 0", ParseErrorKind::ExpectedArguments, 3..4),
+            (
+                "hsl(1turns 20% 30%)",
+                ParseErrorKind::UnknownAngleDimension,
+                4..10,
+            ),
+            (
+                "color(srgb-lin 1 0 0)",
+                ParseErrorKind::UnknownColorSpace,
+                6..14,
+            ),
+            (
+                "rgb(calc(1 + 1%) 0 0)",
+                ParseErrorKind::InvalidMathExpression,
+                9..15,
+            ),
+            (
+                "rgb(calc(1+1) 0 0)",
+                ParseErrorKind::InvalidMathExpression,
+                10..11,
+            ),
+            (
+                "rgb(sin(10%) 0 0)",
+                ParseErrorKind::InvalidMathExpression,
+                4..12,
+            ),
+            (
+                "rgb(from gren r g b)",
+                ParseErrorKind::UnknownColorIdentifier,
+                9..13,
+            ),
+            (
+                "color-mix(in oklch longest hue, red, blue)",
+                ParseErrorKind::UnknownHueInterpolationMethod,
+                19..26,
+            ),
+            (
+                "color-mix(in rgb, red, blue)",
+                ParseErrorKind::UnknownColorSpace,
+                13..16,
+            ),
+            (
+                "rgb(/* 255 0 0)",
+                ParseErrorKind::UnknownColorComponent,
+                4..5,
+            ),
+            ("!red", ParseErrorKind::UnknownColorSyntax, 0..1),
+        ] {
+            let err = parse_color(s).unwrap_err();
+            assert_eq!(err.kind(), kind, "{s}");
+            assert_eq!(err.span(), span, "{s}");
+        }
+    }
+
+    #[test]
+    fn error_details() {
+        let err = parse_color("rgb(255 0 foo)").unwrap_err();
+        assert_eq!(err.expected(), ["<number>", "<percentage>", "none"]);
+        assert_eq!(err.suggestion(), None);
+        assert_eq!(
+            err.to_string(),
+            "unknown color component at 10..13, expected <number> or <percentage> or none"
+        );
+
+        let err = parse_color("hsl(10foo 20% 30%)").unwrap_err();
+        assert_eq!(err.expected(), ["deg", "grad", "rad", "turn"]);
+
+        let err = parse_color("rgb(255 0 0").unwrap_err();
+        assert_eq!(err.expected(), [")"]);
+
+        for (name, suggestion) in [
+            ("gren", Some("green")),
+            ("bleu", Some("blue")),
+            ("Rde", Some("red")),
+            ("lightgoldenrodyelow", Some("lightgoldenrodyellow")),
+            ("lightsalmom", Some("lightsalmon")),
+            ("xyz", None),
+            ("notacolorname", None),
+        ] {
+            let err = parse_color(name).unwrap_err();
+            assert_eq!(err.kind(), ParseErrorKind::UnknownColorIdentifier);
+            assert_eq!(err.suggestion(), suggestion, "{name}");
+        }
+        assert_eq!(
+            parse_color("bleu").unwrap_err().to_string(),
+            "unknown color identifier at 0..4, did you mean `blue`?"
+        );
+    }
 }
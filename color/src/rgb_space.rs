@@ -0,0 +1,479 @@
+// Copyright 2025 the Color Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use crate::colorspace::{XYZ_D65_TO_LINEAR_SRGB, lin_to_pq, pq_to_lin};
+use crate::{Chromaticity, Rec2100Hlg, matdiagmatmul, matinv, matmatmul, matvecmul};
+
+#[cfg(all(not(feature = "std"), not(test)))]
+use crate::floatfuncs::FloatFuncs;
+
+/// The transfer function of an RGB color space, converting between encoded and linear values.
+///
+/// All transfer functions are extended to negative values by symmetry, as for the built-in RGB
+/// color spaces.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum TransferFunction {
+    /// No transfer function: the encoded values are linear.
+    Linear,
+    /// A pure power function, where the linear value is the encoded value to the power of the
+    /// given gamma.
+    Gamma(f32),
+    /// A parametric curve, as in the `parametricCurveType` of ICC profiles.
+    ///
+    /// The linear value `Y` of an encoded value `X` is
+    ///
+    /// ```text
+    /// Y = (a * X + b)^g + e   if X >= d
+    /// Y = c * X + f           otherwise
+    /// ```
+    ///
+    /// This covers the transfer functions of sRGB and Rec. 709, among others. See
+    /// [`TransferFunction::SRGB`].
+    Parametric {
+        /// The exponent of the curve.
+        g: f32,
+        /// The scale of the encoded value in the curve.
+        a: f32,
+        /// The offset of the encoded value in the curve.
+        b: f32,
+        /// The slope of the linear segment.
+        c: f32,
+        /// The encoded value where the curve starts.
+        d: f32,
+        /// The offset of the curve.
+        e: f32,
+        /// The offset of the linear segment.
+        f: f32,
+    },
+    /// The perceptual quantizer of SMPTE ST 2084, as in [`Rec2100Pq`](crate::Rec2100Pq).
+    ///
+    /// As in `Rec2100Pq`, a linear value of 1 is the reference white of 203 cd/m².
+    Pq,
+    /// The hybrid log-gamma transfer function, as in [`Rec2100Hlg`].
+    ///
+    /// As in `Rec2100Hlg`, a linear value of 1 is the reference white at an encoded value of 0.75.
+    Hlg,
+}
+
+impl TransferFunction {
+    /// The transfer function of [sRGB](crate::Srgb) and [Display P3](crate::DisplayP3).
+    pub const SRGB: Self = Self::Parametric {
+        g: 2.4,
+        a: 1. / 1.055,
+        b: 0.055 / 1.055,
+        c: 1. / 12.92,
+        d: 0.04045,
+        e: 0.,
+        f: 0.,
+    };
+
+    /// The transfer function of ITU-R BT.709 and BT.601.
+    pub const REC709: Self = Self::Parametric {
+        g: 1. / 0.45,
+        a: 1. / 1.099,
+        b: 0.099 / 1.099,
+        c: 1. / 4.5,
+        d: 0.081,
+        e: 0.,
+        f: 0.,
+    };
+
+    /// Convert an encoded value to a linear value.
+    #[must_use]
+    pub fn to_linear(self, x: f32) -> f32 {
+        let linear = match self {
+            Self::Linear => return x,
+            Self::Pq => return pq_to_lin(x),
+            Self::Gamma(gamma) => x.abs().powf(gamma),
+            Self::Parametric {
+                g,
+                a,
+                b,
+                c,
+                d,
+                e,
+                f,
+            } => {
+                let x = x.abs();
+                if x >= d {
+                    (a * x + b).max(0.).powf(g) + e
+                } else {
+                    c * x + f
+                }
+            }
+            Self::Hlg => Rec2100Hlg::inverse_oetf(x.abs()) * Rec2100Hlg::SCALE,
+        };
+        linear.copysign(x)
+    }
+
+    /// Convert a linear value to an encoded value.
+    ///
+    /// This is the inverse of [`TransferFunction::to_linear`].
+    #[must_use]
+    pub fn from_linear(self, x: f32) -> f32 {
+        let encoded = match self {
+            Self::Linear => return x,
+            Self::Pq => return lin_to_pq(x),
+            Self::Gamma(gamma) => x.abs().powf(1. / gamma),
+            Self::Parametric {
+                g,
+                a,
+                b,
+                c,
+                d,
+                e,
+                f,
+            } => {
+                let x = x.abs();
+                if x >= (a * d + b).max(0.).powf(g) + e {
+                    ((x - e).max(0.).powf(1. / g) - b) / a
+                } else if c == 0. {
+                    0.
+                } else {
+                    (x - f) / c
+                }
+            }
+            Self::Hlg => Rec2100Hlg::oetf(x.abs() * (1. / Rec2100Hlg::SCALE)),
+        };
+        encoded.copysign(x)
+    }
+}
+
+/// An RGB color space defined at runtime.
+///
+/// The [`ColorSpace`](crate::ColorSpace) trait and [`ColorSpaceTag`](crate::ColorSpaceTag) cover
+/// color spaces known at compile time. An RGB color space learned about at runtime, for example
+/// from an ICC profile or the metadata of a video, is defined by the chromaticities of its
+/// primaries and white point, and its transfer function.
+///
+/// The matrices converting between the color space and XYZ are computed when the definition is
+/// created. As in the built-in color spaces, colors are chromatically adapted with the linear
+/// Bradford transform when converting to and from [linear sRGB](crate::LinearSrgb), which has a
+/// D65 white point.
+///
+/// Use [`DynamicColor::to_rgb_space`](crate::DynamicColor::to_rgb_space) and
+/// [`DynamicColor::from_rgb_space`](crate::DynamicColor::from_rgb_space) to convert colors.
+///
+/// # Example
+///
+/// ```rust
+/// use color::{Chromaticity, ColorSpace, DisplayP3, RgbSpaceDef, TransferFunction};
+///
+/// let display_p3 = RgbSpaceDef::new(
+///     Chromaticity { x: 0.680, y: 0.320 },
+///     Chromaticity { x: 0.265, y: 0.690 },
+///     Chromaticity { x: 0.150, y: 0.060 },
+///     Chromaticity::D65,
+///     TransferFunction::SRGB,
+/// );
+/// let p3 = [0.2, 0.6, 0.4];
+/// let expected = DisplayP3::to_linear_srgb(p3);
+/// let actual = display_p3.to_linear_srgb(p3);
+/// assert!(actual.iter().zip(expected).all(|(a, e)| (a - e).abs() < 1e-4));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RgbSpaceDef {
+    red: Chromaticity,
+    green: Chromaticity,
+    blue: Chromaticity,
+    white: Chromaticity,
+    transfer: TransferFunction,
+    to_xyz: [[f32; 3]; 3],
+    from_xyz: [[f32; 3]; 3],
+    to_linear_srgb: [[f32; 3]; 3],
+    from_linear_srgb: [[f32; 3]; 3],
+}
+
+impl RgbSpaceDef {
+    /// Define an RGB color space by the chromaticities of its primaries and white point, and its
+    /// transfer function.
+    ///
+    /// The primaries must not be collinear.
+    #[must_use]
+    pub const fn new(
+        red: Chromaticity,
+        green: Chromaticity,
+        blue: Chromaticity,
+        white: Chromaticity,
+        transfer: TransferFunction,
+    ) -> Self {
+        // The columns of the matrix are the XYZ of the primaries, scaled such that the sum of the
+        // primaries is the white point with a luminance of 1.
+        let primaries = {
+            let [r, g, b] = [red.to_xyz(), green.to_xyz(), blue.to_xyz()];
+            [[r[0], g[0], b[0]], [r[1], g[1], b[1]], [r[2], g[2], b[2]]]
+        };
+        let scale = matvecmul(&matinv(&primaries), white.to_xyz());
+        let to_xyz = matdiagmatmul(&primaries, scale);
+        let from_xyz = matinv(&to_xyz);
+
+        let to_linear_srgb = matmatmul(
+            &white.linear_srgb_chromatic_adaptation_matrix(Chromaticity::D65),
+            &matmatmul(&XYZ_D65_TO_LINEAR_SRGB, &to_xyz),
+        );
+        let from_linear_srgb = matinv(&to_linear_srgb);
+        Self {
+            red,
+            green,
+            blue,
+            white,
+            transfer,
+            to_xyz,
+            from_xyz,
+            to_linear_srgb,
+            from_linear_srgb,
+        }
+    }
+
+    /// The chromaticity of the red primary.
+    #[must_use]
+    pub const fn red(&self) -> Chromaticity {
+        self.red
+    }
+
+    /// The chromaticity of the green primary.
+    #[must_use]
+    pub const fn green(&self) -> Chromaticity {
+        self.green
+    }
+
+    /// The chromaticity of the blue primary.
+    #[must_use]
+    pub const fn blue(&self) -> Chromaticity {
+        self.blue
+    }
+
+    /// The chromaticity of the white point.
+    #[must_use]
+    pub const fn white(&self) -> Chromaticity {
+        self.white
+    }
+
+    /// The transfer function.
+    #[must_use]
+    pub const fn transfer(&self) -> TransferFunction {
+        self.transfer
+    }
+
+    /// The matrix converting linear RGB to XYZ, relative to the white point of the color space.
+    ///
+    /// White has a luminance `Y` of 1.
+    #[must_use]
+    pub const fn to_xyz_matrix(&self) -> [[f32; 3]; 3] {
+        self.to_xyz
+    }
+
+    /// The matrix converting XYZ, relative to the white point of the color space, to linear RGB.
+    ///
+    /// This is the inverse of [`RgbSpaceDef::to_xyz_matrix`].
+    #[must_use]
+    pub const fn from_xyz_matrix(&self) -> [[f32; 3]; 3] {
+        self.from_xyz
+    }
+
+    /// Convert encoded RGB to XYZ, relative to the white point of the color space.
+    #[must_use]
+    pub fn to_xyz(&self, rgb: [f32; 3]) -> [f32; 3] {
+        matvecmul(&self.to_xyz, rgb.map(|x| self.transfer.to_linear(x)))
+    }
+
+    /// Convert XYZ, relative to the white point of the color space, to encoded RGB.
+    #[must_use]
+    pub fn from_xyz(&self, xyz: [f32; 3]) -> [f32; 3] {
+        matvecmul(&self.from_xyz, xyz).map(|x| self.transfer.from_linear(x))
+    }
+
+    /// Convert encoded RGB to linear sRGB, chromatically adapting to D65.
+    #[must_use]
+    pub fn to_linear_srgb(&self, rgb: [f32; 3]) -> [f32; 3] {
+        matvecmul(
+            &self.to_linear_srgb,
+            rgb.map(|x| self.transfer.to_linear(x)),
+        )
+    }
+
+    /// Convert linear sRGB to encoded RGB, chromatically adapting from D65.
+    #[must_use]
+    pub fn from_linear_srgb(&self, rgb: [f32; 3]) -> [f32; 3] {
+        matvecmul(&self.from_linear_srgb, rgb).map(|x| self.transfer.from_linear(x))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RgbSpaceDef, TransferFunction};
+    use crate::{
+        AlphaColor, Chromaticity, ColorSpace, ColorSpaceTag, DisplayP3, DynamicColor, LinearSrgb,
+        OpaqueColor, ProphotoRgb, Rec2020, Rec2100Hlg, Rec2100Pq, Srgb,
+    };
+
+    const SRGB_PRIMARIES: [Chromaticity; 3] = [
+        Chromaticity { x: 0.64, y: 0.33 },
+        Chromaticity { x: 0.30, y: 0.60 },
+        Chromaticity { x: 0.15, y: 0.06 },
+    ];
+
+    const REC2020_PRIMARIES: [Chromaticity; 3] = [
+        Chromaticity { x: 0.708, y: 0.292 },
+        Chromaticity { x: 0.170, y: 0.797 },
+        Chromaticity { x: 0.131, y: 0.046 },
+    ];
+
+    fn def(
+        [red, green, blue]: [Chromaticity; 3],
+        white: Chromaticity,
+        transfer: TransferFunction,
+    ) -> RgbSpaceDef {
+        RgbSpaceDef::new(red, green, blue, white, transfer)
+    }
+
+    /// Check the definition against a built-in color space.
+    fn check<CS: ColorSpace>(space: RgbSpaceDef, epsilon: f32) {
+        for rgb in [
+            [0., 0., 0.],
+            [1., 1., 1.],
+            [0.2, 0.6, 0.4],
+            [0.9, 0.1, 0.05],
+        ] {
+            let expected = CS::to_linear_srgb(rgb);
+            let actual = space.to_linear_srgb(rgb);
+            assert!(
+                OpaqueColor::<LinearSrgb>::new(actual).difference(OpaqueColor::new(expected))
+                    < epsilon,
+                "expected {expected:?}, got {actual:?} for {:?}",
+                CS::TAG
+            );
+            let roundtripped = space.from_linear_srgb(actual);
+            assert!(
+                OpaqueColor::<CS>::new(roundtripped).difference(OpaqueColor::new(rgb)) < epsilon,
+                "expected {rgb:?}, got {roundtripped:?} for {:?}",
+                CS::TAG
+            );
+        }
+    }
+
+    #[test]
+    fn builtin_spaces() {
+        check::<Srgb>(
+            def(SRGB_PRIMARIES, Chromaticity::D65, TransferFunction::SRGB),
+            1e-4,
+        );
+        check::<LinearSrgb>(
+            def(SRGB_PRIMARIES, Chromaticity::D65, TransferFunction::Linear),
+            1e-4,
+        );
+        check::<DisplayP3>(
+            def(
+                [
+                    Chromaticity { x: 0.680, y: 0.320 },
+                    Chromaticity { x: 0.265, y: 0.690 },
+                    Chromaticity { x: 0.150, y: 0.060 },
+                ],
+                Chromaticity::D65,
+                TransferFunction::SRGB,
+            ),
+            1e-4,
+        );
+        check::<Rec2020>(
+            def(
+                REC2020_PRIMARIES,
+                Chromaticity::D65,
+                TransferFunction::Parametric {
+                    g: 1. / 0.45,
+                    a: 1. / 1.099_296_8,
+                    b: 0.099_296_8 / 1.099_296_8,
+                    c: 1. / 4.5,
+                    d: 0.018_053_97 * 4.5,
+                    e: 0.,
+                    f: 0.,
+                },
+            ),
+            1e-4,
+        );
+        check::<Rec2100Pq>(
+            def(REC2020_PRIMARIES, Chromaticity::D65, TransferFunction::Pq),
+            1e-3,
+        );
+        check::<Rec2100Hlg>(
+            def(REC2020_PRIMARIES, Chromaticity::D65, TransferFunction::Hlg),
+            1e-4,
+        );
+        // A D50 white point.
+        check::<ProphotoRgb>(
+            def(
+                [
+                    Chromaticity {
+                        x: 0.734_699,
+                        y: 0.265_301,
+                    },
+                    Chromaticity {
+                        x: 0.159_597,
+                        y: 0.840_403,
+                    },
+                    Chromaticity {
+                        x: 0.036_598,
+                        y: 0.000_105,
+                    },
+                ],
+                Chromaticity::D50,
+                TransferFunction::Parametric {
+                    g: 1.8,
+                    a: 1.,
+                    b: 0.,
+                    c: 1. / 16.,
+                    d: 1. / 32.,
+                    e: 0.,
+                    f: 0.,
+                },
+            ),
+            1e-3,
+        );
+    }
+
+    #[test]
+    fn xyz_matrices() {
+        let space = def(
+            SRGB_PRIMARIES,
+            Chromaticity::D50,
+            TransferFunction::Gamma(2.2),
+        );
+        let white = space.to_xyz([1., 1., 1.]);
+        let expected = Chromaticity::D50.to_xyz();
+        for (actual, expected) in white.into_iter().zip(expected) {
+            assert!((actual - expected).abs() < 1e-5);
+        }
+        let rgb = space.from_xyz(space.to_xyz([0.2, 0.6, 0.4]));
+        assert!(OpaqueColor::<Srgb>::new(rgb).difference(OpaqueColor::new([0.2, 0.6, 0.4])) < 1e-5);
+    }
+
+    #[test]
+    fn dynamic_color() {
+        let space = def(REC2020_PRIMARIES, Chromaticity::D65, TransferFunction::Pq);
+        let color = DynamicColor::from_rgb_space(&space, [0.5, 0.3, 0.2, 0.8]);
+        let expected = AlphaColor::<Rec2100Pq>::new([0.5, 0.3, 0.2, 0.8]);
+        assert!(color.to_alpha_color::<Rec2100Pq>().difference(expected) < 1e-3);
+        let roundtripped = color.convert(ColorSpaceTag::Oklch).to_rgb_space(&space);
+        assert!(AlphaColor::<Rec2100Pq>::new(roundtripped).difference(expected) < 1e-3);
+    }
+
+    #[test]
+    fn transfer_functions() {
+        for transfer in [
+            TransferFunction::Linear,
+            TransferFunction::Gamma(2.2),
+            TransferFunction::SRGB,
+            TransferFunction::REC709,
+            TransferFunction::Pq,
+            TransferFunction::Hlg,
+        ] {
+            for x in [0., 0.01, 0.05, 0.5, 0.75, 1., -0.5] {
+                let roundtripped = transfer.from_linear(transfer.to_linear(x));
+                assert!(
+                    (roundtripped - x).abs() < 1e-4,
+                    "expected {x}, got {roundtripped} for {transfer:?}"
+                );
+            }
+        }
+    }
+}
@@ -5,107 +5,396 @@
 
 use core::fmt::{Formatter, Result};
 
-use crate::{ColorSpaceTag, DynamicColor, Rgba8};
-
-fn write_scaled_component(
-    color: &DynamicColor,
-    ix: usize,
-    f: &mut Formatter<'_>,
-    scale: f32,
-) -> Result {
-    if color.flags.missing().contains(ix) {
-        // According to the serialization rules (§15.2), missing should be converted to 0.
-        // However, it seems useful to preserve these. Perhaps we want to talk about whether
-        // we want string formatting to strictly follow the serialization spec.
-
-        write!(f, "none")
-    } else {
-        write!(f, "{}", color.components[ix] * scale)
-    }
-}
+use crate::{ColorSpaceTag, DynamicColor, Rgba8, Srgb};
+
+#[cfg(all(not(feature = "std"), not(test)))]
+use crate::floatfuncs::FloatFuncs;
 
-fn write_modern_function(color: &DynamicColor, name: &str, f: &mut Formatter<'_>) -> Result {
-    write!(f, "{name}(")?;
-    write_scaled_component(color, 0, f, 1.0)?;
-    write!(f, " ")?;
-    write_scaled_component(color, 1, f, 1.0)?;
-    write!(f, " ")?;
-    write_scaled_component(color, 2, f, 1.0)?;
-    if color.components[3] < 1.0 {
-        write!(f, " / ")?;
-        // TODO: clamp negative values
-        write_scaled_component(color, 3, f, 1.0)?;
-    }
-    write!(f, ")")
+/// Options for serializing colors as CSS strings.
+///
+/// The default options serialize colors as the [`Display`](core::fmt::Display) implementation of
+/// [`DynamicColor`] does: components are written at full precision, missing components are
+/// written as `none`, and colors specified with named sRGB color space functions use the legacy
+/// comma-separated `rgb()` syntax.
+///
+/// ```
+/// use color::{CssSerializer, parse_color};
+///
+/// let color = parse_color("hsl(30deg 90% 60% / 50%)").unwrap();
+/// let serializer = CssSerializer::new().precision(2).legacy_syntax(false);
+/// assert_eq!(
+///     serializer.display(color).to_string(),
+///     "rgb(244.8 153 61.2 / 0.5)",
+/// );
+/// assert_eq!(
+///     serializer.hex(true).display(color).to_string(),
+///     "#f5993d80",
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CssSerializer {
+    precision: Option<u8>,
+    spec_conformant: bool,
+    hex: bool,
+    legacy_syntax: bool,
+    percentages: bool,
 }
 
-fn write_color_function(color: &DynamicColor, name: &str, f: &mut Formatter<'_>) -> Result {
-    write!(f, "color({name} ")?;
-    write_scaled_component(color, 0, f, 1.0)?;
-    write!(f, " ")?;
-    write_scaled_component(color, 1, f, 1.0)?;
-    write!(f, " ")?;
-    write_scaled_component(color, 2, f, 1.0)?;
-    if color.components[3] < 1.0 {
-        write!(f, " / ")?;
-        // TODO: clamp negative values
-        write_scaled_component(color, 3, f, 1.0)?;
-    }
-    write!(f, ")")
+/// A color with options for serializing it as a CSS string.
+///
+/// This is returned by [`CssSerializer::display`], and serializes the color through its
+/// [`Display`](core::fmt::Display) implementation.
+#[derive(Clone, Copy, Debug)]
+pub struct CssDisplay {
+    serializer: CssSerializer,
+    color: DynamicColor,
 }
 
-fn write_legacy_function(
-    color: &DynamicColor,
-    name: &str,
-    scale: f32,
-    f: &mut Formatter<'_>,
-) -> Result {
-    let opt_a = if color.components[3] < 1.0 { "a" } else { "" };
-    write!(f, "{name}{opt_a}(")?;
-    write_scaled_component(color, 0, f, scale)?;
-    write!(f, ", ")?;
-    write_scaled_component(color, 1, f, scale)?;
-    write!(f, ", ")?;
-    write_scaled_component(color, 2, f, scale)?;
-    if color.components[3] < 1.0 {
-        write!(f, ", ")?;
-        // TODO: clamp negative values
-        write_scaled_component(color, 3, f, 1.0)?;
-    }
-    write!(f, ")")
+/// The syntax of a color function.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Syntax {
+    /// Comma-separated arguments, with an `a` suffix on the function name when there is alpha.
+    Legacy,
+    /// Space-separated arguments, with alpha after a slash.
+    Modern,
+    /// The `color()` function, with the name as its first argument.
+    Color,
 }
 
-impl core::fmt::Display for DynamicColor {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        if let Some(color_name) = self.flags.color_name() {
+/// How a color component is serialized: the scale applied to numbers, and the scale applied to
+/// percentages, if the component can be a percentage.
+#[derive(Clone, Copy, Debug)]
+struct Channel(f32, Option<f32>);
+
+/// A hue channel.
+const HUE: Channel = Channel(1.0, None);
+
+impl CssSerializer {
+    /// Create a serializer with the default options.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            precision: None,
+            spec_conformant: false,
+            hex: false,
+            legacy_syntax: true,
+            percentages: false,
+        }
+    }
+
+    /// Round numbers to at most `digits` digits after the decimal point.
+    ///
+    /// Trailing zeros are never written. By default, numbers are written with the shortest
+    /// representation that round-trips to the same `f32`.
+    #[must_use]
+    pub const fn precision(mut self, digits: u8) -> Self {
+        self.precision = Some(digits);
+        self
+    }
+
+    /// Follow the serialization rules of [CSS Color Module Level 4 § 15][css-sec].
+    ///
+    /// Missing components are written as 0 instead of `none`, and alpha is clamped to the range
+    /// `[0, 1]`.
+    ///
+    /// [css-sec]: https://www.w3.org/TR/css-color-4/#serializing-color-values
+    #[must_use]
+    pub const fn spec_conformant(mut self, spec_conformant: bool) -> Self {
+        self.spec_conformant = spec_conformant;
+        self
+    }
+
+    /// Write colors in the [`Srgb`], [`Hsl`](crate::Hsl) and [`Hwb`](crate::Hwb) color spaces
+    /// as `#rrggbb` or `#rrggbbaa` hex colors when they are within the sRGB gamut.
+    ///
+    /// The shorter `#rgb` and `#rgba` forms are used when they represent the same color. Colors
+    /// with missing components are not written as hex colors, unless
+    /// [spec conformance](Self::spec_conformant) is enabled. Named colors are still written by
+    /// name.
+    #[must_use]
+    pub const fn hex(mut self, hex: bool) -> Self {
+        self.hex = hex;
+        self
+    }
+
+    /// Use the legacy comma-separated syntax of `rgb()` and `hsl()`, such as
+    /// `rgba(255, 0, 0, 0.5)`, instead of the modern space-separated syntax, such as
+    /// `rgb(255 0 0 / 0.5)`.
+    ///
+    /// This is enabled by default.
+    #[must_use]
+    pub const fn legacy_syntax(mut self, legacy_syntax: bool) -> Self {
+        self.legacy_syntax = legacy_syntax;
+        self
+    }
+
+    /// Write components that can be percentages as percentages.
+    ///
+    /// Hue and alpha are always written as numbers.
+    #[must_use]
+    pub const fn percentages(mut self, percentages: bool) -> Self {
+        self.percentages = percentages;
+        self
+    }
+
+    /// Wrap `color` to serialize it with these options through [`Display`](core::fmt::Display).
+    #[must_use]
+    pub const fn display(self, color: DynamicColor) -> CssDisplay {
+        CssDisplay {
+            serializer: self,
+            color,
+        }
+    }
+
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "rounding happens in f64 to avoid errors from scaling by powers of ten"
+    )]
+    fn write_number(self, value: f32, f: &mut Formatter<'_>) -> Result {
+        let Some(precision) = self.precision else {
+            return write!(f, "{value}");
+        };
+        let mut scale = 1_f64;
+        for _ in 0..precision {
+            scale *= 10.0;
+        }
+        let scaled = f64::from(value) * scale;
+        let rounded = if scaled.is_finite() {
+            (scaled.round() / scale) as f32
+        } else {
+            value
+        };
+        // Adding zero turns negative zero into zero.
+        write!(f, "{}", rounded + 0.0)
+    }
+
+    fn write_component(
+        self,
+        color: &DynamicColor,
+        ix: usize,
+        channel: Channel,
+        f: &mut Formatter<'_>,
+    ) -> Result {
+        let value = if color.flags.missing().contains(ix) {
+            if !self.spec_conformant {
+                return write!(f, "none");
+            }
+            0.0
+        } else {
+            color.components[ix]
+        };
+        match channel {
+            Channel(_, Some(pct_scale)) if self.percentages => {
+                self.write_number(value * pct_scale, f)?;
+                write!(f, "%")
+            }
+            Channel(scale, _) => self.write_number(value * scale, f),
+        }
+    }
+
+    fn write_function(
+        self,
+        color: &DynamicColor,
+        name: &str,
+        syntax: Syntax,
+        channels: [Channel; 3],
+        f: &mut Formatter<'_>,
+    ) -> Result {
+        let alpha_missing = color.flags.missing().contains(3);
+        let has_alpha = alpha_missing || color.components[3] < 1.0;
+        let separator = match syntax {
+            Syntax::Legacy => {
+                let opt_a = if has_alpha { "a" } else { "" };
+                write!(f, "{name}{opt_a}(")?;
+                ", "
+            }
+            Syntax::Modern => {
+                write!(f, "{name}(")?;
+                " "
+            }
+            Syntax::Color => {
+                write!(f, "color({name} ")?;
+                " "
+            }
+        };
+        for (ix, channel) in channels.into_iter().enumerate() {
+            if ix > 0 {
+                write!(f, "{separator}")?;
+            }
+            self.write_component(color, ix, channel, f)?;
+        }
+        if has_alpha {
+            if syntax == Syntax::Legacy {
+                write!(f, ", ")?;
+            } else {
+                write!(f, " / ")?;
+            }
+            if alpha_missing && !self.spec_conformant {
+                write!(f, "none")?;
+            } else {
+                let alpha = if alpha_missing {
+                    0.0
+                } else if self.spec_conformant {
+                    color.components[3].clamp(0.0, 1.0)
+                } else {
+                    color.components[3]
+                };
+                self.write_number(alpha, f)?;
+            }
+        }
+        write!(f, ")")
+    }
+
+    fn write_rgb(self, color: &DynamicColor, f: &mut Formatter<'_>) -> Result {
+        let syntax = if self.legacy_syntax {
+            Syntax::Legacy
+        } else {
+            Syntax::Modern
+        };
+        self.write_function(color, "rgb", syntax, [Channel(255.0, Some(100.0)); 3], f)
+    }
+
+    /// The color as 8-bit sRGB, if it should be written as a hex color.
+    fn hex_rgba8(self, color: &DynamicColor) -> Option<Rgba8> {
+        if !self.spec_conformant && !color.flags.missing().is_empty() {
+            return None;
+        }
+        if !color.is_in_gamut(ColorSpaceTag::Srgb, 1e-4) {
+            return None;
+        }
+        let mut srgb = color.to_alpha_color::<Srgb>();
+        if self.spec_conformant {
+            srgb.components[3] = srgb.components[3].clamp(0.0, 1.0);
+        }
+        Some(srgb.to_rgba8())
+    }
+
+    fn write_color(self, color: &DynamicColor, f: &mut Formatter<'_>) -> Result {
+        if let Some(color_name) = color.flags.color_name() {
             return write!(f, "{color_name}");
         }
 
-        match self.cs {
-            ColorSpaceTag::Srgb if self.flags.named() => {
-                write_legacy_function(self, "rgb", 255.0, f)
+        let lab = [
+            Channel(1.0, Some(1.0)),
+            Channel(1.0, Some(0.8)),
+            Channel(1.0, Some(0.8)),
+        ];
+        let lch = [Channel(1.0, Some(1.0)), Channel(1.0, Some(0.8)), HUE];
+        let oklab = [
+            Channel(1.0, Some(100.0)),
+            Channel(1.0, Some(250.0)),
+            Channel(1.0, Some(250.0)),
+        ];
+        let oklch = [Channel(1.0, Some(100.0)), Channel(1.0, Some(250.0)), HUE];
+        let ictcp = [
+            Channel(1.0, Some(100.0)),
+            Channel(1.0, Some(200.0)),
+            Channel(1.0, Some(200.0)),
+        ];
+        let jzczhz = [Channel(1.0, Some(100.0)), Channel(1.0, Some(100.0)), HUE];
+        let luv = [
+            Channel(1.0, Some(1.0)),
+            Channel(1.0, Some(100.0 / 215.0)),
+            Channel(1.0, Some(100.0 / 215.0)),
+        ];
+        let lchuv = [
+            Channel(1.0, Some(1.0)),
+            Channel(1.0, Some(100.0 / 220.0)),
+            HUE,
+        ];
+        let hsl = [HUE, Channel(1.0, Some(1.0)), Channel(1.0, Some(1.0))];
+        let okhsx = [HUE, Channel(1.0, Some(100.0)), Channel(1.0, Some(100.0))];
+        let rgb = [Channel(1.0, Some(100.0)); 3];
+        let color_function =
+            |name, f: &mut Formatter<'_>| self.write_function(color, name, Syntax::Color, rgb, f);
+
+        if self.hex
+            && matches!(
+                color.cs,
+                ColorSpaceTag::Srgb | ColorSpaceTag::Hsl | ColorSpaceTag::Hwb
+            )
+        {
+            if let Some(rgba8) = self.hex_rgba8(color) {
+                return write_hex(rgba8, f);
+            }
+        }
+
+        match color.cs {
+            ColorSpaceTag::Srgb if color.flags.named() => self.write_rgb(color, f),
+            ColorSpaceTag::Hsl | ColorSpaceTag::Hwb if color.flags.named() => {
+                self.write_rgb(&color.convert(ColorSpaceTag::Srgb), f)
             }
-            ColorSpaceTag::Hsl | ColorSpaceTag::Hwb if self.flags.named() => {
-                let srgb = self.convert(ColorSpaceTag::Srgb);
-                write_legacy_function(&srgb, "rgb", 255.0, f)
+            ColorSpaceTag::Srgb => color_function("srgb", f),
+            ColorSpaceTag::LinearSrgb => color_function("srgb-linear", f),
+            ColorSpaceTag::DisplayP3 => color_function("display-p3", f),
+            ColorSpaceTag::A98Rgb => color_function("a98-rgb", f),
+            ColorSpaceTag::ProphotoRgb => color_function("prophoto-rgb", f),
+            ColorSpaceTag::Rec2020 => color_function("rec2020", f),
+            ColorSpaceTag::Rec2100Pq => color_function("rec2100-pq", f),
+            ColorSpaceTag::Rec2100Hlg => color_function("rec2100-hlg", f),
+            ColorSpaceTag::Aces2065_1 => color_function("--aces2065-1", f),
+            ColorSpaceTag::AcesCg => color_function("--acescg", f),
+            ColorSpaceTag::Hsl => {
+                let syntax = if self.legacy_syntax {
+                    Syntax::Legacy
+                } else {
+                    Syntax::Modern
+                };
+                self.write_function(color, "hsl", syntax, hsl, f)
             }
-            ColorSpaceTag::Srgb => write_color_function(self, "srgb", f),
-            ColorSpaceTag::LinearSrgb => write_color_function(self, "srgb-linear", f),
-            ColorSpaceTag::DisplayP3 => write_color_function(self, "display-p3", f),
-            ColorSpaceTag::A98Rgb => write_color_function(self, "a98-rgb", f),
-            ColorSpaceTag::ProphotoRgb => write_color_function(self, "prophoto-rgb", f),
-            ColorSpaceTag::Rec2020 => write_color_function(self, "rec2020", f),
-            ColorSpaceTag::Aces2065_1 => write_color_function(self, "--aces2065-1", f),
-            ColorSpaceTag::AcesCg => write_color_function(self, "--acescg", f),
-            ColorSpaceTag::Hsl => write_legacy_function(self, "hsl", 1.0, f),
-            ColorSpaceTag::Hwb => write_modern_function(self, "hwb", f),
-            ColorSpaceTag::XyzD50 => write_color_function(self, "xyz-d50", f),
-            ColorSpaceTag::XyzD65 => write_color_function(self, "xyz-d65", f),
-            ColorSpaceTag::Lab => write_modern_function(self, "lab", f),
-            ColorSpaceTag::Lch => write_modern_function(self, "lch", f),
-            ColorSpaceTag::Oklab => write_modern_function(self, "oklab", f),
-            ColorSpaceTag::Oklch => write_modern_function(self, "oklch", f),
+            ColorSpaceTag::Hwb => self.write_function(color, "hwb", Syntax::Modern, hsl, f),
+            ColorSpaceTag::Hsv => self.write_function(color, "--hsv", Syntax::Color, hsl, f),
+            ColorSpaceTag::Okhsl => self.write_function(color, "--okhsl", Syntax::Color, okhsx, f),
+            ColorSpaceTag::Okhsv => self.write_function(color, "--okhsv", Syntax::Color, okhsx, f),
+            ColorSpaceTag::XyzD50 => color_function("xyz-d50", f),
+            ColorSpaceTag::XyzD65 => color_function("xyz-d65", f),
+            ColorSpaceTag::Lab => self.write_function(color, "lab", Syntax::Modern, lab, f),
+            ColorSpaceTag::Lch => self.write_function(color, "lch", Syntax::Modern, lch, f),
+            ColorSpaceTag::Oklab => self.write_function(color, "oklab", Syntax::Modern, oklab, f),
+            ColorSpaceTag::Oklch => self.write_function(color, "oklch", Syntax::Modern, oklch, f),
+            ColorSpaceTag::ICtCp => self.write_function(color, "ictcp", Syntax::Modern, ictcp, f),
+            ColorSpaceTag::Jzazbz => color_function("--jzazbz", f),
+            ColorSpaceTag::JzCzhz => {
+                self.write_function(color, "--jzczhz", Syntax::Color, jzczhz, f)
+            }
+            ColorSpaceTag::Luv => self.write_function(color, "--luv", Syntax::Color, luv, f),
+            ColorSpaceTag::LchUv => self.write_function(color, "--lchuv", Syntax::Color, lchuv, f),
+            ColorSpaceTag::Hsluv => self.write_function(color, "--hsluv", Syntax::Color, hsl, f),
+            ColorSpaceTag::Hpluv => self.write_function(color, "--hpluv", Syntax::Color, hsl, f),
+        }
+    }
+}
+
+impl Default for CssSerializer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Write a lowercase hex color, using the short form if possible.
+fn write_hex(rgba8: Rgba8, f: &mut Formatter<'_>) -> Result {
+    let Rgba8 { r, g, b, a } = rgba8;
+    // A byte can be written as a single hex digit if both of its digits are the same.
+    if [r, g, b, a].iter().all(|x| x % 17 == 0) {
+        write!(f, "#{:x}{:x}{:x}", r / 17, g / 17, b / 17)?;
+        if a != 255 {
+            write!(f, "{:x}", a / 17)?;
         }
+        Ok(())
+    } else {
+        write!(f, "{rgba8:x}")
+    }
+}
+
+impl core::fmt::Display for CssDisplay {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        self.serializer.write_color(&self.color, f)
+    }
+}
+
+impl core::fmt::Display for DynamicColor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        CssSerializer::new().write_color(self, f)
     }
 }
 
@@ -152,7 +441,7 @@ impl core::fmt::UpperHex for Rgba8 {
 mod tests {
     extern crate alloc;
 
-    use crate::{AlphaColor, DynamicColor, Hsl, Oklab, Srgb, XyzD65, parse_color};
+    use crate::{AlphaColor, CssSerializer, DynamicColor, Hsl, Oklab, Srgb, XyzD65, parse_color};
     use alloc::format;
 
     #[test]
@@ -184,6 +473,31 @@ mod tests {
             ("color(srgb 1.0 1.0 1.0)", "color(srgb 1 1 1)"),
             ("oklab(0.4 0.2 -0.2)", "oklab(0.4 0.2 -0.2)"),
             ("lab(20% 0 60)", "lab(20 0 60)"),
+            (
+                "color(rec2100-pq 0.58 0.5 0.2)",
+                "color(rec2100-pq 0.58 0.5 0.2)",
+            ),
+            ("color(rec2100-hlg 75% 1 0)", "color(rec2100-hlg 0.75 1 0)"),
+            ("ictcp(0.5 0.1 -0.1)", "ictcp(0.5 0.1 -0.1)"),
+            ("ictcp(50% 20% -20%)", "ictcp(0.5 0.1 -0.1)"),
+            (
+                "color(--jzazbz 0.1 0.02 -0.03)",
+                "color(--jzazbz 0.1 0.02 -0.03)",
+            ),
+            (
+                "color(--JzCzhz 0.1 5% 0.5turn)",
+                "color(--jzczhz 0.1 0.05 180)",
+            ),
+            ("color(--luv 50% 50% -10)", "color(--luv 50 107.5 -10)"),
+            ("color(--lchuv 50 20 0.25turn)", "color(--lchuv 50 20 90)"),
+            ("color(--hsluv 120 50% 40)", "color(--hsluv 120 50 40)"),
+            ("color(--hsv 0.5turn 50% 40)", "color(--hsv 180 50 40)"),
+            (
+                "color(--okhsl 0.5turn 50% 0.4)",
+                "color(--okhsl 180 0.5 0.4)",
+            ),
+            ("color(--okhsv 30 1 100%)", "color(--okhsv 30 1 1)"),
+            ("color(--hpluv 120deg 150 40)", "color(--hpluv 120 150 40)"),
         ] {
             let result = format!("{}", parse_color(specified).unwrap());
             assert_eq!(
@@ -249,4 +563,88 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn serializer_options() {
+        let default = CssSerializer::new();
+        let rounded = default.precision(3);
+        let modern = rounded.legacy_syntax(false);
+        let conformant = rounded.spec_conformant(true);
+        let hex = default.hex(true);
+        for (serializer, specified, expected) in [
+            (default, "rgb(10% 20% 30%)", "rgb(25.5, 51, 76.5)"),
+            (
+                rounded,
+                "hsl(120deg 50% 25%)",
+                "rgb(31.875, 95.625, 31.875)",
+            ),
+            (
+                rounded.precision(0),
+                "hsl(120deg 50% 25%)",
+                "rgb(32, 96, 32)",
+            ),
+            (modern, "rgb(255 0 0 / 50%)", "rgb(255 0 0 / 0.5)"),
+            (modern, "rgb(none 0 0)", "rgb(none 0 0)"),
+            (conformant, "rgb(none 0 0 / none)", "rgba(0, 0, 0, 0)"),
+            (conformant, "lab(50 none 10)", "lab(50 0 10)"),
+            (
+                rounded,
+                "oklch(0.62796 0.25768 29.234)",
+                "oklch(0.628 0.258 29.234)",
+            ),
+            (rounded, "oklab(0.5 -0.0001 0)", "oklab(0.5 0 0)"),
+            (
+                rounded.percentages(true),
+                "oklch(0.62796 0.25768 29.234)",
+                "oklch(62.796% 64.42% 29.234)",
+            ),
+            (
+                rounded.percentages(true),
+                "rgb(51 102 0)",
+                "rgb(20%, 40%, 0%)",
+            ),
+            (
+                modern.percentages(true),
+                "hsl(120 50% 25% / 0.5)",
+                "rgb(12.5% 37.5% 12.5% / 0.5)",
+            ),
+            (
+                rounded.percentages(true),
+                "color(display-p3 1 0.5 0)",
+                "color(display-p3 100% 50% 0%)",
+            ),
+            (hex, "rgb(255 0 0)", "#f00"),
+            (hex, "rgb(255 0 0 / 0.5)", "#ff000080"),
+            (hex, "rgb(255 0 0 / 0.4)", "#f006"),
+            (hex, "color(srgb 0.2 0.4 0.6)", "#369"),
+            (hex, "hsl(30deg 90% 60%)", "#f5993d"),
+            (hex, "red", "red"),
+            (hex, "color(srgb 1.2 0 0)", "color(srgb 1.2 0 0)"),
+            (hex, "rgb(255 none 0)", "rgb(255, none, 0)"),
+            (hex.spec_conformant(true), "rgb(255 none 0)", "#f00"),
+            (hex, "lab(50 0 0)", "lab(50 0 0)"),
+        ] {
+            let result = format!("{}", serializer.display(parse_color(specified).unwrap()));
+            assert_eq!(
+                result, expected,
+                "Failed serializing specified color `{specified}` with {serializer:?}."
+            );
+        }
+
+        let color =
+            DynamicColor::from_alpha_color(AlphaColor::<Srgb>::new([0.5, -0.0001, 1.1, 1.5]));
+        assert_eq!(
+            format!("{}", conformant.display(color)),
+            "color(srgb 0.5 0 1.1)"
+        );
+        let color = DynamicColor::from_alpha_color(AlphaColor::<Srgb>::new([0.5, 0.2, 1.1, -0.5]));
+        assert_eq!(
+            format!("{}", conformant.display(color)),
+            "color(srgb 0.5 0.2 1.1 / 0)"
+        );
+        assert_eq!(
+            format!("{}", default.display(color)),
+            "color(srgb 0.5 0.2 1.1 / -0.5)"
+        );
+    }
 }
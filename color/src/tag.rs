@@ -4,8 +4,10 @@
 //! The color space tag enum.
 
 use crate::{
-    A98Rgb, Aces2065_1, AcesCg, Chromaticity, ColorSpace, ColorSpaceLayout, DisplayP3, Hsl, Hwb,
-    Lab, Lch, LinearSrgb, Missing, Oklab, Oklch, ProphotoRgb, Rec2020, Srgb, XyzD50, XyzD65,
+    A98Rgb, Aces2065_1, AcesCg, AdaptationMethod, Chromaticity, ColorSpace, ColorSpaceLayout,
+    DisplayP3, Hpluv, Hsl, Hsluv, Hsv, Hwb, ICtCp, JzCzhz, Jzazbz, Lab, Lch, LchUv, LinearSrgb,
+    Luv, Missing, Okhsl, Okhsv, Oklab, Oklch, ProphotoRgb, Rec2020, Rec2100Hlg, Rec2100Pq, Srgb,
+    XyzD50, XyzD65, gamut, matvecmul,
 };
 
 /// The color space tag for [dynamic colors].
@@ -56,6 +58,30 @@ pub enum ColorSpaceTag {
     XyzD50 = 13,
     /// The [`XyzD65`] color space.
     XyzD65 = 14,
+    /// The [`Rec2100Pq`] color space.
+    Rec2100Pq = 16,
+    /// The [`Rec2100Hlg`] color space.
+    Rec2100Hlg = 17,
+    /// The [`ICtCp`] color space.
+    ICtCp = 18,
+    /// The [`Jzazbz`] color space.
+    Jzazbz = 19,
+    /// The [`JzCzhz`] color space.
+    JzCzhz = 20,
+    /// The [`Luv`] color space.
+    Luv = 21,
+    /// The [`LchUv`] color space.
+    LchUv = 22,
+    /// The [`Hsluv`] color space.
+    Hsluv = 23,
+    /// The [`Hpluv`] color space.
+    Hpluv = 24,
+    /// The [`Hsv`] color space.
+    Hsv = 25,
+    /// The [`Okhsl`] color space.
+    Okhsl = 26,
+    /// The [`Okhsv`] color space.
+    Okhsv = 27,
     // NOTICE: If a new value is added, be sure to modify `MAX_VALUE` in the bytemuck impl. Also
     // note the variants' integer values are not necessarily in order, allowing newly added color
     // space tags to be grouped with related color spaces.
@@ -64,8 +90,14 @@ pub enum ColorSpaceTag {
 impl ColorSpaceTag {
     pub(crate) fn layout(self) -> ColorSpaceLayout {
         match self {
-            Self::Lch | Self::Oklch => ColorSpaceLayout::HueThird,
-            Self::Hsl | Self::Hwb => ColorSpaceLayout::HueFirst,
+            Self::Lch | Self::Oklch | Self::JzCzhz | Self::LchUv => ColorSpaceLayout::HueThird,
+            Self::Hsl
+            | Self::Hwb
+            | Self::Hsv
+            | Self::Hsluv
+            | Self::Hpluv
+            | Self::Okhsl
+            | Self::Okhsv => ColorSpaceLayout::HueFirst,
             _ => ColorSpaceLayout::Rectangular,
         }
     }
@@ -87,6 +119,8 @@ impl ColorSpaceTag {
                     | A98Rgb
                     | ProphotoRgb
                     | Rec2020
+                    | Rec2100Pq
+                    | Rec2100Hlg
                     | Aces2065_1
                     | AcesCg
                     | XyzD50
@@ -96,20 +130,26 @@ impl ColorSpaceTag {
                     | A98Rgb
                     | ProphotoRgb
                     | Rec2020
+                    | Rec2100Pq
+                    | Rec2100Hlg
                     | Aces2065_1
                     | AcesCg
                     | XyzD50
                     | XyzD65
-            ) | (Lab | Oklab, Lab | Oklab)
-                | (Lch | Oklch, Lch | Oklch)
+            ) | (Lab | Oklab | Jzazbz | Luv, Lab | Oklab | Jzazbz | Luv)
+                | (Lch | Oklch | JzCzhz | LchUv, Lch | Oklch | JzCzhz | LchUv)
+                | (Hsl | Hsluv | Hpluv | Okhsl, Hsl | Hsluv | Hpluv | Okhsl)
+                | (Hsv | Okhsv, Hsv | Okhsv)
         )
     }
 
     pub(crate) fn l_missing(self, missing: Missing) -> bool {
         use ColorSpaceTag::*;
         match self {
-            Lab | Lch | Oklab | Oklch => missing.contains(0),
-            Hsl => missing.contains(2),
+            Lab | Lch | Oklab | Oklch | ICtCp | Jzazbz | JzCzhz | Luv | LchUv => {
+                missing.contains(0)
+            }
+            Hsl | Hsluv | Hpluv | Okhsl => missing.contains(2),
             _ => false,
         }
     }
@@ -117,11 +157,11 @@ impl ColorSpaceTag {
     pub(crate) fn set_l_missing(self, missing: &mut Missing, components: &mut [f32; 4]) {
         use ColorSpaceTag::*;
         match self {
-            Lab | Lch | Oklab | Oklch => {
+            Lab | Lch | Oklab | Oklch | ICtCp | Jzazbz | JzCzhz | Luv | LchUv => {
                 missing.insert(0);
                 components[0] = 0.0;
             }
-            Hsl => {
+            Hsl | Hsluv | Hpluv | Okhsl => {
                 missing.insert(2);
                 components[2] = 0.0;
             }
@@ -132,7 +172,8 @@ impl ColorSpaceTag {
     pub(crate) fn c_missing(self, missing: Missing) -> bool {
         use ColorSpaceTag::*;
         match self {
-            Lab | Lch | Oklab | Oklch | Hsl => missing.contains(1),
+            Lab | Lch | Oklab | Oklch | Jzazbz | JzCzhz | Luv | LchUv | Hsl | Hsv | Hsluv
+            | Hpluv | Okhsl | Okhsv => missing.contains(1),
             _ => false,
         }
     }
@@ -140,7 +181,8 @@ impl ColorSpaceTag {
     pub(crate) fn set_c_missing(self, missing: &mut Missing, components: &mut [f32; 4]) {
         use ColorSpaceTag::*;
         match self {
-            Lab | Lch | Oklab | Oklch | Hsl => {
+            Lab | Lch | Oklab | Oklch | Jzazbz | JzCzhz | Luv | LchUv | Hsl | Hsv | Hsluv
+            | Hpluv | Okhsl | Okhsv => {
                 missing.insert(1);
                 components[1] = 0.0;
             }
@@ -170,18 +212,30 @@ impl ColorSpaceTag {
             Self::LinearSrgb => rgb,
             Self::Lab => Lab::from_linear_srgb(rgb),
             Self::Lch => Lch::from_linear_srgb(rgb),
+            Self::Luv => Luv::from_linear_srgb(rgb),
+            Self::LchUv => LchUv::from_linear_srgb(rgb),
+            Self::Hsluv => Hsluv::from_linear_srgb(rgb),
+            Self::Hpluv => Hpluv::from_linear_srgb(rgb),
             Self::Oklab => Oklab::from_linear_srgb(rgb),
             Self::Oklch => Oklch::from_linear_srgb(rgb),
+            Self::Okhsl => Okhsl::from_linear_srgb(rgb),
+            Self::Okhsv => Okhsv::from_linear_srgb(rgb),
+            Self::Jzazbz => Jzazbz::from_linear_srgb(rgb),
+            Self::JzCzhz => JzCzhz::from_linear_srgb(rgb),
             Self::DisplayP3 => DisplayP3::from_linear_srgb(rgb),
             Self::A98Rgb => A98Rgb::from_linear_srgb(rgb),
             Self::ProphotoRgb => ProphotoRgb::from_linear_srgb(rgb),
             Self::Rec2020 => Rec2020::from_linear_srgb(rgb),
+            Self::Rec2100Pq => Rec2100Pq::from_linear_srgb(rgb),
+            Self::Rec2100Hlg => Rec2100Hlg::from_linear_srgb(rgb),
+            Self::ICtCp => ICtCp::from_linear_srgb(rgb),
             Self::Aces2065_1 => Aces2065_1::from_linear_srgb(rgb),
             Self::AcesCg => AcesCg::from_linear_srgb(rgb),
             Self::XyzD50 => XyzD50::from_linear_srgb(rgb),
             Self::XyzD65 => XyzD65::from_linear_srgb(rgb),
             Self::Hsl => Hsl::from_linear_srgb(rgb),
             Self::Hwb => Hwb::from_linear_srgb(rgb),
+            Self::Hsv => Hsv::from_linear_srgb(rgb),
         }
     }
 
@@ -194,18 +248,30 @@ impl ColorSpaceTag {
             Self::LinearSrgb => src,
             Self::Lab => Lab::to_linear_srgb(src),
             Self::Lch => Lch::to_linear_srgb(src),
+            Self::Luv => Luv::to_linear_srgb(src),
+            Self::LchUv => LchUv::to_linear_srgb(src),
+            Self::Hsluv => Hsluv::to_linear_srgb(src),
+            Self::Hpluv => Hpluv::to_linear_srgb(src),
             Self::Oklab => Oklab::to_linear_srgb(src),
             Self::Oklch => Oklch::to_linear_srgb(src),
+            Self::Okhsl => Okhsl::to_linear_srgb(src),
+            Self::Okhsv => Okhsv::to_linear_srgb(src),
+            Self::Jzazbz => Jzazbz::to_linear_srgb(src),
+            Self::JzCzhz => JzCzhz::to_linear_srgb(src),
             Self::DisplayP3 => DisplayP3::to_linear_srgb(src),
             Self::A98Rgb => A98Rgb::to_linear_srgb(src),
             Self::ProphotoRgb => ProphotoRgb::to_linear_srgb(src),
             Self::Rec2020 => Rec2020::to_linear_srgb(src),
+            Self::Rec2100Pq => Rec2100Pq::to_linear_srgb(src),
+            Self::Rec2100Hlg => Rec2100Hlg::to_linear_srgb(src),
+            Self::ICtCp => ICtCp::to_linear_srgb(src),
             Self::Aces2065_1 => Aces2065_1::to_linear_srgb(src),
             Self::AcesCg => AcesCg::to_linear_srgb(src),
             Self::XyzD50 => XyzD50::to_linear_srgb(src),
             Self::XyzD65 => XyzD65::to_linear_srgb(src),
             Self::Hsl => Hsl::to_linear_srgb(src),
             Self::Hwb => Hwb::to_linear_srgb(src),
+            Self::Hsv => Hsv::to_linear_srgb(src),
         }
     }
 
@@ -223,6 +289,16 @@ impl ColorSpaceTag {
             (Self::Hwb, Self::Srgb) => Hwb::convert::<Srgb>(src),
             (Self::Hsl, Self::Hwb) => Hsl::convert::<Hwb>(src),
             (Self::Hwb, Self::Hsl) => Hwb::convert::<Hsl>(src),
+            (Self::Srgb, Self::Hsv) => Srgb::convert::<Hsv>(src),
+            (Self::Hsv, Self::Srgb) => Hsv::convert::<Srgb>(src),
+            (Self::Hsl, Self::Hsv) => Hsl::convert::<Hsv>(src),
+            (Self::Hsv, Self::Hsl) => Hsv::convert::<Hsl>(src),
+            (Self::Hwb, Self::Hsv) => Hwb::convert::<Hsv>(src),
+            (Self::Hsv, Self::Hwb) => Hsv::convert::<Hwb>(src),
+            (Self::Jzazbz, Self::JzCzhz) => Jzazbz::convert::<JzCzhz>(src),
+            (Self::JzCzhz, Self::Jzazbz) => JzCzhz::convert::<Jzazbz>(src),
+            (Self::Luv, Self::LchUv) => Luv::convert::<LchUv>(src),
+            (Self::LchUv, Self::Luv) => LchUv::convert::<Luv>(src),
             _ => target.from_linear_srgb(self.to_linear_srgb(src)),
         }
     }
@@ -239,18 +315,30 @@ impl ColorSpaceTag {
             Self::LinearSrgb => rgb,
             Self::Lab => Lab::from_linear_srgb_absolute(rgb),
             Self::Lch => Lch::from_linear_srgb_absolute(rgb),
+            Self::Luv => Luv::from_linear_srgb_absolute(rgb),
+            Self::LchUv => LchUv::from_linear_srgb_absolute(rgb),
+            Self::Hsluv => Hsluv::from_linear_srgb_absolute(rgb),
+            Self::Hpluv => Hpluv::from_linear_srgb_absolute(rgb),
             Self::Oklab => Oklab::from_linear_srgb_absolute(rgb),
             Self::Oklch => Oklch::from_linear_srgb_absolute(rgb),
+            Self::Okhsl => Okhsl::from_linear_srgb_absolute(rgb),
+            Self::Okhsv => Okhsv::from_linear_srgb_absolute(rgb),
+            Self::Jzazbz => Jzazbz::from_linear_srgb_absolute(rgb),
+            Self::JzCzhz => JzCzhz::from_linear_srgb_absolute(rgb),
             Self::DisplayP3 => DisplayP3::from_linear_srgb_absolute(rgb),
             Self::A98Rgb => A98Rgb::from_linear_srgb_absolute(rgb),
             Self::ProphotoRgb => ProphotoRgb::from_linear_srgb_absolute(rgb),
             Self::Rec2020 => Rec2020::from_linear_srgb_absolute(rgb),
+            Self::Rec2100Pq => Rec2100Pq::from_linear_srgb_absolute(rgb),
+            Self::Rec2100Hlg => Rec2100Hlg::from_linear_srgb_absolute(rgb),
+            Self::ICtCp => ICtCp::from_linear_srgb_absolute(rgb),
             Self::Aces2065_1 => Aces2065_1::from_linear_srgb_absolute(rgb),
             Self::AcesCg => AcesCg::from_linear_srgb_absolute(rgb),
             Self::XyzD50 => XyzD50::from_linear_srgb_absolute(rgb),
             Self::XyzD65 => XyzD65::from_linear_srgb_absolute(rgb),
             Self::Hsl => Hsl::from_linear_srgb_absolute(rgb),
             Self::Hwb => Hwb::from_linear_srgb_absolute(rgb),
+            Self::Hsv => Hsv::from_linear_srgb_absolute(rgb),
         }
     }
 
@@ -266,18 +354,30 @@ impl ColorSpaceTag {
             Self::LinearSrgb => src,
             Self::Lab => Lab::to_linear_srgb_absolute(src),
             Self::Lch => Lch::to_linear_srgb_absolute(src),
+            Self::Luv => Luv::to_linear_srgb_absolute(src),
+            Self::LchUv => LchUv::to_linear_srgb_absolute(src),
+            Self::Hsluv => Hsluv::to_linear_srgb_absolute(src),
+            Self::Hpluv => Hpluv::to_linear_srgb_absolute(src),
             Self::Oklab => Oklab::to_linear_srgb_absolute(src),
             Self::Oklch => Oklch::to_linear_srgb_absolute(src),
+            Self::Okhsl => Okhsl::to_linear_srgb_absolute(src),
+            Self::Okhsv => Okhsv::to_linear_srgb_absolute(src),
+            Self::Jzazbz => Jzazbz::to_linear_srgb_absolute(src),
+            Self::JzCzhz => JzCzhz::to_linear_srgb_absolute(src),
             Self::DisplayP3 => DisplayP3::to_linear_srgb_absolute(src),
             Self::A98Rgb => A98Rgb::to_linear_srgb_absolute(src),
             Self::ProphotoRgb => ProphotoRgb::to_linear_srgb_absolute(src),
             Self::Rec2020 => Rec2020::to_linear_srgb_absolute(src),
+            Self::Rec2100Pq => Rec2100Pq::to_linear_srgb_absolute(src),
+            Self::Rec2100Hlg => Rec2100Hlg::to_linear_srgb_absolute(src),
+            Self::ICtCp => ICtCp::to_linear_srgb_absolute(src),
             Self::Aces2065_1 => Aces2065_1::to_linear_srgb_absolute(src),
             Self::AcesCg => AcesCg::to_linear_srgb_absolute(src),
             Self::XyzD50 => XyzD50::to_linear_srgb_absolute(src),
             Self::XyzD65 => XyzD65::to_linear_srgb_absolute(src),
             Self::Hsl => Hsl::to_linear_srgb_absolute(src),
             Self::Hwb => Hwb::to_linear_srgb_absolute(src),
+            Self::Hsv => Hsv::to_linear_srgb_absolute(src),
         }
     }
 
@@ -303,6 +403,16 @@ impl ColorSpaceTag {
             (Self::Hwb, Self::Srgb) => Hwb::convert_absolute::<Srgb>(src),
             (Self::Hsl, Self::Hwb) => Hsl::convert_absolute::<Hwb>(src),
             (Self::Hwb, Self::Hsl) => Hwb::convert_absolute::<Hsl>(src),
+            (Self::Srgb, Self::Hsv) => Srgb::convert_absolute::<Hsv>(src),
+            (Self::Hsv, Self::Srgb) => Hsv::convert_absolute::<Srgb>(src),
+            (Self::Hsl, Self::Hsv) => Hsl::convert_absolute::<Hsv>(src),
+            (Self::Hsv, Self::Hsl) => Hsv::convert_absolute::<Hsl>(src),
+            (Self::Hwb, Self::Hsv) => Hwb::convert_absolute::<Hsv>(src),
+            (Self::Hsv, Self::Hwb) => Hsv::convert_absolute::<Hwb>(src),
+            (Self::Jzazbz, Self::JzCzhz) => Jzazbz::convert_absolute::<JzCzhz>(src),
+            (Self::JzCzhz, Self::Jzazbz) => JzCzhz::convert_absolute::<Jzazbz>(src),
+            (Self::Luv, Self::LchUv) => Luv::convert_absolute::<LchUv>(src),
+            (Self::LchUv, Self::Luv) => LchUv::convert_absolute::<Luv>(src),
             _ => target.from_linear_srgb_absolute(self.to_linear_srgb_absolute(src)),
         }
     }
@@ -325,19 +435,57 @@ impl ColorSpaceTag {
             Self::LinearSrgb => LinearSrgb::chromatically_adapt(src, from, to),
             Self::Lab => Lab::chromatically_adapt(src, from, to),
             Self::Lch => Lch::chromatically_adapt(src, from, to),
+            Self::Luv => Luv::chromatically_adapt(src, from, to),
+            Self::LchUv => LchUv::chromatically_adapt(src, from, to),
+            Self::Hsluv => Hsluv::chromatically_adapt(src, from, to),
+            Self::Hpluv => Hpluv::chromatically_adapt(src, from, to),
             Self::Oklab => Oklab::chromatically_adapt(src, from, to),
             Self::Oklch => Oklch::chromatically_adapt(src, from, to),
+            Self::Okhsl => Okhsl::chromatically_adapt(src, from, to),
+            Self::Okhsv => Okhsv::chromatically_adapt(src, from, to),
+            Self::Jzazbz => Jzazbz::chromatically_adapt(src, from, to),
+            Self::JzCzhz => JzCzhz::chromatically_adapt(src, from, to),
             Self::DisplayP3 => DisplayP3::chromatically_adapt(src, from, to),
             Self::A98Rgb => A98Rgb::chromatically_adapt(src, from, to),
             Self::ProphotoRgb => ProphotoRgb::chromatically_adapt(src, from, to),
             Self::Rec2020 => Rec2020::chromatically_adapt(src, from, to),
+            Self::Rec2100Pq => Rec2100Pq::chromatically_adapt(src, from, to),
+            Self::Rec2100Hlg => Rec2100Hlg::chromatically_adapt(src, from, to),
+            Self::ICtCp => ICtCp::chromatically_adapt(src, from, to),
             Self::Aces2065_1 => Aces2065_1::chromatically_adapt(src, from, to),
             Self::AcesCg => AcesCg::chromatically_adapt(src, from, to),
             Self::XyzD50 => XyzD50::chromatically_adapt(src, from, to),
             Self::XyzD65 => XyzD65::chromatically_adapt(src, from, to),
             Self::Hsl => Hsl::chromatically_adapt(src, from, to),
             Self::Hwb => Hwb::chromatically_adapt(src, from, to),
+            Self::Hsv => Hsv::chromatically_adapt(src, from, to),
+        }
+    }
+
+    /// Chromatically adapt the color between the given white point chromaticities, using the given
+    /// chromatic adaptation transform and degree of adaptation.
+    ///
+    /// This is the tagged counterpart of [`ColorSpace::chromatically_adapt_with`].
+    pub fn chromatically_adapt_with(
+        self,
+        src: [f32; 3],
+        from: Chromaticity,
+        to: Chromaticity,
+        method: AdaptationMethod,
+        degree: f32,
+    ) -> [f32; 3] {
+        if method == AdaptationMethod::Bradford && degree == 1. {
+            return self.chromatically_adapt(src, from, to);
+        }
+        if from == to {
+            return src;
         }
+
+        let lin_srgb_adapted = matvecmul(
+            &from.linear_srgb_adaptation_matrix(to, method, degree),
+            self.to_linear_srgb_absolute(src),
+        );
+        self.from_linear_srgb_absolute(lin_srgb_adapted)
     }
 
     /// Scale the chroma by the given amount.
@@ -346,8 +494,17 @@ impl ColorSpaceTag {
     pub fn scale_chroma(self, src: [f32; 3], scale: f32) -> [f32; 3] {
         match self {
             Self::LinearSrgb => LinearSrgb::scale_chroma(src, scale),
-            Self::Oklab | Self::Lab => Oklab::scale_chroma(src, scale),
-            Self::Oklch | Self::Lch | Self::Hsl => Oklch::scale_chroma(src, scale),
+            Self::Oklab | Self::Lab | Self::Jzazbz | Self::Luv => Oklab::scale_chroma(src, scale),
+            Self::Oklch
+            | Self::Lch
+            | Self::JzCzhz
+            | Self::LchUv
+            | Self::Hsl
+            | Self::Hsv
+            | Self::Hsluv
+            | Self::Hpluv
+            | Self::Okhsl
+            | Self::Okhsv => Oklch::scale_chroma(src, scale),
             _ => {
                 let rgb = self.to_linear_srgb(src);
                 let scaled = LinearSrgb::scale_chroma(rgb, scale);
@@ -356,6 +513,45 @@ impl ColorSpaceTag {
         }
     }
 
+    /// Map an opaque color into the gamut of the color space.
+    ///
+    /// The color is given both as `src`, its components in this color space, and as `origin`,
+    /// its components in [`Oklch`].
+    ///
+    /// See [`DynamicColor::map_to_gamut`](crate::DynamicColor::map_to_gamut) for more details.
+    pub(crate) fn map_to_gamut(self, src: [f32; 3], origin: [f32; 3]) -> [f32; 3] {
+        match self {
+            Self::Srgb => gamut::map_to_gamut::<Srgb>(src, origin),
+            Self::LinearSrgb => gamut::map_to_gamut::<LinearSrgb>(src, origin),
+            Self::Lab => gamut::map_to_gamut::<Lab>(src, origin),
+            Self::Lch => gamut::map_to_gamut::<Lch>(src, origin),
+            Self::Luv => gamut::map_to_gamut::<Luv>(src, origin),
+            Self::LchUv => gamut::map_to_gamut::<LchUv>(src, origin),
+            Self::Hsluv => gamut::map_to_gamut::<Hsluv>(src, origin),
+            Self::Hpluv => gamut::map_to_gamut::<Hpluv>(src, origin),
+            Self::Oklab => gamut::map_to_gamut::<Oklab>(src, origin),
+            Self::Oklch => gamut::map_to_gamut::<Oklch>(src, origin),
+            Self::Okhsl => gamut::map_to_gamut::<Okhsl>(src, origin),
+            Self::Okhsv => gamut::map_to_gamut::<Okhsv>(src, origin),
+            Self::Jzazbz => gamut::map_to_gamut::<Jzazbz>(src, origin),
+            Self::JzCzhz => gamut::map_to_gamut::<JzCzhz>(src, origin),
+            Self::DisplayP3 => gamut::map_to_gamut::<DisplayP3>(src, origin),
+            Self::A98Rgb => gamut::map_to_gamut::<A98Rgb>(src, origin),
+            Self::ProphotoRgb => gamut::map_to_gamut::<ProphotoRgb>(src, origin),
+            Self::Rec2020 => gamut::map_to_gamut::<Rec2020>(src, origin),
+            Self::Rec2100Pq => gamut::map_to_gamut::<Rec2100Pq>(src, origin),
+            Self::Rec2100Hlg => gamut::map_to_gamut::<Rec2100Hlg>(src, origin),
+            Self::ICtCp => gamut::map_to_gamut::<ICtCp>(src, origin),
+            Self::Aces2065_1 => gamut::map_to_gamut::<Aces2065_1>(src, origin),
+            Self::AcesCg => gamut::map_to_gamut::<AcesCg>(src, origin),
+            Self::XyzD50 => gamut::map_to_gamut::<XyzD50>(src, origin),
+            Self::XyzD65 => gamut::map_to_gamut::<XyzD65>(src, origin),
+            Self::Hsl => gamut::map_to_gamut::<Hsl>(src, origin),
+            Self::Hwb => gamut::map_to_gamut::<Hwb>(src, origin),
+            Self::Hsv => gamut::map_to_gamut::<Hsv>(src, origin),
+        }
+    }
+
     /// Clip the color's components to fit within the natural gamut of the color space.
     ///
     /// See [`ColorSpace::clip`] for more details.
@@ -365,18 +561,66 @@ impl ColorSpaceTag {
             Self::LinearSrgb => LinearSrgb::clip(src),
             Self::Lab => Lab::clip(src),
             Self::Lch => Lch::clip(src),
+            Self::Luv => Luv::clip(src),
+            Self::LchUv => LchUv::clip(src),
+            Self::Hsluv => Hsluv::clip(src),
+            Self::Hpluv => Hpluv::clip(src),
             Self::Oklab => Oklab::clip(src),
             Self::Oklch => Oklch::clip(src),
+            Self::Okhsl => Okhsl::clip(src),
+            Self::Okhsv => Okhsv::clip(src),
+            Self::Jzazbz => Jzazbz::clip(src),
+            Self::JzCzhz => JzCzhz::clip(src),
             Self::DisplayP3 => DisplayP3::clip(src),
             Self::A98Rgb => A98Rgb::clip(src),
             Self::ProphotoRgb => ProphotoRgb::clip(src),
             Self::Rec2020 => Rec2020::clip(src),
+            Self::Rec2100Pq => Rec2100Pq::clip(src),
+            Self::Rec2100Hlg => Rec2100Hlg::clip(src),
+            Self::ICtCp => ICtCp::clip(src),
             Self::Aces2065_1 => Aces2065_1::clip(src),
             Self::AcesCg => AcesCg::clip(src),
             Self::XyzD50 => XyzD50::clip(src),
             Self::XyzD65 => XyzD65::clip(src),
             Self::Hsl => Hsl::clip(src),
             Self::Hwb => Hwb::clip(src),
+            Self::Hsv => Hsv::clip(src),
+        }
+    }
+
+    /// Whether the color is within the natural gamut of the color space.
+    ///
+    /// This is the tagged counterpart of [`ColorSpace::is_in_gamut`].
+    pub fn is_in_gamut(self, src: [f32; 3], epsilon: f32) -> bool {
+        match self {
+            Self::Srgb => Srgb::is_in_gamut(src, epsilon),
+            Self::LinearSrgb => LinearSrgb::is_in_gamut(src, epsilon),
+            Self::Lab => Lab::is_in_gamut(src, epsilon),
+            Self::Lch => Lch::is_in_gamut(src, epsilon),
+            Self::Luv => Luv::is_in_gamut(src, epsilon),
+            Self::LchUv => LchUv::is_in_gamut(src, epsilon),
+            Self::Hsluv => Hsluv::is_in_gamut(src, epsilon),
+            Self::Hpluv => Hpluv::is_in_gamut(src, epsilon),
+            Self::Oklab => Oklab::is_in_gamut(src, epsilon),
+            Self::Oklch => Oklch::is_in_gamut(src, epsilon),
+            Self::Okhsl => Okhsl::is_in_gamut(src, epsilon),
+            Self::Okhsv => Okhsv::is_in_gamut(src, epsilon),
+            Self::Jzazbz => Jzazbz::is_in_gamut(src, epsilon),
+            Self::JzCzhz => JzCzhz::is_in_gamut(src, epsilon),
+            Self::DisplayP3 => DisplayP3::is_in_gamut(src, epsilon),
+            Self::A98Rgb => A98Rgb::is_in_gamut(src, epsilon),
+            Self::ProphotoRgb => ProphotoRgb::is_in_gamut(src, epsilon),
+            Self::Rec2020 => Rec2020::is_in_gamut(src, epsilon),
+            Self::Rec2100Pq => Rec2100Pq::is_in_gamut(src, epsilon),
+            Self::Rec2100Hlg => Rec2100Hlg::is_in_gamut(src, epsilon),
+            Self::ICtCp => ICtCp::is_in_gamut(src, epsilon),
+            Self::Aces2065_1 => Aces2065_1::is_in_gamut(src, epsilon),
+            Self::AcesCg => AcesCg::is_in_gamut(src, epsilon),
+            Self::XyzD50 => XyzD50::is_in_gamut(src, epsilon),
+            Self::XyzD65 => XyzD65::is_in_gamut(src, epsilon),
+            Self::Hsl => Hsl::is_in_gamut(src, epsilon),
+            Self::Hwb => Hwb::is_in_gamut(src, epsilon),
+            Self::Hsv => Hsv::is_in_gamut(src, epsilon),
         }
     }
 }
@@ -0,0 +1,456 @@
+// Copyright 2025 the Color Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Y′CbCr encodings of video.
+//!
+//! Digital video is usually not stored as R′G′B′, but as a luma component `Y′` and two chroma
+//! difference components `Cb` and `Cr`. This module converts between the gamma-encoded R′G′B′
+//! of [`Srgb`] or [`Rec2020`] and Y′CbCr:
+//!
+//! - [`YCbCrMatrix`] selects the encoding of ITU-R BT.601, BT.709 or BT.2020, and converts
+//!   between R′G′B′ and Y′CbCr with floating point components. `Y′` ranges from 0 to 1, `Cb` and
+//!   `Cr` from -0.5 to 0.5.
+//! - [`YCbCrFormat`] adds a [`Range`] and a bit depth, and converts between R′G′B′ and integer
+//!   code values as found in decoded video frames.
+//!
+//! The R′G′B′ of BT.601 and BT.709 is taken to be sRGB, and that of BT.2020 to be [`Rec2020`].
+//! BT.709 shares its primaries with sRGB. The primaries of BT.601 differ slightly, but its video
+//! is commonly displayed as sRGB.
+//!
+//! # Example
+//!
+//! ```rust
+//! use color::ycbcr::{Range, YCbCrFormat, YCbCrMatrix};
+//! use color::{AlphaColor, Srgb};
+//!
+//! // A pixel of an 8-bit BT.709 video frame in limited range.
+//! let format = YCbCrFormat::new(YCbCrMatrix::Bt709, Range::Limited, 8);
+//! let rgb = format.decode([235, 128, 128]);
+//! let color = AlphaColor::<Srgb>::new([rgb[0], rgb[1], rgb[2], 1.]);
+//! assert_eq!(color.to_rgba8().to_u32(), 0xffffffff);
+//! ```
+
+use crate::colorspace::{lin_to_rec2020, rec2020_to_lin};
+use crate::{AlphaColor, ColorSpace, Rec2020, Srgb};
+
+#[cfg(all(not(feature = "std"), not(test)))]
+use crate::floatfuncs::FloatFuncs;
+
+/// The matrix converting between R′G′B′ and Y′CbCr.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum YCbCrMatrix {
+    /// The encoding of ITU-R BT.601, used by standard definition video and JPEG.
+    Bt601,
+    /// The encoding of ITU-R BT.709, used by high definition video.
+    Bt709,
+    /// The non-constant luminance encoding of ITU-R BT.2020, used by ultra high definition video.
+    Bt2020Ncl,
+    /// The constant luminance encoding of ITU-R BT.2020.
+    ///
+    /// Unlike the other encodings, the luma `Y′` is computed from the linear components and then
+    /// gamma-encoded, so it is exactly the encoded luminance of the color.
+    Bt2020Cl,
+}
+
+/// The range of integer code values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Range {
+    /// Full range, using all code values.
+    ///
+    /// This is used by JPEG and most computer-generated content.
+    Full,
+    /// Limited range, also known as studio or TV range.
+    ///
+    /// At 8 bits, `Y′` ranges from 16 to 235, and `Cb` and `Cr` from 16 to 240. The code values
+    /// outside of these ranges leave headroom and footroom for filtering. This is the range of
+    /// most video.
+    Limited,
+}
+
+/// The luma coefficients of BT.2020, also used for its constant luminance encoding.
+const BT2020_LUMA: [f32; 3] = [0.2627, 0.678, 0.0593];
+
+/// The divisors of the constant luminance chroma components of BT.2020, for negative and positive
+/// differences `B′ - Y′` and `R′ - Y′`.
+const BT2020_CL_CB: (f32, f32) = (1.9404, 1.5816);
+const BT2020_CL_CR: (f32, f32) = (1.7184, 0.9936);
+
+impl YCbCrMatrix {
+    /// The coefficients `[Kr, Kg, Kb]` weighting `R′`, `G′` and `B′` in the luma `Y′`.
+    ///
+    /// For [`YCbCrMatrix::Bt2020Cl`], these weight the linear components in the luminance.
+    #[must_use]
+    pub const fn luma_coefficients(self) -> [f32; 3] {
+        match self {
+            Self::Bt601 => [0.299, 0.587, 0.114],
+            Self::Bt709 => [0.2126, 0.7152, 0.0722],
+            Self::Bt2020Ncl | Self::Bt2020Cl => BT2020_LUMA,
+        }
+    }
+
+    /// Convert R′G′B′ to Y′CbCr.
+    ///
+    /// `Y′` is between 0 and 1, and `Cb` and `Cr` are between -0.5 and 0.5 for R′G′B′ components
+    /// between 0 and 1.
+    #[must_use]
+    pub fn encode(self, [r, g, b]: [f32; 3]) -> [f32; 3] {
+        if self == Self::Bt2020Cl {
+            let [kr, kg, kb] = BT2020_LUMA;
+            let [lr, lg, lb] = [r, g, b].map(rec2020_to_lin);
+            let y = lin_to_rec2020(kr * lr + kg * lg + kb * lb);
+            let chroma = |difference: f32, (negative, positive): (f32, f32)| {
+                difference / if difference <= 0. { negative } else { positive }
+            };
+            return [y, chroma(b - y, BT2020_CL_CB), chroma(r - y, BT2020_CL_CR)];
+        }
+        let [kr, kg, kb] = self.luma_coefficients();
+        let y = kr * r + kg * g + kb * b;
+        [y, (b - y) / (2. * (1. - kb)), (r - y) / (2. * (1. - kr))]
+    }
+
+    /// Convert Y′CbCr to R′G′B′.
+    ///
+    /// This is the inverse of [`YCbCrMatrix::encode`].
+    #[must_use]
+    pub fn decode(self, [y, cb, cr]: [f32; 3]) -> [f32; 3] {
+        if self == Self::Bt2020Cl {
+            let [kr, kg, kb] = BT2020_LUMA;
+            let difference = |chroma: f32, (negative, positive): (f32, f32)| {
+                chroma * if chroma <= 0. { negative } else { positive }
+            };
+            let b = y + difference(cb, BT2020_CL_CB);
+            let r = y + difference(cr, BT2020_CL_CR);
+            let [ly, lr, lb] = [y, r, b].map(rec2020_to_lin);
+            let g = lin_to_rec2020((ly - kr * lr - kb * lb) / kg);
+            return [r, g, b];
+        }
+        let [kr, kg, kb] = self.luma_coefficients();
+        let r = y + 2. * (1. - kr) * cr;
+        let b = y + 2. * (1. - kb) * cb;
+        let g = (y - kr * r - kb * b) / kg;
+        [r, g, b]
+    }
+
+    /// Convert R′G′B′ pixels to Y′CbCr in place.
+    ///
+    /// See [`YCbCrMatrix::encode`].
+    pub fn encode_slice(self, pixels: &mut [[f32; 3]]) {
+        for pixel in pixels {
+            *pixel = self.encode(*pixel);
+        }
+    }
+
+    /// Convert Y′CbCr pixels to R′G′B′ in place.
+    ///
+    /// See [`YCbCrMatrix::decode`].
+    pub fn decode_slice(self, pixels: &mut [[f32; 3]]) {
+        for pixel in pixels {
+            *pixel = self.decode(*pixel);
+        }
+    }
+
+    /// Convert a color to Y′CbCr, keeping its alpha.
+    ///
+    /// The color is first converted to the R′G′B′ of this encoding: [`Srgb`] for BT.601 and
+    /// BT.709, and [`Rec2020`] for BT.2020. This is free if the color already is in that color
+    /// space.
+    ///
+    /// ```rust
+    /// use color::ycbcr::YCbCrMatrix;
+    /// use color::{AlphaColor, Srgb};
+    ///
+    /// let [y, cb, cr, alpha] = YCbCrMatrix::Bt709.encode_color(AlphaColor::<Srgb>::WHITE);
+    /// assert!((y - 1.).abs() < 1e-6 && cb.abs() < 1e-6 && cr.abs() < 1e-6);
+    /// ```
+    #[must_use]
+    pub fn encode_color<CS: ColorSpace>(self, color: AlphaColor<CS>) -> [f32; 4] {
+        let [r, g, b, alpha] = match self {
+            Self::Bt601 | Self::Bt709 => color.convert::<Srgb>().components,
+            Self::Bt2020Ncl | Self::Bt2020Cl => color.convert::<Rec2020>().components,
+        };
+        let [y, cb, cr] = self.encode([r, g, b]);
+        [y, cb, cr, alpha]
+    }
+
+    /// Convert Y′CbCr with alpha to a color.
+    ///
+    /// This is the inverse of [`YCbCrMatrix::encode_color`].
+    #[must_use]
+    pub fn decode_color<CS: ColorSpace>(self, [y, cb, cr, alpha]: [f32; 4]) -> AlphaColor<CS> {
+        let [r, g, b] = self.decode([y, cb, cr]);
+        let components = [r, g, b, alpha];
+        match self {
+            Self::Bt601 | Self::Bt709 => AlphaColor::<Srgb>::new(components).convert(),
+            Self::Bt2020Ncl | Self::Bt2020Cl => AlphaColor::<Rec2020>::new(components).convert(),
+        }
+    }
+}
+
+/// An integer Y′CbCr format: an encoding, a range and a bit depth.
+///
+/// The code values follow ITU-R BT.2100. In limited range, `Y′` maps 0 and 1 to 16 and 235, and
+/// `Cb` and `Cr` map -0.5 and 0.5 to 16 and 240, scaled by 2<sup>bit depth - 8</sup>. In full
+/// range, `Y′` maps 0 and 1 to 0 and 2<sup>bit depth</sup> - 1, and `Cb` and `Cr` are scaled
+/// likewise around 2<sup>bit depth - 1</sup>.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct YCbCrFormat {
+    matrix: YCbCrMatrix,
+    range: Range,
+    bit_depth: u8,
+}
+
+impl YCbCrFormat {
+    /// Create an integer Y′CbCr format.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bit depth is not between 8 and 16. Common bit depths are 8, 10 and 12.
+    #[must_use]
+    pub const fn new(matrix: YCbCrMatrix, range: Range, bit_depth: u8) -> Self {
+        assert!(
+            8 <= bit_depth && bit_depth <= 16,
+            "the bit depth must be between 8 and 16"
+        );
+        Self {
+            matrix,
+            range,
+            bit_depth,
+        }
+    }
+
+    /// The encoding of the format.
+    #[must_use]
+    pub const fn matrix(self) -> YCbCrMatrix {
+        self.matrix
+    }
+
+    /// The range of the code values.
+    #[must_use]
+    pub const fn range(self) -> Range {
+        self.range
+    }
+
+    /// The number of bits per component.
+    #[must_use]
+    pub const fn bit_depth(self) -> u8 {
+        self.bit_depth
+    }
+
+    /// The scale and offset of `Y′`, and the scale and offset of `Cb` and `Cr`.
+    fn scale_offset(self) -> ([f32; 2], [f32; 2]) {
+        let n = self.bit_depth;
+        let max = ((1_u32 << n) - 1) as f32;
+        let mid = (1_u32 << (n - 1)) as f32;
+        match self.range {
+            Range::Full => ([max, 0.], [max, mid]),
+            Range::Limited => {
+                let step = (1_u32 << (n - 8)) as f32;
+                ([219. * step, 16. * step], [224. * step, mid])
+            }
+        }
+    }
+
+    /// Quantize floating point Y′CbCr to code values.
+    ///
+    /// The code values are rounded and clamped to the range of the bit depth.
+    #[must_use]
+    #[expect(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        reason = "the value is rounded and clamped to the range of u16"
+    )]
+    pub fn quantize(self, [y, cb, cr]: [f32; 3]) -> [u16; 3] {
+        let ([y_scale, y_offset], [c_scale, c_offset]) = self.scale_offset();
+        let max = ((1_u32 << self.bit_depth) - 1) as f32;
+        let quantize =
+            |x: f32, scale: f32, offset: f32| (x * scale + offset).round().clamp(0., max);
+        [
+            quantize(y, y_scale, y_offset) as u16,
+            quantize(cb, c_scale, c_offset) as u16,
+            quantize(cr, c_scale, c_offset) as u16,
+        ]
+    }
+
+    /// Convert code values to floating point Y′CbCr.
+    ///
+    /// This is the inverse of [`YCbCrFormat::quantize`], up to rounding. Code values in the
+    /// headroom and footroom of limited range result in components outside of their nominal
+    /// range.
+    #[must_use]
+    pub fn dequantize(self, [y, cb, cr]: [u16; 3]) -> [f32; 3] {
+        let ([y_scale, y_offset], [c_scale, c_offset]) = self.scale_offset();
+        [
+            (f32::from(y) - y_offset) / y_scale,
+            (f32::from(cb) - c_offset) / c_scale,
+            (f32::from(cr) - c_offset) / c_scale,
+        ]
+    }
+
+    /// Convert R′G′B′ to code values.
+    ///
+    /// See [`YCbCrMatrix::encode`] and [`YCbCrFormat::quantize`].
+    #[must_use]
+    pub fn encode(self, rgb: [f32; 3]) -> [u16; 3] {
+        self.quantize(self.matrix.encode(rgb))
+    }
+
+    /// Convert code values to R′G′B′.
+    ///
+    /// See [`YCbCrFormat::dequantize`] and [`YCbCrMatrix::decode`].
+    #[must_use]
+    pub fn decode(self, ycbcr: [u16; 3]) -> [f32; 3] {
+        self.matrix.decode(self.dequantize(ycbcr))
+    }
+
+    /// Convert R′G′B′ pixels to code values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slices have different lengths.
+    pub fn encode_slice(self, rgb: &[[f32; 3]], ycbcr: &mut [[u16; 3]]) {
+        assert_eq!(
+            rgb.len(),
+            ycbcr.len(),
+            "the slices must have the same length"
+        );
+        for (src, dst) in rgb.iter().zip(ycbcr) {
+            *dst = self.encode(*src);
+        }
+    }
+
+    /// Convert code values to R′G′B′ pixels.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slices have different lengths.
+    pub fn decode_slice(self, ycbcr: &[[u16; 3]], rgb: &mut [[f32; 3]]) {
+        assert_eq!(
+            ycbcr.len(),
+            rgb.len(),
+            "the slices must have the same length"
+        );
+        for (src, dst) in ycbcr.iter().zip(rgb) {
+            *dst = self.decode(*src);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Range, YCbCrFormat, YCbCrMatrix};
+    use crate::{AlphaColor, OpaqueColor, Rec2020, Srgb};
+
+    const MATRICES: [YCbCrMatrix; 4] = [
+        YCbCrMatrix::Bt601,
+        YCbCrMatrix::Bt709,
+        YCbCrMatrix::Bt2020Ncl,
+        YCbCrMatrix::Bt2020Cl,
+    ];
+
+    fn assert_close(actual: [f32; 3], expected: [f32; 3], epsilon: f32) {
+        assert!(
+            OpaqueColor::<Srgb>::new(actual).difference(OpaqueColor::new(expected)) < epsilon,
+            "expected {expected:?}, got {actual:?}"
+        );
+    }
+
+    #[test]
+    fn encode() {
+        for matrix in MATRICES {
+            assert_close(matrix.encode([1., 1., 1.]), [1., 0., 0.], 1e-6);
+            assert_close(matrix.encode([0., 0., 0.]), [0., 0., 0.], 1e-6);
+            // The chroma components reach their extremes at blue and red.
+            assert!((matrix.encode([0., 0., 1.])[1] - 0.5).abs() < 1e-3);
+            assert!((matrix.encode([1., 0., 0.])[2] - 0.5).abs() < 1e-3);
+        }
+        assert_close(
+            YCbCrMatrix::Bt709.encode([1., 0., 0.]),
+            [0.2126, -0.114_572, 0.5],
+            1e-5,
+        );
+        assert_close(
+            YCbCrMatrix::Bt601.encode([0., 1., 0.]),
+            [0.587, -0.331_264, -0.418_688],
+            1e-5,
+        );
+    }
+
+    #[test]
+    fn roundtrip() {
+        for matrix in MATRICES {
+            let mut pixels = [
+                [0.2, 0.4, 0.6],
+                [0.9, 0.1, 0.5],
+                [0., 1., 0.],
+                [0.5, 0.5, 0.5],
+            ];
+            let original = pixels;
+            matrix.encode_slice(&mut pixels);
+            matrix.decode_slice(&mut pixels);
+            for (actual, expected) in pixels.into_iter().zip(original) {
+                assert_close(actual, expected, 1e-5);
+            }
+        }
+    }
+
+    #[test]
+    fn colors() {
+        let color = AlphaColor::<Srgb>::new([0.9, 0.1, 0.5, 0.5]);
+        let ycbcr = YCbCrMatrix::Bt709.encode_color(color);
+        assert_eq!(ycbcr[3], 0.5);
+        assert_close(
+            [ycbcr[0], ycbcr[1], ycbcr[2]],
+            YCbCrMatrix::Bt709.encode([0.9, 0.1, 0.5]),
+            1e-6,
+        );
+        let decoded = YCbCrMatrix::Bt709.decode_color::<Srgb>(ycbcr);
+        assert!(decoded.difference(color) < 1e-5);
+
+        // BT.2020 encodes the components of Rec. 2020.
+        let ycbcr = YCbCrMatrix::Bt2020Ncl.encode_color(color);
+        let rec2020 = color.convert::<Rec2020>().components;
+        assert_close(
+            [ycbcr[0], ycbcr[1], ycbcr[2]],
+            YCbCrMatrix::Bt2020Ncl.encode([rec2020[0], rec2020[1], rec2020[2]]),
+            1e-6,
+        );
+        let decoded = YCbCrMatrix::Bt2020Ncl.decode_color::<Srgb>(ycbcr);
+        assert!(decoded.difference(color) < 1e-5);
+    }
+
+    #[test]
+    fn code_values() {
+        for (range, bit_depth, white, black) in [
+            (Range::Limited, 8, [235, 128, 128], [16, 128, 128]),
+            (Range::Limited, 10, [940, 512, 512], [64, 512, 512]),
+            (Range::Limited, 12, [3760, 2048, 2048], [256, 2048, 2048]),
+            (Range::Full, 8, [255, 128, 128], [0, 128, 128]),
+            (Range::Full, 10, [1023, 512, 512], [0, 512, 512]),
+        ] {
+            let format = YCbCrFormat::new(YCbCrMatrix::Bt709, range, bit_depth);
+            assert_eq!(format.encode([1., 1., 1.]), white);
+            assert_eq!(format.encode([0., 0., 0.]), black);
+            assert_close(format.decode(white), [1., 1., 1.], 1e-6);
+            assert_close(format.decode(black), [0., 0., 0.], 1e-6);
+        }
+
+        let format = YCbCrFormat::new(YCbCrMatrix::Bt601, Range::Limited, 8);
+        // Chroma extremes, and clamping of colors out of the gamut.
+        assert_eq!(format.encode([0., 0., 1.])[1], 240);
+        assert_eq!(format.encode([1., 0., 0.])[2], 240);
+        assert_eq!(format.encode([-10., -10., -10.]), [0, 128, 128]);
+        // Footroom decodes to negative components.
+        assert!(format.decode([0, 128, 128])[0] < 0.);
+
+        let rgb = [[0.2, 0.4, 0.6], [0.9, 0.1, 0.5]];
+        let mut codes = [[0; 3]; 2];
+        let mut decoded = [[0.; 3]; 2];
+        let format = YCbCrFormat::new(YCbCrMatrix::Bt2020Ncl, Range::Full, 12);
+        format.encode_slice(&rgb, &mut codes);
+        format.decode_slice(&codes, &mut decoded);
+        for (actual, expected) in decoded.into_iter().zip(rgb) {
+            assert_close(actual, expected, 1e-3);
+        }
+    }
+}